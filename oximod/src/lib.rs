@@ -1,7 +1,32 @@
 // --- public API --- 
 pub use oximod_core::feature::model::Model as ModelTrait;
-pub use oximod_core::feature::conn::client::{set_global_client, get_global_client};
-pub use oximod_macros::Model;
+pub use oximod_core::feature::validate::Validate;
+pub use oximod_core::feature::model::write_op::WriteOp;
+pub use oximod_core::feature::model::hooks::HookFuture;
+pub use oximod_core::feature::model::change_stream::ChangeStream;
+pub use oximod_core::feature::model::handle::CollectionHandle;
+pub use oximod_core::feature::model::query::QueryBuilder;
+pub use oximod_core::feature::model::upsert::UpsertOutcome;
+pub use oximod_core::feature::model::batch::BatchResult;
+pub use oximod_core::feature::model::deserialize::DeserializeError;
+pub use oximod_core::feature::model::page::Page;
+pub use oximod_core::feature::conn::client::{
+    set_global_client,
+    set_global_client_with,
+    set_global_client_with_retry,
+    get_global_client,
+    reset_global_client,
+    set_client,
+    set_client_with,
+    get_client,
+    ping,
+};
+#[cfg(feature = "mock")]
+pub use oximod_core::feature::conn::mock::set_global_client_mock;
+pub use oximod_core::feature::conn::transaction::{ with_transaction, with_transaction_on, TransactionFuture };
+pub use oximod_core::feature::id::parse_object_id;
+pub use oximod_core::feature::update;
+pub use oximod_macros::{ filter, Model, Validate };
 
 // --- Internal API ---
 #[doc(hidden)]
@@ -14,6 +39,12 @@ pub mod _error {
 #[doc(hidden)]
 pub use oximod_core::attach_printables as _attach_printables;
 #[doc(hidden)]
+pub use oximod_core::instrument as _instrument;
+#[doc(hidden)]
+pub use oximod_core::warning as _warning;
+#[doc(hidden)]
+pub use oximod_core::mock as _mock;
+#[doc(hidden)]
 pub use async_trait as _async_trait;
 #[doc(hidden)]
 pub use futures_util as _futures_util;
@@ -22,4 +53,8 @@ pub use mongodb as _mongodb;
 #[doc(hidden)]
 pub use regex as _regex;
 #[doc(hidden)]
+pub use serde as _serde;
+#[doc(hidden)]
+pub use url as _url;
+#[doc(hidden)]
 pub use oximod_core::feature::model::Model; // removes the need of importing the trait
@@ -44,3 +44,386 @@ async fn aggregates_documents_correctly() -> TestResult {
 
     Ok(())
 }
+
+// Run test: cargo nextest run aggregates_into_typed_results
+#[tokio::test]
+async fn aggregates_into_typed_results() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("aggregate_as_test")]
+    pub struct User {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        age: i32,
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct AgeCount {
+        _id: i32,
+        count: i64,
+    }
+
+    User::clear().await?;
+    for age in [20, 30, 20, 40, 20] {
+        User::new().age(age).save().await?;
+    }
+
+    let counts: Vec<AgeCount> = User::aggregate_as(
+        vec![
+            doc! { "$group": { "_id": "$age", "count": { "$sum": 1_i64 } } },
+            doc! { "$sort": { "_id": 1 } }
+        ]
+    ).await?;
+
+    assert_eq!(
+        counts,
+        vec![
+            AgeCount { _id: 20, count: 3 },
+            AgeCount { _id: 30, count: 1 },
+            AgeCount { _id: 40, count: 1 }
+        ]
+    );
+
+    Ok(())
+}
+
+// Run test: cargo nextest run counts_grouped_by_field
+#[tokio::test]
+async fn counts_grouped_by_field() -> TestResult {
+    use mongodb::bson::Bson;
+
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("count_grouped_test")]
+    pub struct Order {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        status: String,
+        total: i32,
+    }
+
+    Order::clear().await?;
+    for (status, total) in [("paid", 10), ("paid", 20), ("pending", 5), ("paid", 1), ("refunded", 50)] {
+        Order::new().status(status.to_string()).total(total).save().await?;
+    }
+
+    let counts = Order::count_grouped("status", doc! {}).await?;
+    assert_eq!(
+        counts,
+        vec![
+            (Bson::String("paid".to_string()), 3),
+            (Bson::String("pending".to_string()), 1),
+            (Bson::String("refunded".to_string()), 1)
+        ]
+    );
+
+    let counts = Order::count_grouped("status", doc! { "total": { "$gte": 10 } }).await?;
+    assert_eq!(
+        counts,
+        vec![(Bson::String("paid".to_string()), 2), (Bson::String("refunded".to_string()), 1)]
+    );
+
+    Ok(())
+}
+
+// Run test: cargo nextest run computes_numeric_aggregates
+#[tokio::test]
+async fn computes_numeric_aggregates() -> TestResult {
+    use mongodb::bson::Bson;
+
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("numeric_aggregates_test")]
+    pub struct Order {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        status: String,
+        total: i32,
+    }
+
+    Order::clear().await?;
+    for (status, total) in [("paid", 10), ("paid", 20), ("pending", 5), ("paid", 30)] {
+        Order::new().status(status.to_string()).total(total).save().await?;
+    }
+
+    let paid = doc! { "status": "paid" };
+    assert_eq!(Order::sum("total", paid.clone()).await?, 60.0);
+    assert_eq!(Order::avg("total", paid.clone()).await?, Some(20.0));
+    assert_eq!(Order::min("total", paid.clone()).await?, Some(Bson::Int32(10)));
+    assert_eq!(Order::max("total", paid).await?, Some(Bson::Int32(30)));
+
+    let none = doc! { "status": "refunded" };
+    assert_eq!(Order::sum("total", none.clone()).await?, 0.0);
+    assert_eq!(Order::avg("total", none.clone()).await?, None);
+    assert_eq!(Order::min("total", none.clone()).await?, None);
+    assert_eq!(Order::max("total", none).await?, None);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run rejects_expression_field_names
+#[tokio::test]
+async fn rejects_expression_field_names() -> TestResult {
+    use oximod::_error::oximod_error::OximodError;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("numeric_aggregates_test")]
+    pub struct Order {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        total: i32,
+    }
+
+    for field in ["", "$total", "$$ROOT", "a..b", ".total"] {
+        assert!(matches!(Order::sum(field, doc! {}).await, Err(OximodError::AggregationError(_))));
+        assert!(matches!(Order::max(field, doc! {}).await, Err(OximodError::AggregationError(_))));
+        assert!(matches!(Order::count_grouped(field, doc! {}).await, Err(OximodError::AggregationError(_))));
+    }
+
+    let _ = Order::new().total(1);
+
+    Ok(())
+}
+
+#[derive(Serialize)]
+enum Stage {
+    #[serde(rename = "$match")]
+    Match(mongodb::bson::Document),
+    #[serde(rename = "$sort")]
+    Sort(mongodb::bson::Document),
+    #[serde(rename = "$limit")]
+    Limit(i64),
+    Invalid,
+}
+
+// Run test: cargo nextest run aggregates_typed_stages
+#[tokio::test]
+async fn aggregates_typed_stages() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("aggregate_typed_test")]
+    pub struct Player {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        name: String,
+        score: i32,
+    }
+
+    Player::clear().await?;
+    for (name, score) in [("a", 10), ("b", 30), ("c", 20)] {
+        Player::new().name(name.to_string()).score(score).save().await?;
+    }
+
+    let mut cursor = Player::aggregate_typed(vec![
+        Stage::Match(doc! { "score": { "$gte": 20 } }),
+        Stage::Sort(doc! { "score": -1 }),
+        Stage::Limit(1)
+    ]).await?;
+
+    let top = cursor.next().await.expect("one result")?;
+    assert_eq!(top.get_str("name")?, "b");
+    assert!(cursor.next().await.is_none());
+
+    Ok(())
+}
+
+// Run test: cargo nextest run reports_failing_typed_stage
+#[tokio::test]
+async fn reports_failing_typed_stage() -> TestResult {
+    use oximod::_error::oximod_error::OximodError;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("aggregate_typed_test")]
+    pub struct Player {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        score: i32,
+    }
+
+    let result = Player::aggregate_typed(vec![Stage::Limit(1), Stage::Invalid]).await;
+    match result {
+        Err(OximodError::SerializationError(message)) => assert!(message.starts_with("stage 1:")),
+        other => panic!("expected a serialization error, got {:?}", other.map(|_| ())),
+    }
+
+    let _ = Player::new().score(1);
+
+    Ok(())
+}
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("lookup_customers")]
+pub struct Customer {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    name: String,
+}
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("lookup_orders")]
+pub struct Order {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    customer_id: ObjectId,
+    total: f64,
+}
+
+// Run test: cargo nextest run builds_lookup_stage_from_declared_collection
+#[tokio::test]
+async fn builds_lookup_stage_from_declared_collection() -> TestResult {
+    assert_eq!(Customer::collection_name(), "lookup_customers");
+    assert_eq!(
+        Order::lookup_stage::<Customer>("customer_id", "_id", "customer"),
+        doc! {
+            "$lookup": {
+                "from": "lookup_customers",
+                "localField": "customer_id",
+                "foreignField": "_id",
+                "as": "customer",
+            }
+        }
+    );
+
+    Ok(())
+}
+
+// Run test: cargo nextest run joins_models_with_lookup_stage
+#[tokio::test]
+async fn joins_models_with_lookup_stage() -> TestResult {
+    init().await;
+
+    Customer::clear().await?;
+    Order::clear().await?;
+
+    let customer_id = Customer::new().name("Ada".to_string()).save().await?;
+    Order::new().customer_id(customer_id).total(12.5).save().await?;
+
+    let joined = Order::aggregate_as::<mongodb::bson::Document>(
+        vec![
+            Order::lookup_stage::<Customer>("customer_id", "_id", "customer"),
+            doc! { "$unwind": "$customer" }
+        ]
+    ).await?;
+
+    assert_eq!(joined.len(), 1);
+    assert_eq!(joined[0].get_document("customer")?.get_str("name")?, "Ada");
+
+    Ok(())
+}
+
+// Run test: cargo nextest run paginates_aggregation_results
+#[tokio::test]
+async fn paginates_aggregation_results() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("aggregate_paginated_test")]
+    pub struct Item {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        rank: i32,
+        active: bool,
+    }
+
+    Item::clear().await?;
+    for rank in 1..=7 {
+        Item::new().rank(rank).active(rank != 4).save().await?;
+    }
+
+    let pipeline = vec![doc! { "$match": { "active": true } }, doc! { "$sort": { "rank": 1 } }];
+
+    let page = Item::aggregate_paginated(pipeline.clone(), 2, 4).await?;
+    let ranks: Vec<i32> = page.items
+        .iter()
+        .map(|item| item.get_i32("rank"))
+        .collect::<Result<_, _>>()?;
+    assert_eq!(ranks, [6, 7]);
+    assert_eq!((page.total, page.page, page.per_page), (6, 2, 4));
+    assert_eq!(page.total_pages(), 2);
+    assert!(!page.has_next());
+
+    let first = Item::aggregate_paginated(pipeline.clone(), 1, 4).await?;
+    assert_eq!(first.items.len(), 4);
+    assert!(first.has_next());
+
+    let past_end = Item::aggregate_paginated(pipeline, 5, 4).await?;
+    assert!(past_end.items.is_empty());
+    assert_eq!(past_end.total, 6);
+
+    let empty = Item::aggregate_paginated(vec![doc! { "$match": { "rank": 0 } }], 1, 4).await?;
+    assert!(empty.items.is_empty());
+    assert_eq!((empty.total, empty.total_pages()), (0, 0));
+
+    Ok(())
+}
+
+// Run test: cargo nextest run rejects_empty_pages
+#[tokio::test]
+async fn rejects_empty_pages() -> TestResult {
+    use oximod::_error::oximod_error::OximodError;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("aggregate_paginated_test")]
+    pub struct Item {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        rank: i32,
+    }
+
+    for (page, per_page) in [(1, 0), (0, 10)] {
+        let result = Item::aggregate_paginated(vec![], page, per_page).await;
+        assert!(matches!(result, Err(OximodError::AggregationError(_))));
+    }
+
+    let _ = Item::new().rank(1);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run samples_random_documents
+#[tokio::test]
+async fn samples_random_documents() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("aggregate_random_test")]
+    pub struct Item {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        rank: i32,
+        featured: bool,
+    }
+
+    Item::clear().await?;
+    assert!(Item::random(doc! {}, 3).await?.is_empty());
+
+    for rank in 1..=6 {
+        Item::new().rank(rank).featured(rank % 2 == 0).save().await?;
+    }
+
+    let picked = Item::random(doc! { "featured": true }, 2).await?;
+    assert_eq!(picked.len(), 2);
+    assert!(picked.iter().all(|item| item.featured));
+    assert_ne!(picked[0]._id, picked[1]._id);
+
+    // Asking for more than match returns every match once.
+    assert_eq!(Item::random(doc! { "featured": true }, 10).await?.len(), 3);
+    assert!(Item::random(doc! {}, 0).await?.is_empty());
+
+    Ok(())
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::clone_on_copy, clippy::bool_assert_comparison)]
+
 mod common;
 
 use common::init;
@@ -0,0 +1,74 @@
+use mongodb::bson::{ doc, oid::ObjectId };
+use oximod::{ Model, WriteOp };
+use oximod::_error::oximod_error::OximodError;
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+mod common;
+use common::init;
+
+// Run test: cargo nextest run runs_mixed_bulk_write
+#[tokio::test]
+async fn runs_mixed_bulk_write() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("bulk_write")]
+    pub struct User {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        name: String,
+        age: i32,
+    }
+
+    User::clear().await?;
+    User::new().name("Bob".to_string()).age(40).save().await?;
+    User::new().name("Carol".to_string()).age(25).save().await?;
+
+    let result = User::bulk_write(
+        vec![
+            WriteOp::InsertOne(doc! { "name": "Alice", "age": 30 }),
+            WriteOp::InsertOne(doc! { "name": "Dave", "age": 18 }),
+            WriteOp::UpdateOne {
+                filter: doc! { "name": "Bob" },
+                update: doc! { "$set": { "age": 41 } },
+            },
+            WriteOp::UpdateMany {
+                filter: doc! { "age": { "$lt": 30 } },
+                update: doc! { "$inc": { "age": 1 } },
+            },
+            WriteOp::DeleteOne(doc! { "name": "Carol" })
+        ],
+        true
+    ).await?;
+
+    assert_eq!(result.inserted_count, 2);
+    assert_eq!(result.modified_count, 3);
+    assert_eq!(result.deleted_count, 1);
+    assert_eq!(User::count(doc! {}).await?, 3);
+    assert_eq!(User::find_one(doc! { "name": "Dave" }).await?.unwrap().age, 19);
+
+    let result = User::bulk_write(vec![WriteOp::DeleteMany(doc! {})], false).await?;
+    assert_eq!(result.deleted_count, 3);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run rejects_empty_bulk_write
+#[tokio::test]
+async fn rejects_empty_bulk_write() -> TestResult {
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("bulk_write_empty")]
+    pub struct User {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        name: String,
+    }
+
+    let result = User::bulk_write(vec![], true).await;
+    assert!(matches!(result, Err(OximodError::CommandError(_))));
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use mongodb::bson::{ doc, oid::ObjectId };
+use oximod::{ _error::oximod_error::OximodError, Model };
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+mod common;
+use common::init;
+
+// Run test: cargo nextest run creates_capped_collection_before_first_save
+#[tokio::test]
+async fn creates_capped_collection_before_first_save() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection(name = "capped_log", capped(size = 4096, max = 3))]
+    pub struct LogEntry {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        message: String,
+    }
+
+    LogEntry::get_collection()?.drop().await?;
+
+    for i in 0..5 {
+        LogEntry::new().message(format!("entry {}", i)).save().await?;
+    }
+
+    // The oldest entries rolled off once `max` was reached.
+    let messages: Vec<String> = LogEntry::find(doc! {}).await?
+        .into_iter()
+        .map(|entry| entry.message)
+        .collect();
+    assert_eq!(messages, ["entry 2", "entry 3", "entry 4"]);
+
+    // Re-running against the existing capped collection is a no-op.
+    LogEntry::sync_indexes().await?;
+    LogEntry::sync_indexes().await?;
+
+    Ok(())
+}
+
+// Run test: cargo nextest run creates_capped_collection_without_auto_indexes
+#[tokio::test]
+async fn creates_capped_collection_without_auto_indexes() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("capped_events", capped(size = 4096))]
+    #[model(auto_create_indexes = false)]
+    pub struct Event {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        kind: String,
+    }
+
+    Event::get_collection()?.drop().await?;
+    Event::new().kind("started".to_string()).save().await?;
+
+    let stats = Event::get_database()?.run_command(doc! { "collStats": "capped_events" }).await?;
+    assert!(stats.get_bool("capped")?);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run rejects_existing_uncapped_collection
+#[tokio::test]
+async fn rejects_existing_uncapped_collection() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("capped_conflict")]
+    pub struct Plain {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        value: i32,
+    }
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("capped_conflict", capped(size = 4096))]
+    pub struct Capped {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        value: i32,
+    }
+
+    Plain::get_collection()?.drop().await?;
+    Plain::new().value(1).save().await?;
+
+    let result = Capped::new().value(2).save().await;
+    assert!(matches!(result, Err(OximodError::ConnectionError(_))));
+    assert_eq!(Plain::count(doc! {}).await?, 1);
+
+    Ok(())
+}
@@ -39,3 +39,67 @@ async fn clears_collection_successfully() -> TestResult {
 
     Ok(())
 }
+
+// Run test: cargo nextest run clear_is_refused_when_disabled
+#[tokio::test]
+async fn clear_is_refused_when_disabled() -> TestResult {
+    use oximod::_error::oximod_error::OximodError;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("clear_disabled")]
+    #[model(allow_clear = false)]
+    pub struct Invoice {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        total: i64,
+    }
+
+    // `Invoice::clear()` and `Invoice::drop_collection()` do not compile; generic callers get an error instead.
+    async fn clear_any<T: oximod::ModelTrait>() -> Result<(), OximodError> {
+        T::clear().await.map(|_| ())
+    }
+
+    async fn drop_any<T: oximod::ModelTrait>() -> Result<(), OximodError> {
+        T::drop_collection().await
+    }
+
+    let result = clear_any::<Invoice>().await;
+    assert!(matches!(result, Err(OximodError::CommandError(_))));
+    let result = drop_any::<Invoice>().await;
+    assert!(matches!(result, Err(OximodError::CommandError(_))));
+    Invoice::new().total(1).validate()?;
+
+    Ok(())
+}
+
+// Run test: cargo nextest run drops_collection_with_indexes
+#[tokio::test]
+async fn drops_collection_with_indexes() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("drop_collection")]
+    pub struct Session {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        #[index(unique)]
+        token: String,
+    }
+
+    Session::new().token("abc".to_string()).save().await?;
+    assert!(Session::collection_exists().await?);
+
+    // `clear()` keeps the collection and its indexes; dropping removes both.
+    Session::clear().await?;
+    assert!(Session::collection_exists().await?);
+
+    Session::drop_collection().await?;
+    assert!(!Session::collection_exists().await?);
+
+    // Dropping a missing collection is not an error.
+    Session::drop_collection().await?;
+
+    Ok(())
+}
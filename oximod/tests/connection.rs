@@ -13,3 +13,150 @@ async fn connects_to_db_successfully() -> TestResult {
 
     Ok(())
 }
+
+// Run test: cargo nextest run resolves_named_client
+#[tokio::test]
+async fn resolves_named_client() -> TestResult {
+    use oximod::{ get_client, set_client, Model };
+    use mongodb::bson::oid::ObjectId;
+    use serde::{ Deserialize, Serialize };
+
+    dotenv::dotenv().ok();
+    let mongodb_uri = std::env::var("MONGODB_URI").expect("Missing MONGODB_URI");
+    set_client("secondary", mongodb_uri.clone()).await?;
+
+    assert!(set_client("secondary", mongodb_uri).await.is_err());
+    assert!(get_client("unknown").is_err());
+
+    #[derive(Model, Serialize, Deserialize)]
+    #[db("test", client = "secondary")]
+    #[collection("named_client")]
+    pub struct Event {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        kind: String,
+    }
+
+    let collection = Event::get_collection()?;
+    assert_eq!(collection.namespace().db, "test");
+
+    Event::clear().await?;
+    let id = Event::new().kind("click".to_string()).save().await?;
+    assert!(Event::find_by_id(id).await?.is_some());
+
+    Ok(())
+}
+
+// Run test: cargo nextest run sets_prebuilt_global_client
+#[tokio::test]
+async fn sets_prebuilt_global_client() -> TestResult {
+    use mongodb::{ options::ClientOptions, Client };
+    use oximod::set_global_client_with;
+
+    dotenv::dotenv().ok();
+    let mongodb_uri = std::env::var("MONGODB_URI").expect("Missing MONGODB_URI");
+
+    let mut options = ClientOptions::parse(&mongodb_uri).await?;
+    options.app_name = Some("oximod-tests".to_string());
+    set_global_client_with(Client::with_options(options)?)?;
+
+    get_global_client()?.list_database_names().await?;
+
+    let again = Client::with_uri_str(&mongodb_uri).await?;
+    assert!(set_global_client_with(again).is_err());
+
+    Ok(())
+}
+
+// Run test: cargo nextest run fails_fast_with_short_server_selection_timeout
+#[tokio::test]
+async fn fails_fast_with_short_server_selection_timeout() -> TestResult {
+    use std::time::{ Duration, Instant };
+    use mongodb::{ bson::{ doc, oid::ObjectId }, options::ClientOptions, Client };
+    use oximod::{ _error::oximod_error::OximodError, set_client_with, Model };
+    use serde::{ Deserialize, Serialize };
+
+    // Nothing listens on port 1, so every operation waits out server selection.
+    let mut options = ClientOptions::parse("mongodb://127.0.0.1:1").await?;
+    options.server_selection_timeout = Some(Duration::from_millis(200));
+    set_client_with("unreachable", Client::with_options(options)?)?;
+
+    assert!(set_client_with("unreachable", Client::with_uri_str("mongodb://127.0.0.1:1").await?).is_err());
+
+    #[allow(dead_code)]
+    #[derive(Model, Serialize, Deserialize)]
+    #[db("analytics", client = "unreachable")]
+    #[collection("page_views")]
+    pub struct PageView {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        path: String,
+    }
+
+    let started = Instant::now();
+    let result = PageView::find(doc! { "path": "/" }).await;
+    assert!(matches!(result, Err(OximodError::ConnectionError(_))));
+    assert!(started.elapsed() < Duration::from_secs(5));
+
+    Ok(())
+}
+
+// Run test: cargo nextest run pings_db_successfully
+#[tokio::test]
+async fn pings_db_successfully() -> TestResult {
+    init().await;
+
+    oximod::ping().await?;
+
+    Ok(())
+}
+
+// Run test: cargo nextest run gives_up_after_retry_budget
+#[tokio::test]
+async fn gives_up_after_retry_budget() -> TestResult {
+    use oximod::{ _error::oximod_error::OximodError, set_global_client_with_retry };
+    use std::time::{ Duration, Instant };
+
+    let started = Instant::now();
+    let result = set_global_client_with_retry(
+        "mongodb://127.0.0.1:1/?serverSelectionTimeoutMS=50".to_string(),
+        2,
+        Duration::from_millis(20)
+    ).await;
+
+    match result {
+        Err(OximodError::ConnectionError(message)) => {
+            assert!(message.starts_with("Gave up after 3 attempt(s): "));
+        }
+        other => panic!("expected ConnectionError, got {:?}", other),
+    }
+    // Two waits: 20ms, then 40ms.
+    assert!(started.elapsed() >= Duration::from_millis(60));
+
+    Ok(())
+}
+
+// Run test: cargo nextest run fails_fast_on_malformed_uri
+#[tokio::test]
+async fn fails_fast_on_malformed_uri() -> TestResult {
+    use oximod::{ _error::oximod_error::OximodError, set_global_client_with_retry };
+    use std::time::{ Duration, Instant };
+
+    let started = Instant::now();
+    let result = set_global_client_with_retry(
+        "not-a-mongodb-uri".to_string(),
+        5,
+        Duration::from_secs(10)
+    ).await;
+
+    match result {
+        Err(OximodError::ConnectionError(message)) => {
+            assert!(!message.starts_with("Gave up after"));
+        }
+        other => panic!("expected ConnectionError, got {:?}", other),
+    }
+    // No retry waits happened.
+    assert!(started.elapsed() < Duration::from_secs(10));
+
+    Ok(())
+}
@@ -39,3 +39,35 @@ async fn counts_matching_documents_correctly() -> TestResult {
 
     Ok(())
 }
+
+// Run test: cargo nextest run counts_with_limit_and_skip
+#[tokio::test]
+async fn counts_with_limit_and_skip() -> TestResult {
+    use mongodb::options::CountOptions;
+
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("count_with_options")]
+    pub struct Event {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        kind: String,
+    }
+
+    Event::clear().await?;
+    for _ in 0..5 {
+        Event::default().kind("click".to_string()).save().await?;
+    }
+
+    let capped = CountOptions::builder().limit(3).build();
+    assert_eq!(Event::count_with_options(doc! { "kind": "click" }, capped).await?, 3);
+
+    let skipped = CountOptions::builder().skip(4).build();
+    assert_eq!(Event::count_with_options(doc! { "kind": "click" }, skipped).await?, 1);
+
+    assert_eq!(Event::count_with_options(doc! {}, CountOptions::default()).await?, 5);
+
+    Ok(())
+}
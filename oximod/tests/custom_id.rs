@@ -0,0 +1,57 @@
+use mongodb::bson::{ doc, Document };
+use oximod::Model;
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+mod common;
+use common::init;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("custom_id")]
+#[model(custom_id = true)]
+pub struct Country {
+    _id: String,
+    name: String,
+}
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("custom_optional_id")]
+#[model(custom_id = true)]
+pub struct Slug {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<String>,
+    target: String,
+}
+
+// Run test: cargo nextest run builds_models_with_custom_id
+#[tokio::test]
+async fn builds_models_with_custom_id() -> TestResult {
+    let country = Country { _id: "NZ".to_string(), name: "New Zealand".to_string() };
+
+    let document = Document::try_from(&country)?;
+    assert_eq!(document, doc! { "_id": "NZ", "name": "New Zealand" });
+    assert_eq!(Country::try_from(document)?._id, "NZ");
+
+    let slug = Slug::new().id("docs".to_string()).target("/documentation".to_string());
+    assert_eq!(Document::try_from(&slug)?, doc! { "_id": "docs", "target": "/documentation" });
+
+    Ok(())
+}
+
+// Run test: cargo nextest run stores_models_with_custom_id
+#[tokio::test]
+async fn stores_models_with_custom_id() -> TestResult {
+    init().await;
+
+    Country::clear().await?;
+
+    let country = Country { _id: "NZ".to_string(), name: "New Zealand".to_string() };
+    Country::get_collection()?.insert_one(Document::try_from(&country)?).await?;
+
+    let stored = Country::find_one(doc! { "_id": "NZ" }).await?.expect("country exists");
+    assert_eq!(stored.name, "New Zealand");
+
+    Ok(())
+}
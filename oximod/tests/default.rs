@@ -1,3 +1,5 @@
+#![allow(clippy::clone_on_copy)]
+
 use mongodb::bson::{ doc, oid::ObjectId };
 use oximod::Model;
 use testresult::TestResult;
@@ -115,3 +117,69 @@ async fn enum_default_and_override() -> TestResult {
 
     Ok(())
 }
+
+fn default_region() -> String {
+    "eu-west".to_string()
+}
+
+// Run test: cargo nextest run evaluates_default_calls_per_new
+#[tokio::test]
+async fn evaluates_default_calls_per_new() -> TestResult {
+    use mongodb::bson::DateTime;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("defaults_calls")]
+    pub struct Session {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+
+        #[default(ObjectId::new())]
+        token: ObjectId,
+
+        #[default(DateTime::now())]
+        created_at: DateTime,
+
+        #[default(fn = "default_region")]
+        region: String,
+    }
+
+    let first = Session::new();
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    let second = Session::default();
+
+    assert_ne!(first.token, second.token);
+    assert!(second.created_at > first.created_at);
+    assert_eq!(first.region, "eu-west");
+
+    Ok(())
+}
+
+// Run test: cargo nextest run default_now_sets_current_time
+#[tokio::test]
+async fn default_now_sets_current_time() -> TestResult {
+    use mongodb::bson::DateTime;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("defaults_now")]
+    pub struct Event {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+
+        #[default_now]
+        created_at: DateTime,
+
+        #[default_now]
+        updated_at: Option<DateTime>,
+    }
+
+    let before = DateTime::now();
+    let event = Event::new();
+    let after = DateTime::now();
+
+    assert!(event.created_at >= before && event.created_at <= after);
+    assert!(event.updated_at.is_some_and(|at| at >= before && at <= after));
+
+    Ok(())
+}
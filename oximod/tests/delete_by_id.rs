@@ -1,3 +1,5 @@
+#![allow(clippy::clone_on_copy)]
+
 use mongodb::bson::{ doc, oid::ObjectId };
 use oximod::Model;
 use testresult::TestResult;
@@ -37,3 +39,36 @@ async fn deletes_document_by_id_correctly() -> TestResult {
 
     Ok(())
 }
+
+// Run test: cargo nextest run deletes_documents_by_ids
+#[tokio::test]
+async fn deletes_documents_by_ids() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("delete_by_ids")]
+    pub struct User {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        name: String,
+    }
+
+    User::clear().await?;
+
+    let mut ids = Vec::new();
+    for name in ["User1", "User2", "User3"] {
+        ids.push(User::default().name(name.to_string()).save().await?);
+    }
+
+    let deleted = User::delete_by_ids(vec![ids[0], ids[2]]).await?;
+    assert_eq!(deleted.deleted_count, 2);
+
+    assert_eq!(User::count(doc! {}).await?, 1);
+    assert!(User::find_by_id(ids[1]).await?.is_some());
+
+    let deleted = User::delete_by_ids(vec![]).await?;
+    assert_eq!(deleted.deleted_count, 0);
+
+    Ok(())
+}
@@ -36,3 +36,69 @@ async fn checks_existence_of_matching_document() -> TestResult {
 
     Ok(())
 }
+
+// Run test: cargo nextest run checks_existence_without_deserializing
+#[tokio::test]
+async fn checks_existence_without_deserializing() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("exists_raw")]
+    pub struct User {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        name: String,
+        age: i32,
+    }
+
+    User::clear().await?;
+
+    // Missing `age`, so it can't be deserialized into `User`.
+    User::get_collection()?.insert_one(doc! { "name": "Legacy" }).await?;
+
+    assert!(User::find_one(doc! { "name": "Legacy" }).await.is_err());
+    assert!(User::exists(doc! { "name": "Legacy" }).await?);
+    assert!(!User::exists(doc! { "name": "Nobody" }).await?);
+
+    let _ = User::new().name("Unused".to_string()).age(1);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run checks_any_none_and_all_match
+#[tokio::test]
+async fn checks_any_none_and_all_match() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("exists_predicates")]
+    pub struct User {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        role: String,
+        verified: bool,
+    }
+
+    User::clear().await?;
+    User::new().role("admin".to_string()).verified(true).save().await?;
+    User::new().role("admin".to_string()).verified(true).save().await?;
+    User::new().role("member".to_string()).verified(false).save().await?;
+
+    assert!(User::any(doc! { "role": "admin" }).await?);
+    assert!(!User::any(doc! { "role": "owner" }).await?);
+    assert!(User::none(doc! { "role": "owner" }).await?);
+    assert!(!User::none(doc! { "role": "member" }).await?);
+
+    assert!(User::all_match(doc! { "verified": true }, doc! { "role": "admin" }).await?);
+    assert!(!User::all_match(doc! { "verified": true }, doc! {}).await?);
+    // Nothing to check, so every owner is trivially verified.
+    assert!(User::all_match(doc! { "verified": true }, doc! { "role": "owner" }).await?);
+
+    // A document without the field doesn't meet the condition.
+    User::get_collection()?.insert_one(doc! { "role": "admin" }).await?;
+    assert!(!User::all_match(doc! { "verified": true }, doc! { "role": "admin" }).await?);
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use mongodb::bson::{ doc, oid::ObjectId };
+use oximod::Model;
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+// Run test: cargo nextest run generates_field_name_constants
+#[tokio::test]
+async fn generates_field_name_constants() -> TestResult {
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("field_constants")]
+    pub struct UserProfile {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        name: String,
+        #[serde(rename = "emailAddress")]
+        email: String,
+        #[serde(rename(serialize = "yrs", deserialize = "years"))]
+        age: i32,
+    }
+
+    assert_eq!(user_profile_fields::_ID, "_id");
+    assert_eq!(user_profile_fields::NAME, "name");
+    assert_eq!(user_profile_fields::EMAIL, "emailAddress");
+    assert_eq!(user_profile_fields::AGE, "yrs");
+    assert_eq!(doc! { user_profile_fields::EMAIL: "a@b.c" }, doc! { "emailAddress": "a@b.c" });
+
+    let user = UserProfile::new().email("a@b.c".to_string());
+    assert!(mongodb::bson::to_document(&user)?.contains_key(user_profile_fields::EMAIL));
+
+    Ok(())
+}
+
+// Run test: cargo nextest run field_constants_honor_rename_all
+#[tokio::test]
+async fn field_constants_honor_rename_all() -> TestResult {
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("field_constants")]
+    #[serde(rename_all = "camelCase")]
+    pub struct Order {
+        #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+        id: Option<ObjectId>,
+        customer_name: String,
+        #[serde(rename = "total")]
+        total_cents: i64,
+    }
+
+    assert_eq!(order_fields::ID, "_id");
+    assert_eq!(order_fields::CUSTOMER_NAME, "customerName");
+    assert_eq!(order_fields::TOTAL_CENTS, "total");
+
+    let order = mongodb::bson::to_document(&Order::new().customer_name("Ada".to_string()))?;
+    assert!(order.contains_key(order_fields::CUSTOMER_NAME));
+    assert!(order.contains_key(order_fields::TOTAL_CENTS));
+
+    Ok(())
+}
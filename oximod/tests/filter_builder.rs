@@ -0,0 +1,99 @@
+use mongodb::bson::{ doc, oid::ObjectId, Document };
+use oximod::Model;
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("filter_builder")]
+pub struct User {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    name: String,
+    age: i32,
+    role: String,
+}
+
+// Run test: cargo nextest run builds_field_conditions
+#[tokio::test]
+async fn builds_field_conditions() -> TestResult {
+    let filter = UserFilter::new().name().eq("Alice").age().gt(18).age().lte(65).build();
+
+    assert_eq!(filter, doc! { "name": { "$eq": "Alice" }, "age": { "$gt": 18, "$lte": 65 } });
+
+    let filter = UserFilter::new().role().in_(["admin", "owner"]).name().ne("root").build();
+    assert_eq!(filter, doc! { "role": { "$in": ["admin", "owner"] }, "name": { "$ne": "root" } });
+
+    assert_eq!(UserFilter::new().build(), doc! {});
+
+    Ok(())
+}
+
+// Run test: cargo nextest run composes_filters_with_and_or
+#[tokio::test]
+async fn composes_filters_with_and_or() -> TestResult {
+    let filter = UserFilter::new()
+        .age()
+        .gte(18)
+        .or([UserFilter::new().role().eq("admin"), UserFilter::new().name().eq("Alice")])
+        .build();
+
+    assert_eq!(
+        filter,
+        doc! {
+            "$and": [
+                { "age": { "$gte": 18 } },
+                { "$or": [{ "role": { "$eq": "admin" } }, { "name": { "$eq": "Alice" } }] },
+            ]
+        }
+    );
+
+    let filter: Document = UserFilter::new()
+        .and([UserFilter::new().age().gt(1), UserFilter::new().age().lt(9)])
+        .into();
+    assert_eq!(filter, doc! { "$and": [{ "age": { "$gt": 1 } }, { "age": { "$lt": 9 } }] });
+
+    Ok(())
+}
+
+// Run test: cargo nextest run filters_use_serialized_field_names
+#[tokio::test]
+async fn filters_use_serialized_field_names() -> TestResult {
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("filter_builder")]
+    #[serde(rename_all = "camelCase")]
+    pub struct Customer {
+        #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+        id: Option<ObjectId>,
+        first_name: String,
+        #[serde(rename = "mail")]
+        email_address: String,
+    }
+
+    let filter = CustomerFilter::new().first_name().eq("Ada").email_address().eq("ada@example.com").build();
+    assert_eq!(filter, doc! { "firstName": { "$eq": "Ada" }, "mail": { "$eq": "ada@example.com" } });
+
+    let stored = mongodb::bson::to_document(&Customer::new().first_name("Ada".to_string()))?;
+    assert!(stored.contains_key("firstName"));
+
+    Ok(())
+}
+
+// Run test: cargo nextest run filters_models_with_a_filter_field
+#[tokio::test]
+async fn filters_models_with_a_filter_field() -> TestResult {
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("filter_builder")]
+    pub struct Search {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        filter: String,
+    }
+
+    let search = Search::new().filter("active".to_string());
+    assert_eq!(SearchFilter::new().filter().eq(search.filter.as_str()).build(), doc! { "filter": { "$eq": "active" } });
+
+    Ok(())
+}
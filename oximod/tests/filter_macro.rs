@@ -0,0 +1,81 @@
+use mongodb::bson::{ doc, oid::ObjectId };
+use oximod::{ filter, Model };
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+mod models {
+    use mongodb::bson::oid::ObjectId;
+    use oximod::Model;
+    use serde::{ Deserialize, Serialize };
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("filter_macro")]
+    #[serde(rename_all = "camelCase")]
+    pub struct Account {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub _id: Option<ObjectId>,
+        pub display_name: String,
+        #[serde(rename = "mail")]
+        pub email: String,
+    }
+}
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("filter_macro")]
+pub struct User {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    name: String,
+    age: i32,
+    role: String,
+}
+
+// Run test: cargo nextest run builds_checked_filters
+#[tokio::test]
+async fn builds_checked_filters() -> TestResult {
+    assert_eq!(filter!(User { name: "Alice", age: 30 }), doc! { "name": "Alice", "age": 30 });
+    assert_eq!(filter!(User {}), doc! {});
+
+    let name = "Bob".to_string();
+    assert_eq!(filter!(User { name }), doc! { "name": "Bob" });
+
+    Ok(())
+}
+
+// Run test: cargo nextest run builds_checked_filters_with_operators
+#[tokio::test]
+async fn builds_checked_filters_with_operators() -> TestResult {
+    assert_eq!(
+        filter!(User { age: gte(18), age: lt(65), role: in_(["admin", "owner"]), name: ne("root") }),
+        doc! {
+            "age": { "$gte": 18, "$lt": 65 },
+            "role": { "$in": ["admin", "owner"] },
+            "name": { "$ne": "root" },
+        }
+    );
+
+    // Equivalent to the typed builder.
+    assert_eq!(filter!(User { age: gt(18) }), UserFilter::new().age().gt(18).build());
+
+    let id = ObjectId::new();
+    assert_eq!(filter!(User { _id: id }), doc! { "_id": id });
+
+    let _ = User::new().name("Alice".to_string()).age(30).role("admin".to_string());
+
+    Ok(())
+}
+
+// Run test: cargo nextest run checked_filters_use_stored_keys
+#[tokio::test]
+async fn checked_filters_use_stored_keys() -> TestResult {
+    assert_eq!(
+        filter!(models::Account { display_name: "Ada", email: "ada@example.com" }),
+        doc! { "displayName": "Ada", "mail": "ada@example.com" }
+    );
+
+    let _ = models::Account::new().display_name("Ada".to_string()).email("ada@example.com".to_string());
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use mongodb::bson::{ doc, oid::ObjectId };
-use oximod::Model;
+use oximod::{ _error::oximod_error::OximodError, Model };
 use testresult::TestResult;
 use serde::{ Deserialize, Serialize };
 
@@ -46,3 +46,76 @@ async fn finds_multiple_matching_documents() -> TestResult {
 
     Ok(())
 }
+
+// Run test: cargo nextest run processes_documents_in_batches
+#[tokio::test]
+async fn processes_documents_in_batches() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("find_batches")]
+    pub struct Item {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        n: i32,
+    }
+
+    Item::clear().await?;
+
+    for n in 0..7 {
+        Item::default().n(n).save().await?;
+    }
+
+    let mut sizes = Vec::new();
+    let mut total = 0;
+    Item::for_each_batch(doc! {}, 3, |items| {
+        sizes.push(items.len());
+        total += items.iter().map(|i| i.n).sum::<i32>();
+        async { Ok(()) }
+    }).await?;
+    assert_eq!(sizes, vec![3, 3, 1]);
+    assert_eq!(total, 21);
+
+    let mut calls = 0;
+    let result = Item::for_each_batch(doc! {}, 2, |_| {
+        calls += 1;
+        async { Err(OximodError::CommandError("stop".to_string())) }
+    }).await;
+    assert!(result.is_err());
+    assert_eq!(calls, 1);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run find_lenient_skips_malformed_documents
+#[tokio::test]
+async fn find_lenient_skips_malformed_documents() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("find_lenient")]
+    pub struct User {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        name: String,
+        age: i32,
+    }
+
+    User::clear().await?;
+    User::new().name("Alice".to_string()).age(30).save().await?;
+    User::new().name("Bob".to_string()).age(25).save().await?;
+
+    let legacy = User::get_collection()?.insert_one(doc! { "name": "Legacy", "age": "old" }).await?;
+
+    assert!(User::find(doc! {}).await.is_err());
+
+    let (users, skipped) = User::find_lenient(doc! {}).await?;
+    assert_eq!(users.len(), 2);
+    assert_eq!(skipped.len(), 1);
+    assert_eq!(skipped[0].id, Some(legacy.inserted_id));
+    assert!(matches!(skipped[0].error, OximodError::SerializationError(_)));
+
+    Ok(())
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::clone_on_copy)]
+
 use mongodb::bson::{ doc, oid::ObjectId };
 use oximod::Model;
 use testresult::TestResult;
@@ -41,3 +43,52 @@ async fn finds_document_by_id_correctly() -> TestResult {
 
     Ok(())
 }
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("find_by_id_str")]
+pub struct Article {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    title: String,
+}
+
+// Run test: cargo nextest run finds_updates_and_deletes_by_id_string
+#[tokio::test]
+async fn finds_updates_and_deletes_by_id_string() -> TestResult {
+    init().await;
+
+    Article::clear().await?;
+
+    let id = Article::new().title("Draft".to_string()).save().await?;
+    let id_str = id.to_hex();
+
+    let found = Article::find_by_id_str(&id_str).await?;
+    assert_eq!(found.map(|article| article.title), Some("Draft".to_string()));
+
+    let updated = Article::update_by_id_str(&id_str, doc! { "$set": { "title": "Final" } }).await?;
+    assert_eq!(updated.modified_count, 1);
+
+    let deleted = Article::delete_by_id_str(&id_str).await?;
+    assert_eq!(deleted.deleted_count, 1);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run rejects_malformed_id_string
+#[tokio::test]
+async fn rejects_malformed_id_string() -> TestResult {
+    use oximod::_error::oximod_error::OximodError;
+
+    // The id is parsed before any database access, so no connection is needed.
+    let result = Article::find_by_id_str("not-an-object-id").await;
+    assert!(matches!(result, Err(OximodError::InvalidId(ref msg)) if msg.contains("not-an-object-id")));
+
+    let result = Article::update_by_id_str("123", doc! { "$set": { "title": "x" } }).await;
+    assert!(matches!(result, Err(OximodError::InvalidId(_))));
+
+    let result = Article::delete_by_id_str("").await;
+    assert!(matches!(result, Err(OximodError::InvalidId(_))));
+
+    Ok(())
+}
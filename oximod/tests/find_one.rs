@@ -43,3 +43,36 @@ async fn finds_first_matching_document_correctly() -> TestResult {
 
     Ok(())
 }
+
+// Run test: cargo nextest run finds_first_and_last_by_id
+#[tokio::test]
+async fn finds_first_and_last_by_id() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("first_last")]
+    pub struct Post {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        title: String,
+        published: bool,
+    }
+
+    Post::clear().await?;
+
+    assert!(Post::last(doc! {}).await?.is_none());
+
+    for (title, published) in [("One", true), ("Two", true), ("Three", false)] {
+        Post::new().title(title.to_string()).published(published).save().await?;
+    }
+
+    assert_eq!(Post::first(doc! {}).await?.map(|post| post.title), Some("One".to_string()));
+    assert_eq!(Post::last(doc! {}).await?.map(|post| post.title), Some("Three".to_string()));
+    assert_eq!(
+        Post::last(doc! { "published": true }).await?.map(|post| post.title),
+        Some("Two".to_string())
+    );
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use mongodb::bson::{ doc, oid::ObjectId };
+use oximod::{ _error::oximod_error::OximodError, Model };
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+mod common;
+use common::init;
+
+#[derive(Model, Serialize, Deserialize, Debug, Clone)]
+#[db("test")]
+#[collection("find_one_or_create")]
+pub struct Settings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    #[index(unique)]
+    key: String,
+    #[validate(min_length = 1)]
+    theme: String,
+}
+
+fn defaults(theme: &str) -> Settings {
+    Settings::new().key("site".to_string()).theme(theme.to_string())
+}
+
+// Run test: cargo nextest run creates_then_returns_existing_document
+#[tokio::test]
+async fn creates_then_returns_existing_document() -> TestResult {
+    init().await;
+
+    Settings::clear().await?;
+
+    let created = Settings::find_one_or_create(doc! { "key": "site" }, defaults("light")).await?;
+    assert!(created._id.is_some());
+    assert_eq!(created.theme, "light");
+
+    // The second default is ignored; the stored document wins.
+    let existing = Settings::find_one_or_create(doc! { "key": "site" }, defaults("dark")).await?;
+    assert_eq!(existing._id, created._id);
+    assert_eq!(existing.theme, "light");
+    assert_eq!(Settings::count(doc! {}).await?, 1);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run concurrent_callers_share_one_document
+#[tokio::test]
+async fn concurrent_callers_share_one_document() -> TestResult {
+    init().await;
+
+    Settings::clear().await?;
+    Settings::sync_indexes().await?;
+
+    let (first, second) = tokio::join!(
+        Settings::find_one_or_create(doc! { "key": "site" }, defaults("light")),
+        Settings::find_one_or_create(doc! { "key": "site" }, defaults("dark"))
+    );
+    assert_eq!(first?._id, second?._id);
+    assert_eq!(Settings::count(doc! {}).await?, 1);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run rejects_invalid_default
+#[tokio::test]
+async fn rejects_invalid_default() -> TestResult {
+    init().await;
+
+    Settings::clear().await?;
+
+    let result = Settings::find_one_or_create(doc! { "key": "site" }, defaults("")).await;
+    assert!(matches!(result, Err(OximodError::ValidationError(_))));
+    assert_eq!(Settings::count(doc! {}).await?, 0);
+
+    Ok(())
+}
@@ -47,3 +47,89 @@ async fn uses_get_collection_and_manual_indexing() -> TestResult {
 
     Ok(())
 }
+
+// Run test: cargo nextest run applies_model_read_preference_and_write_concern
+#[tokio::test]
+async fn applies_model_read_preference_and_write_concern() -> TestResult {
+    use mongodb::options::{ Acknowledgment, ReadPreference, SelectionCriteria };
+
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("concern_test")]
+    #[model(read_preference = "secondaryPreferred", write_concern = "majority")]
+    pub struct Report {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        title: String,
+    }
+
+    let collection = Report::get_collection()?;
+
+    assert!(
+        matches!(
+            collection.selection_criteria(),
+            Some(SelectionCriteria::ReadPreference(ReadPreference::SecondaryPreferred { .. }))
+        )
+    );
+    assert!(matches!(collection.write_concern().and_then(|wc| wc.w.as_ref()), Some(Acknowledgment::Majority)));
+
+    Report::clear().await?;
+    Report::new().title("Q3".to_string()).save().await?;
+    assert_eq!(Report::count(doc! {}).await?, 1);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run uses_get_database_for_database_operations
+#[tokio::test]
+async fn uses_get_database_for_database_operations() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("get_database_test")]
+    pub struct User {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        username: String,
+    }
+
+    User::clear().await?;
+    User::default().username("User1".to_string()).save().await?;
+
+    let db = User::get_database()?;
+    assert_eq!(db.name(), "test");
+
+    let names = db.list_collection_names().await?;
+    assert!(names.contains(&"get_database_test".to_string()));
+
+    let stats = db.run_command(doc! { "dbStats": 1 }).await?;
+    assert_eq!(stats.get_str("db")?, "test");
+
+    Ok(())
+}
+
+// Run test: cargo nextest run checks_whether_collection_exists
+#[tokio::test]
+async fn checks_whether_collection_exists() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("collection_exists")]
+    pub struct Migration {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        version: i32,
+    }
+
+    Migration::get_collection()?.drop().await?;
+    assert!(!Migration::collection_exists().await?);
+
+    Migration::new().version(1).save().await?;
+    assert!(Migration::collection_exists().await?);
+
+    Ok(())
+}
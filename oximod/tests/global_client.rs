@@ -0,0 +1,24 @@
+use mongodb::Client;
+use oximod::{ _error::oximod_error::OximodError, get_global_client, reset_global_client, set_global_client_with };
+use testresult::TestResult;
+
+// Run test: cargo nextest run replaces_global_client_after_reset
+#[tokio::test]
+async fn replaces_global_client_after_reset() -> TestResult {
+    // Clients connect lazily, so no server is needed.
+    let uri = "mongodb://127.0.0.1:1";
+
+    assert!(reset_global_client()?.is_none());
+    set_global_client_with(Client::with_uri_str(uri).await?)?;
+    let first = get_global_client()?;
+    assert!(set_global_client_with(Client::with_uri_str(uri).await?).is_err());
+
+    let previous = reset_global_client()?.expect("client was set");
+    assert!(std::sync::Arc::ptr_eq(&first, &previous));
+    assert!(matches!(get_global_client(), Err(OximodError::GlobalClientMissing(_))));
+
+    set_global_client_with(Client::with_uri_str(uri).await?)?;
+    assert!(!std::sync::Arc::ptr_eq(&first, &get_global_client()?));
+
+    Ok(())
+}
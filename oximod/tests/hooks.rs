@@ -0,0 +1,185 @@
+use std::sync::{ Arc, Mutex };
+use mongodb::bson::{ doc, oid::ObjectId };
+use oximod::{ with_transaction, Model };
+use oximod::_error::oximod_error::OximodError;
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+mod common;
+use common::init;
+
+// Run test: cargo nextest run runs_save_hooks_in_order
+#[tokio::test]
+async fn runs_save_hooks_in_order() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("save_hooks")]
+    pub struct User {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        name: String,
+        password: String,
+    }
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let log = calls.clone();
+    User::pre_save(move |doc| {
+        log.lock().unwrap().push("hash");
+        let hashed = format!("hashed:{}", doc.get_str("password").unwrap_or_default());
+        doc.insert("password", hashed);
+        Box::pin(async { Ok(()) })
+    });
+    let log = calls.clone();
+    User::pre_save(move |_| {
+        log.lock().unwrap().push("check");
+        Box::pin(async { Ok(()) })
+    });
+    let log = calls.clone();
+    User::post_save(move |doc| {
+        assert!(doc.get_object_id("_id").is_ok());
+        log.lock().unwrap().push("audit");
+        Box::pin(async { Ok(()) })
+    });
+
+    User::clear().await?;
+    let id = User::new().name("alice".to_string()).password("secret".to_string()).save().await?;
+
+    assert_eq!(*calls.lock().unwrap(), vec!["hash", "check", "audit"]);
+    assert_eq!(User::find_by_id(id).await?.unwrap().password, "hashed:secret");
+
+    Ok(())
+}
+
+// Run test: cargo nextest run pre_save_error_aborts_save
+#[tokio::test]
+async fn pre_save_error_aborts_save() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("save_hooks_abort")]
+    pub struct User {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        name: String,
+    }
+
+    User::pre_save(|doc| {
+        let banned = doc.get_str("name") == Ok("root");
+        Box::pin(async move {
+            if banned {
+                return Err(OximodError::ValidationError("name is reserved".to_string()));
+            }
+            Ok(())
+        })
+    });
+
+    User::clear().await?;
+    assert!(User::new().name("root".to_string()).save().await.is_err());
+    User::new().name("alice".to_string()).save().await?;
+    assert_eq!(User::count(doc! {}).await?, 1);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run runs_delete_hooks
+#[tokio::test]
+async fn runs_delete_hooks() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("delete_hooks")]
+    pub struct User {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        name: String,
+    }
+
+    let deleted = Arc::new(Mutex::new(Vec::new()));
+
+    User::pre_delete(|filter| {
+        let protected = filter.get_str("name") == Ok("admin");
+        Box::pin(async move {
+            if protected {
+                return Err(OximodError::ValidationError("admin cannot be deleted".to_string()));
+            }
+            Ok(())
+        })
+    });
+    let log = deleted.clone();
+    User::post_delete(move |filter| {
+        log.lock().unwrap().push(filter.clone());
+        Box::pin(async { Ok(()) })
+    });
+
+    User::clear().await?;
+    User::new().name("admin".to_string()).save().await?;
+    User::new().name("bob".to_string()).save().await?;
+
+    assert!(User::delete_one(doc! { "name": "admin" }).await.is_err());
+    User::delete(doc! { "name": "bob" }).await?;
+
+    assert_eq!(User::count(doc! {}).await?, 1);
+    assert_eq!(*deleted.lock().unwrap(), vec![doc! { "name": "bob" }]);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run runs_hooks_in_session_methods
+#[tokio::test]
+async fn runs_hooks_in_session_methods() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("session_hooks")]
+    pub struct User {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        name: String,
+    }
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let log = calls.clone();
+    User::pre_save(move |doc| {
+        log.lock().unwrap().push("pre_save");
+        doc.insert("name", "hooked");
+        Box::pin(async { Ok(()) })
+    });
+    let log = calls.clone();
+    User::post_save(move |_| {
+        log.lock().unwrap().push("post_save");
+        Box::pin(async { Ok(()) })
+    });
+    let log = calls.clone();
+    User::pre_delete(move |_| {
+        log.lock().unwrap().push("pre_delete");
+        Box::pin(async { Ok(()) })
+    });
+    let log = calls.clone();
+    User::post_delete(move |_| {
+        log.lock().unwrap().push("post_delete");
+        Box::pin(async { Ok(()) })
+    });
+
+    User::clear().await?;
+    let id = with_transaction(|session| Box::pin(async move {
+        User::new().name("alice".to_string()).save_with_session(session).await
+    })).await?;
+    assert_eq!(User::find_by_id(id).await?.unwrap().name, "hooked");
+
+    with_transaction(|session| Box::pin(async move {
+        User::delete_by_id_with_session(id, session).await?;
+        Ok(())
+    })).await?;
+
+    assert_eq!(User::count(doc! {}).await?, 0);
+    assert_eq!(*calls.lock().unwrap(), vec!["pre_save", "post_save", "pre_delete", "post_delete"]);
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use mongodb::bson::{ DateTime, oid::ObjectId, doc };
 use oximod::Model;
 use testresult::TestResult;
 use serde::{ Deserialize, Serialize };
-use std::{ thread::sleep, time::Duration };
+use std::time::Duration;
 
 mod common;
 use common::init;
@@ -46,9 +46,9 @@ async fn creates_indexes_correctly() -> TestResult {
     Ok(())
 }
 
-// Run test: cargo nextest run ttl_index_removes_expired_documents
+// Run test: cargo nextest run creates_ttl_index_with_expire_after_seconds
 #[tokio::test]
-async fn ttl_index_removes_expired_documents() -> TestResult {
+async fn creates_ttl_index_with_expire_after_seconds() -> TestResult {
     init().await;
 
     #[derive(Model, Serialize, Deserialize)]
@@ -63,19 +63,454 @@ async fn ttl_index_removes_expired_documents() -> TestResult {
     }
 
     Session::clear().await?;
+    Session::default().created_at(DateTime::now()).save().await?;
 
-    // Insert a document with a created_at timestamp in the past
-    let expired_session = Session::default().created_at(
-        DateTime::from_millis(DateTime::now().timestamp_millis() - 10_000)
+    // The TTL monitor only runs about once a minute, so check the index the server
+    // created instead of waiting for documents to disappear.
+    let mut cursor = Session::get_collection()?.list_indexes().await?;
+    let mut expire_after = None;
+    while let Some(index) = futures_util::stream::StreamExt::next(&mut cursor).await {
+        let index = index?;
+        if index.keys == doc! { "created_at": 1 } {
+            expire_after = index.options.and_then(|o| o.expire_after);
+        }
+    }
+    assert_eq!(expire_after, Some(Duration::from_secs(2)));
+
+    Ok(())
+}
+
+// Run test: cargo nextest run declares_ttl_boundaries
+#[tokio::test]
+async fn declares_ttl_boundaries() -> TestResult {
+    #[derive(Model, Serialize, Deserialize)]
+    #[db("test")]
+    #[collection("ttl_test")]
+    pub struct Reminder {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+
+        #[index(expire_after_secs = 0)]
+        remind_at: Option<DateTime>,
+
+        #[index(expire_after_secs = 2147483647)]
+        created_at: Option<DateTime>,
+    }
+
+    let _ = Reminder::new().remind_at(DateTime::now()).created_at(DateTime::now());
+
+    let expire_after = Reminder::declared_indexes()
+        .into_iter()
+        .map(|index| index.options.and_then(|o| o.expire_after))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        expire_after,
+        [Some(Duration::ZERO), Some(Duration::from_secs(i32::MAX as u64))]
     );
 
-    expired_session.save().await?;
+    Ok(())
+}
+
+// Run test: cargo nextest run creates_compound_indexes_in_declared_order
+#[tokio::test]
+async fn creates_compound_indexes_in_declared_order() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize)]
+    #[db("test")]
+    #[collection("compound_index_test")]
+    #[compound_index(keys("last_name" = 1, "first_name" = -1), unique, name = "full_name_idx")]
+    #[compound_index(keys("city" = 1, "age" = -1))]
+    pub struct Person {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        first_name: String,
+        last_name: String,
+        city: String,
+        age: i32,
+    }
+
+    Person::clear().await?;
+
+    Person::default()
+        .first_name("Ada".to_string())
+        .last_name("Lovelace".to_string())
+        .city("London".to_string())
+        .age(36)
+        .save().await?;
+
+    let mut cursor = Person::get_collection()?.list_indexes().await?;
+    let mut full_name_keys = None;
+    let mut index_names = vec![];
+    while let Some(index) = futures_util::stream::StreamExt::next(&mut cursor).await {
+        let index = index?;
+        let name = index.options.as_ref().and_then(|o| o.name.clone()).unwrap_or_default();
+        if name == "full_name_idx" {
+            full_name_keys = Some(index.keys.clone());
+        }
+        index_names.push(name);
+    }
+
+    let keys = full_name_keys.expect("Expected full_name_idx to exist");
+    let ordered: Vec<(&String, i32)> = keys
+        .iter()
+        .map(|(k, v)| (k, v.as_i32().unwrap()))
+        .collect();
+    assert_eq!(ordered, vec![(&"last_name".to_string(), 1), (&"first_name".to_string(), -1)]);
+    assert!(index_names.contains(&"city_1_age_-1".to_string()));
+
+    // The unique compound index rejects a duplicate full name
+    let duplicate = Person::default()
+        .first_name("Ada".to_string())
+        .last_name("Lovelace".to_string())
+        .city("Paris".to_string())
+        .age(20)
+        .save().await;
+    assert!(
+        matches!(
+            duplicate,
+            Err(oximod::_error::oximod_error::OximodError::DuplicateKey { ref key }) if key == "full_name_idx"
+        )
+    );
+
+    Ok(())
+}
+
+// Run test: cargo nextest run creates_weighted_text_index_and_searches
+#[tokio::test]
+async fn creates_weighted_text_index_and_searches() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize)]
+    #[db("test")]
+    #[collection("text_index_test")]
+    #[text_index(fields("title" = 10, "body" = 1), default_language = "english", name = "search_idx")]
+    pub struct Article {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        title: String,
+        body: String,
+    }
+
+    #[derive(Model, Serialize, Deserialize)]
+    #[db("test")]
+    #[collection("text_field_index_test")]
+    pub struct Note {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+
+        #[index(text, default_language = "spanish")]
+        content: String,
+    }
+
+    Article::clear().await?;
+    Note::clear().await?;
+
+    Article::default()
+        .title("Rust ownership explained".to_string())
+        .body("Borrowing and lifetimes".to_string())
+        .save().await?;
+    Article::default()
+        .title("Cooking pasta".to_string())
+        .body("Boil water first".to_string())
+        .save().await?;
+
+    let found = Article::find(doc! { "$text": { "$search": "ownership" } }).await?;
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].title, "Rust ownership explained");
+
+    Note::default().content("Hola mundo".to_string()).save().await?;
+    let found = Note::find(doc! { "$text": { "$search": "mundo" } }).await?;
+    assert_eq!(found.len(), 1);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run collation_index_enforces_case_insensitive_uniqueness
+#[tokio::test]
+async fn collation_index_enforces_case_insensitive_uniqueness() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize)]
+    #[db("test")]
+    #[collection("collation_index_test")]
+    pub struct Member {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+
+        #[index(unique, name = "email_ci_idx", collation_locale = "en", collation_strength = 2)]
+        email: String,
+    }
+
+    Member::clear().await?;
+
+    Member::default().email("Ada@Example.com".to_string()).save().await?;
+    let duplicate = Member::default().email("ada@example.com".to_string()).save().await;
+    assert!(duplicate.is_err(), "Expected a case-insensitive duplicate key error");
+
+    Ok(())
+}
+
+// Run test: cargo nextest run creates_hidden_index
+#[tokio::test]
+async fn creates_hidden_index() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize)]
+    #[db("test")]
+    #[collection("hidden_index_test")]
+    pub struct Event {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+
+        #[index(hidden, sparse, name = "kind_hidden_idx")]
+        kind: String,
+    }
+
+    Event::clear().await?;
+    Event::default().kind("signup".to_string()).save().await?;
+
+    let mut cursor = Event::get_collection()?.list_indexes().await?;
+    let mut hidden = None;
+    while let Some(index) = futures_util::stream::StreamExt::next(&mut cursor).await {
+        let options = index?.options.unwrap_or_default();
+        if options.name.as_deref() == Some("kind_hidden_idx") {
+            hidden = options.hidden;
+        }
+    }
+    assert_eq!(hidden, Some(true));
+
+    Ok(())
+}
+
+// Run test: cargo nextest run sync_indexes_when_auto_creation_disabled
+#[tokio::test]
+async fn sync_indexes_when_auto_creation_disabled() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize)]
+    #[db("test")]
+    #[collection("sync_index_test")]
+    #[model(auto_create_indexes = false)]
+    pub struct Tag {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+
+        #[index(unique, name = "label_idx")]
+        label: String,
+    }
+
+    async fn index_names() -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        Ok(Tag::get_collection()?.list_index_names().await.unwrap_or_default())
+    }
+
+    Tag::get_collection()?.drop().await?;
+
+    Tag::default().label("rust".to_string()).save().await?;
+    assert!(!index_names().await?.contains(&"label_idx".to_string()));
+
+    Tag::sync_indexes().await?;
+    assert!(index_names().await?.contains(&"label_idx".to_string()));
+
+    Ok(())
+}
+
+// Run test: cargo nextest run lists_and_drops_indexes
+#[tokio::test]
+async fn lists_and_drops_indexes() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize)]
+    #[db("test")]
+    #[collection("drop_index_test")]
+    pub struct Item {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+
+        #[index(name = "sku_idx")]
+        sku: String,
+
+        #[index(name = "price_idx")]
+        price: i32,
+    }
+
+    Item::clear().await?;
+    Item::sync_indexes().await?;
+
+    fn names(indexes: &[mongodb::IndexModel]) -> Vec<String> {
+        indexes
+            .iter()
+            .filter_map(|index| index.options.as_ref().and_then(|o| o.name.clone()))
+            .collect()
+    }
+
+    let listed = names(&Item::list_indexes().await?);
+    assert!(listed.contains(&"sku_idx".to_string()));
+    assert!(listed.contains(&"price_idx".to_string()));
+
+    Item::drop_index("sku_idx").await?;
+    let listed = names(&Item::list_indexes().await?);
+    assert!(!listed.contains(&"sku_idx".to_string()));
+    assert!(listed.contains(&"price_idx".to_string()));
+
+    Item::drop_all_indexes().await?;
+    assert_eq!(names(&Item::list_indexes().await?), vec!["_id_".to_string()]);
+
+    assert!(Item::drop_index("missing_idx").await.is_err());
+
+    Ok(())
+}
+
+// Run test: cargo nextest run indexes_serialized_field_names
+#[tokio::test]
+async fn indexes_serialized_field_names() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize)]
+    #[db("test")]
+    #[collection("renamed_index_test")]
+    #[serde(rename_all = "camelCase")]
+    pub struct Member {
+        #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+        id: Option<ObjectId>,
+
+        #[serde(rename = "emailAddress")]
+        #[index(unique, name = "email_address_idx")]
+        email: String,
+
+        #[index(name = "display_name_idx")]
+        display_name: String,
+    }
+
+    Member::clear().await?;
+    Member::drop_all_indexes().await?;
+    Member::sync_indexes().await?;
+
+    let indexes = Member::list_indexes().await?;
+    let keys_of = |name: &str| {
+        indexes
+            .iter()
+            .find(|index| index.options.as_ref().and_then(|o| o.name.as_deref()) == Some(name))
+            .map(|index| index.keys.clone())
+    };
+
+    assert_eq!(keys_of("email_address_idx"), Some(doc! { "emailAddress": 1 }));
+    assert_eq!(keys_of("display_name_idx"), Some(doc! { "displayName": 1 }));
+
+    Ok(())
+}
+
+// Run test: cargo nextest run explains_find_using_declared_index
+#[tokio::test]
+async fn explains_find_using_declared_index() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize)]
+    #[db("test")]
+    #[collection("explain_test")]
+    pub struct Item {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+
+        #[index(name = "sku_idx")]
+        sku: String,
+
+        price: i32,
+    }
+
+    Item::clear().await?;
+    Item::sync_indexes().await?;
+    Item::new().sku("A-1".to_string()).price(5).save().await?;
+
+    let plan = Item::explain_find(doc! { "sku": "A-1" }).await?;
+    let winning = format!("{:?}", plan.get_document("queryPlanner")?.get("winningPlan"));
+    assert!(winning.contains("IXSCAN"), "{}", winning);
+    assert!(winning.contains("sku_idx"), "{}", winning);
+
+    let plan = Item::explain_find(doc! { "price": 5 }).await?;
+    let winning = format!("{:?}", plan.get_document("queryPlanner")?.get("winningPlan"));
+    assert!(winning.contains("COLLSCAN"), "{}", winning);
 
-    // Give MongoDB TTL monitor enough time to delete the expired document
-    sleep(Duration::from_secs(65));
+    Ok(())
+}
+
+// Run test: cargo nextest run reports_duplicate_key_on_save_and_update
+#[tokio::test]
+async fn reports_duplicate_key_on_save_and_update() -> TestResult {
+    use oximod::_error::oximod_error::OximodError;
+
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("duplicate_key_test")]
+    pub struct Account {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+
+        #[index(unique)]
+        email: String,
+    }
+
+    Account::clear().await?;
+    Account::sync_indexes().await?;
+
+    Account::new().email("ada@example.com".to_string()).save().await?;
+    let id = Account::new().email("grace@example.com".to_string()).save().await?;
+
+    let result = Account::new().email("ada@example.com".to_string()).save().await;
+    assert!(matches!(result, Err(OximodError::DuplicateKey { ref key }) if key == "email_1"));
+
+    let result = Account::update_by_id(id, doc! { "$set": { "email": "ada@example.com" } }).await;
+    assert!(matches!(result, Err(OximodError::DuplicateKey { ref key }) if key == "email_1"));
+
+    Ok(())
+}
+
+// Run test: cargo nextest run lists_declared_indexes_without_db
+#[tokio::test]
+async fn lists_declared_indexes_without_db() -> TestResult {
+    #[derive(Model, Serialize, Deserialize)]
+    #[db("test")]
+    #[collection("declared_indexes_test")]
+    #[compound_index(keys("author" = 1, "created_at" = -1), name = "author_recent")]
+    pub struct Post {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+
+        #[index(unique, name = "slug_idx")]
+        slug: String,
+
+        #[index(expire_after_secs = 60)]
+        created_at: Option<DateTime>,
+
+        author: String,
+    }
+
+    let indexes = Post::declared_indexes();
+    assert_eq!(indexes.len(), 3);
+
+    let slug = &indexes[0];
+    assert_eq!(slug.keys, doc! { "slug": 1 });
+    let options = slug.options.as_ref().expect("slug index has options");
+    assert_eq!(options.name.as_deref(), Some("slug_idx"));
+    assert_eq!(options.unique, Some(true));
+
+    let ttl = &indexes[1];
+    assert_eq!(ttl.keys, doc! { "created_at": 1 });
+    assert_eq!(
+        ttl.options.as_ref().and_then(|o| o.expire_after),
+        Some(Duration::from_secs(60))
+    );
+
+    let compound = &indexes[2];
+    assert_eq!(compound.keys, doc! { "author": 1, "created_at": -1 });
+    assert_eq!(
+        compound.options.as_ref().and_then(|o| o.name.as_deref()),
+        Some("author_recent")
+    );
 
-    let remaining = Session::find(doc! {}).await?;
-    assert_eq!(remaining.len(), 0, "Expected document to be expired and deleted");
+    let _ = Post::new().slug("hello".to_string()).author("alice".to_string());
 
     Ok(())
 }
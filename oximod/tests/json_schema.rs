@@ -0,0 +1,193 @@
+use mongodb::bson::{ doc, oid::ObjectId, DateTime };
+use oximod::{ _error::oximod_error::OximodError, Model };
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+mod common;
+use common::init;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("json_schema")]
+#[model(json_schema = true)]
+pub struct Account {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    #[validate(min_length = 3, max_length = 30)]
+    username: String,
+    #[validate(min = 0, max = 150)]
+    age: i32,
+    #[validate(required, one_of("free", "pro"))]
+    plan: Option<String>,
+    #[validate(non_empty)]
+    nickname: Option<String>,
+    #[validate(positive)]
+    balance: f64,
+    #[validate(max_items = 3)]
+    tags: Vec<String>,
+    active: bool,
+    created_at: Option<DateTime>,
+}
+
+// Run test: cargo nextest run generates_json_schema_from_rules
+#[tokio::test]
+async fn generates_json_schema_from_rules() -> TestResult {
+    let schema = Account::json_schema().expect("json_schema = true");
+
+    assert_eq!(
+        schema,
+        doc! {
+            "bsonType": "object",
+            "required": ["plan"],
+            "properties": {
+                "username": { "bsonType": "string", "minLength": 3, "maxLength": 30 },
+                "age": { "bsonType": ["int", "long"], "minimum": 0, "maximum": 150 },
+                "plan": { "bsonType": "string", "enum": ["free", "pro"] },
+                "nickname": { "bsonType": ["string", "null"], "minLength": 1 },
+                "balance": { "bsonType": "double", "minimum": 0, "exclusiveMinimum": true },
+                "tags": { "bsonType": "array", "items": { "bsonType": "string" }, "maxItems": 3 },
+                "active": { "bsonType": "bool" },
+                "created_at": { "bsonType": ["date", "null"] },
+            },
+        }
+    );
+
+    Ok(())
+}
+
+// Run test: cargo nextest run json_schema_is_opt_in
+#[tokio::test]
+async fn json_schema_is_opt_in() -> TestResult {
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("json_schema")]
+    pub struct Plain {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        #[validate(min_length = 3)]
+        name: String,
+    }
+
+    assert_eq!(Plain::json_schema(), None);
+    let _ = Plain::new().name("abc".to_string());
+
+    Ok(())
+}
+
+// Run test: cargo nextest run merges_bounds_into_the_tightest_one
+#[tokio::test]
+async fn merges_bounds_into_the_tightest_one() -> TestResult {
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("json_schema_bounds")]
+    #[model(json_schema = true)]
+    pub struct Bounds {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        #[validate(exclusive_min = 0)]
+        #[validate(min = 1)]
+        inclusive_wins: f64,
+        #[validate(min = 5, positive)]
+        min_over_positive: i32,
+        #[validate(min = 0, exclusive_min = 0)]
+        exclusive_on_tie: f64,
+        #[validate(exclusive_max = 10)]
+        #[validate(max = 5)]
+        max_over_exclusive: f64,
+        #[validate(max = 0, negative)]
+        negative_on_tie: i32,
+        #[validate(min_length = 3, non_empty, max_length = 10, len = 8)]
+        name: String,
+    }
+
+    let schema = Bounds::json_schema().expect("json_schema = true");
+
+    assert_eq!(
+        schema,
+        doc! {
+            "bsonType": "object",
+            "properties": {
+                "inclusive_wins": { "bsonType": "double", "minimum": 1 },
+                "min_over_positive": { "bsonType": ["int", "long"], "minimum": 5 },
+                "exclusive_on_tie": { "bsonType": "double", "minimum": 0, "exclusiveMinimum": true },
+                "max_over_exclusive": { "bsonType": "double", "maximum": 5 },
+                "negative_on_tie": { "bsonType": ["int", "long"], "maximum": 0, "exclusiveMaximum": true },
+                "name": { "bsonType": "string", "minLength": 8, "maxLength": 8 },
+            },
+        }
+    );
+    let _ = Bounds::new().name("abcdefgh".to_string());
+
+    Ok(())
+}
+
+// Run test: cargo nextest run nullable_one_of_accepts_null
+#[tokio::test]
+async fn nullable_one_of_accepts_null() -> TestResult {
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("json_schema_nullable_enum")]
+    #[model(json_schema = true)]
+    pub struct Member {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        #[validate(one_of("admin", "user"))]
+        role: Option<String>,
+    }
+
+    assert_eq!(
+        Member::json_schema().expect("json_schema = true"),
+        doc! {
+            "bsonType": "object",
+            "properties": {
+                "role": { "bsonType": ["string", "null"], "enum": ["admin", "user", null] },
+            },
+        }
+    );
+
+    init().await;
+
+    Member::get_collection()?.drop().await?;
+    Member::sync_indexes().await?;
+
+    let id = Member::new().save().await?;
+    let member = Member::find_by_id(id).await?.expect("member exists");
+    assert_eq!(member.role, None);
+
+    let result = Member::update_by_id(id, doc! { "$set": { "role": "guest" } }).await;
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+// Run test: cargo nextest run server_rejects_writes_breaking_the_schema
+#[tokio::test]
+async fn server_rejects_writes_breaking_the_schema() -> TestResult {
+    init().await;
+
+    Account::get_collection()?.drop().await?;
+    Account::sync_indexes().await?;
+    // Installing the validator again is a no-op.
+    Account::sync_indexes().await?;
+
+    let id = Account::new()
+        .username("alice".to_string())
+        .age(30)
+        .plan("pro".to_string())
+        .nickname("al".to_string())
+        .balance(10.0)
+        .tags(vec!["a".to_string()])
+        .active(true)
+        .created_at(DateTime::now())
+        .save().await?;
+    assert!(Account::exists(doc! { "_id": id }).await?);
+
+    // A raw write skips `validate()`, so only the server-side validator can catch it.
+    let result = Account::get_collection()?.insert_one(doc! { "username": "al", "age": 30 }).await;
+    assert!(result.is_err());
+
+    let result = Account::update_by_id(id, doc! { "$set": { "plan": "enterprise" } }).await;
+    assert!(matches!(result, Err(OximodError::ConnectionError(_))));
+
+    Ok(())
+}
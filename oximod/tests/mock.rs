@@ -0,0 +1,77 @@
+#![cfg(feature = "mock")]
+
+use mongodb::bson::{ doc, oid::ObjectId };
+use oximod::{ _error::oximod_error::OximodError, set_global_client_mock, Model };
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("mock_users")]
+pub struct User {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    #[validate(min_length = 3)]
+    name: String,
+    age: i32,
+    tags: Vec<String>,
+}
+
+// Run test: cargo nextest run --features mock saves_and_finds_without_a_server
+#[tokio::test]
+async fn saves_and_finds_without_a_server() -> TestResult {
+    set_global_client_mock()?;
+
+    let alice = User::new().name("alice".to_string()).age(30).tags(vec!["admin".to_string()]).save().await?;
+    let bob = User::new().name("bob".to_string()).age(30).save().await?;
+
+    let found = User::find_by_id(alice).await?.expect("alice is stored");
+    assert_eq!(found._id, Some(alice));
+    assert_eq!(found.name, "alice");
+
+    let found = User::find_one(doc! { "name": "bob" }).await?.expect("bob is stored");
+    assert_eq!(found._id, Some(bob));
+
+    // Numbers compare by value and arrays match any element, as on the server.
+    assert_eq!(User::find(doc! { "age": 30_i64 }).await?.len(), 2);
+    assert_eq!(User::find(doc! { "tags": "admin" }).await?.len(), 1);
+    assert!(User::find_one(doc! { "name": "carol" }).await?.is_none());
+
+    Ok(())
+}
+
+// Run test: cargo nextest run --features mock validates_before_storing
+#[tokio::test]
+async fn validates_before_storing() -> TestResult {
+    set_global_client_mock()?;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("mock_validated")]
+    pub struct Account {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        #[validate(min_length = 3)]
+        name: String,
+    }
+
+    let result = Account::new().name("al".to_string()).save().await;
+    assert!(matches!(result, Err(OximodError::ValidationError(_))));
+    assert!(Account::find(doc! {}).await?.is_empty());
+
+    Ok(())
+}
+
+// Run test: cargo nextest run --features mock rejects_what_it_cannot_evaluate
+#[tokio::test]
+async fn rejects_what_it_cannot_evaluate() -> TestResult {
+    set_global_client_mock()?;
+
+    let result = User::find(doc! { "age": { "$gt": 18 } }).await;
+    assert!(matches!(result, Err(OximodError::CommandError(_))));
+
+    let result = User::update(doc! {}, doc! { "$set": { "age": 1 } }).await;
+    assert!(matches!(result, Err(OximodError::GlobalClientMissing(_))));
+
+    Ok(())
+}
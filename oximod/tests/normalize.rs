@@ -0,0 +1,86 @@
+use mongodb::bson::{ doc, oid::ObjectId, Document };
+use oximod::{ _error::oximod_error::OximodError, Model };
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+mod common;
+use common::init;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("normalize")]
+pub struct Account {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[index(unique)]
+    #[normalize(trim, lowercase)]
+    email: String,
+
+    #[normalize(uppercase)]
+    country: Option<String>,
+}
+
+// Run test: cargo nextest run normalizes_case_on_serialization
+#[tokio::test]
+async fn normalizes_case_on_serialization() -> TestResult {
+    let account = Account::default()
+        .email(" User@Example.COM ".to_string())
+        .country("de".to_string());
+
+    let document = Document::try_from(&account)?;
+    assert_eq!(document, doc! { "email": "user@example.com", "country": "DE" });
+
+    let account = Account::default().email("a@b.c".to_string());
+    let document = Document::try_from(&account)?;
+    assert_eq!(document, doc! { "email": "a@b.c", "country": null });
+    Ok(())
+}
+
+// Run test: cargo nextest run rejects_emails_differing_only_in_case
+#[tokio::test]
+async fn rejects_emails_differing_only_in_case() -> TestResult {
+    init().await;
+
+    Account::clear().await?;
+    Account::sync_indexes().await?;
+
+    Account::default().email("user@x.com".to_string()).save().await?;
+    let result = Account::default().email("User@X.com".to_string()).save().await;
+    assert!(matches!(result, Err(OximodError::DuplicateKey { .. })));
+
+    let stored = Account::find_one(doc! { "email": "user@x.com" }).await?.expect("stored");
+    assert_eq!(stored.email, "user@x.com");
+    Ok(())
+}
+
+// Run test: cargo nextest run validates_normalized_values
+#[tokio::test]
+async fn validates_normalized_values() -> TestResult {
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("normalize")]
+    pub struct Member {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+
+        #[normalize(lowercase)]
+        #[validate(one_of("admin", "user"))]
+        role: String,
+
+        #[normalize(trim)]
+        #[validate(trimmed, min_length = 3)]
+        nickname: Option<String>,
+    }
+
+    let member = Member::default().role("Admin".to_string()).nickname("  Ada  ".to_string());
+    member.validate()?;
+    assert_eq!(member.role, "Admin");
+
+    // Trimming leaves too few characters.
+    let member = Member::default().role("user".to_string()).nickname(" Al ".to_string());
+    assert!(format!("{:?}", member.validate()).contains("at least 3 characters"));
+
+    assert!(Member::default().role("Owner".to_string()).validate().is_err());
+    Ok(())
+}
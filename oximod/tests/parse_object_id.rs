@@ -0,0 +1,23 @@
+use mongodb::bson::oid::ObjectId;
+use oximod::{ parse_object_id, _error::oximod_error::OximodError };
+use testresult::TestResult;
+
+// Run test: cargo nextest run parses_valid_object_id
+#[tokio::test]
+async fn parses_valid_object_id() -> TestResult {
+    let id = ObjectId::new();
+    assert_eq!(parse_object_id(&id.to_hex())?, id);
+    Ok(())
+}
+
+// Run test: cargo nextest run rejects_invalid_object_id
+#[tokio::test]
+async fn rejects_invalid_object_id() -> TestResult {
+    for input in ["", "123", "zzzzzzzzzzzzzzzzzzzzzzzz", "652efcddfc13ae2c820000011"] {
+        match parse_object_id(input) {
+            Err(OximodError::InvalidId(msg)) => assert!(msg.contains(&format!("'{}'", input))),
+            other => panic!("expected InvalidId for {:?}, got {:?}", input, other),
+        }
+    }
+    Ok(())
+}
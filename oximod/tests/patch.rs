@@ -0,0 +1,163 @@
+use mongodb::bson::{ self, doc, oid::ObjectId };
+use oximod::{ _error::oximod_error::OximodError, Model };
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+mod common;
+use common::init;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("patch")]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    display_name: String,
+    age: u64,
+    #[serde(rename = "nick")]
+    nickname: Option<String>,
+    #[serde(skip)]
+    cached: i32,
+}
+
+// Run test: cargo nextest run builds_set_from_provided_fields
+#[tokio::test]
+async fn builds_set_from_provided_fields() -> TestResult {
+    let patch: UserPatch = bson::from_document(
+        doc! { "displayName": "Alice", "nick": null, "_id": ObjectId::new() }
+    )?;
+    assert!(!patch.is_empty());
+    assert_eq!(patch.display_name.as_deref(), Some("Alice"));
+    assert_eq!(patch.to_update_doc()?, doc! { "$set": { "displayName": "Alice" } });
+
+    let patch = UserPatch { nickname: Some("Al".to_string()), age: Some(30), ..Default::default() };
+    assert_eq!(patch.to_update_doc()?, doc! { "$set": { "age": 30_i64, "nick": "Al" } });
+
+    Ok(())
+}
+
+// Run test: cargo nextest run empty_patch_is_empty
+#[tokio::test]
+async fn empty_patch_is_empty() -> TestResult {
+    let patch: UserPatch = bson::from_document(doc! {})?;
+    assert!(patch.is_empty());
+    assert_eq!(patch.to_update_doc()?, doc! {});
+
+    Ok(())
+}
+
+// Run test: cargo nextest run reports_unrepresentable_patch_values
+#[tokio::test]
+async fn reports_unrepresentable_patch_values() -> TestResult {
+    let patch = UserPatch { age: Some(u64::MAX), ..Default::default() };
+    assert!(matches!(patch.to_update_doc(), Err(OximodError::SerializationError(_))));
+
+    Ok(())
+}
+
+// Run test: cargo nextest run applies_patch_to_stored_document
+#[tokio::test]
+async fn applies_patch_to_stored_document() -> TestResult {
+    init().await;
+
+    User::clear().await?;
+    let id = User::new()
+        .display_name("Alice".to_string())
+        .age(30_u64)
+        .nickname("Al")
+        .cached(1)
+        .save().await?;
+
+    let patch = UserPatch { display_name: Some("Alicia".to_string()), ..Default::default() };
+    User::update_by_id(id, patch.to_update_doc()?).await?;
+
+    let user = User::find_by_id(id).await?.expect("user exists");
+    assert_eq!(user.display_name, "Alicia");
+    assert_eq!(user.age, 30);
+    assert_eq!(user.nickname.as_deref(), Some("Al"));
+
+    Ok(())
+}
+
+/// Stores a count as a decimal string, to check that patches honor `with`.
+mod count_as_string {
+    use serde::{ Deserialize, Deserializer, Serializer };
+
+    pub fn serialize<S: Serializer>(count: &u32, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&count.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// The same for an optional count.
+mod optional_count_as_string {
+    use serde::{ Deserialize, Deserializer, Serializer };
+
+    pub fn serialize<S: Serializer>(count: &Option<u32>, serializer: S) -> Result<S::Ok, S::Error> {
+        match count {
+            Some(count) => serializer.serialize_str(&count.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u32>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|count| count.parse().map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("patch_normalize")]
+pub struct Account {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    #[normalize(trim, lowercase)]
+    email: String,
+    #[serde(with = "count_as_string")]
+    visits: u32,
+    #[serde(
+        serialize_with = "optional_count_as_string::serialize",
+        deserialize_with = "optional_count_as_string::deserialize"
+    )]
+    best: Option<u32>,
+}
+
+// Run test: cargo nextest run patch_normalizes_provided_values
+#[tokio::test]
+async fn patch_normalizes_provided_values() -> TestResult {
+    let patch: AccountPatch = bson::from_document(doc! { "email": " User@X.com " })?;
+    assert_eq!(patch.to_update_doc()?, doc! { "$set": { "email": "user@x.com" } });
+
+    init().await;
+
+    Account::clear().await?;
+    let id = Account::new().email("old@x.com".to_string()).visits(1).save().await?;
+    Account::update_by_id(id, patch.to_update_doc()?).await?;
+
+    let account = Account::find_by_id(id).await?.expect("account exists");
+    assert_eq!(account.email, "user@x.com");
+
+    Ok(())
+}
+
+// Run test: cargo nextest run patch_uses_field_serde_codecs
+#[tokio::test]
+async fn patch_uses_field_serde_codecs() -> TestResult {
+    let patch: AccountPatch = bson::from_document(doc! { "visits": "7", "best": "9" })?;
+    assert_eq!(patch.visits, Some(7));
+    assert_eq!(patch.best, Some(9));
+    assert_eq!(patch.to_update_doc()?, doc! { "$set": { "visits": "7", "best": "9" } });
+
+    let patch: AccountPatch = bson::from_document(doc! { "best": null })?;
+    assert!(patch.is_empty());
+
+    assert!(bson::from_document::<AccountPatch>(doc! { "visits": 7 }).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use mongodb::bson::{ doc, oid::ObjectId };
+use oximod::Model;
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+mod common;
+use common::init;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("populate_users")]
+pub struct User {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    name: String,
+}
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("populate_orders")]
+pub struct Order {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[reference(User)]
+    user_id: ObjectId,
+
+    #[reference(User)]
+    reviewer_id: Option<ObjectId>,
+
+    #[reference(User)]
+    watcher_ids: Vec<ObjectId>,
+}
+
+// Run test: cargo nextest run populates_references
+#[tokio::test]
+async fn populates_references() -> TestResult {
+    init().await;
+    User::clear().await?;
+
+    let alice = User::new().name("Alice".to_string()).save().await?;
+    let bob = User::new().name("Bob".to_string()).save().await?;
+
+    let order = Order::new().user_id(alice).reviewer_id(bob).watcher_ids(vec![alice, bob, ObjectId::new()]);
+
+    assert_eq!(order.populate_user().await?.unwrap().name, "Alice");
+    assert_eq!(order.populate_reviewer().await?.unwrap().name, "Bob");
+
+    let mut watchers = order
+        .populate_watchers().await?
+        .into_iter()
+        .map(|user| user.name)
+        .collect::<Vec<_>>();
+    watchers.sort();
+    assert_eq!(watchers, vec!["Alice", "Bob"]);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run dangling_references_return_none
+#[tokio::test]
+async fn dangling_references_return_none() -> TestResult {
+    init().await;
+
+    let order = Order::new().user_id(ObjectId::new());
+
+    assert!(order.populate_user().await?.is_none());
+    assert!(order.populate_reviewer().await?.is_none());
+    assert!(order.populate_watchers().await?.is_empty());
+    assert_eq!(Order::count(doc! { "_id": ObjectId::new() }).await?, 0);
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use mongodb::bson::{ doc, oid::ObjectId };
+use oximod::Model;
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+mod common;
+use common::init;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("query_builder")]
+pub struct User {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    name: String,
+    age: i32,
+    active: bool,
+}
+
+// Run test: cargo nextest run merges_filters_with_and
+#[tokio::test]
+async fn merges_filters_with_and() -> TestResult {
+    assert_eq!(User::query().build_filter(), doc! {});
+
+    let active = User::query().filter(doc! { "active": true });
+    assert_eq!(active.build_filter(), doc! { "active": true });
+
+    let adults = active.clone().filter(doc! { "age": { "$gte": 18 } });
+    assert_eq!(
+        adults.build_filter(),
+        doc! { "$and": [{ "active": true }, { "age": { "$gte": 18 } }] }
+    );
+    // The base query is unaffected by narrowing a clone
+    assert_eq!(active.build_filter(), doc! { "active": true });
+
+    Ok(())
+}
+
+// Run test: cargo nextest run runs_chained_queries
+#[tokio::test]
+async fn runs_chained_queries() -> TestResult {
+    init().await;
+
+    User::clear().await?;
+    for (name, age, active) in [("Ann", 17, true), ("Ben", 25, true), ("Cid", 40, true), ("Dee", 33, false)] {
+        User::new().name(name.to_string()).age(age).active(active).save().await?;
+    }
+
+    let active = User::query().filter(doc! { "active": true });
+
+    let names: Vec<String> = active
+        .clone()
+        .sort(doc! { "age": -1 })
+        .skip(1)
+        .limit(1)
+        .all().await?
+        .into_iter()
+        .map(|user| user.name)
+        .collect();
+    assert_eq!(names, vec!["Ben".to_string()]);
+
+    let youngest = active.clone().sort(doc! { "age": 1 }).one().await?;
+    assert_eq!(youngest.map(|user| user.name), Some("Ann".to_string()));
+
+    assert_eq!(active.clone().count().await?, 3);
+    assert_eq!(active.clone().filter(doc! { "age": { "$gte": 18 } }).count().await?, 2);
+
+    let updated = active.clone().update(doc! { "$inc": { "age": 1 } }).await?;
+    assert_eq!(updated.modified_count, 3);
+
+    let deleted = active.delete().await?;
+    assert_eq!(deleted.deleted_count, 3);
+    assert_eq!(User::count(doc! {}).await?, 1);
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use mongodb::bson::{ doc, oid::ObjectId };
+use oximod::Model;
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+mod common;
+use common::init;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("replace_one")]
+pub struct User {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    name: String,
+    #[validate(email)]
+    email: Option<String>,
+}
+
+// Run test: cargo nextest run replaces_with_validated_model
+#[tokio::test]
+async fn replaces_with_validated_model() -> TestResult {
+    init().await;
+
+    User::clear().await?;
+
+    let id = User::new().name("Alice".to_string()).email("alice@example.com").save().await?;
+
+    let mut user = User::find_by_id(id).await?.expect("user was saved");
+    user.email = Some("alice@new.example.com".to_string());
+    let result = User::replace_one(doc! { "_id": id }, &user).await?;
+    assert_eq!(result.matched_count, 1);
+    assert_eq!(result.modified_count, 1);
+
+    let stored = User::find_by_id(id).await?.expect("user still exists");
+    assert_eq!(stored.email.as_deref(), Some("alice@new.example.com"));
+
+    Ok(())
+}
+
+// Run test: cargo nextest run refuses_invalid_replacement
+#[tokio::test]
+async fn refuses_invalid_replacement() -> TestResult {
+    init().await;
+
+    User::delete(doc! { "name": "Bob" }).await?;
+
+    let id = User::new().name("Bob".to_string()).email("bob@example.com").save().await?;
+
+    let invalid = User::new().name("Bob".to_string()).email("not-an-email");
+    let result = User::replace_one(doc! { "_id": id }, &invalid).await;
+    assert!(matches!(result, Err(oximod::_error::oximod_error::OximodError::ValidationError(_))));
+
+    let stored = User::find_by_id(id).await?.expect("user still exists");
+    assert_eq!(stored.email.as_deref(), Some("bob@example.com"));
+
+    Ok(())
+}
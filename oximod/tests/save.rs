@@ -1,3 +1,5 @@
+#![allow(clippy::clone_on_copy)]
+
 use mongodb::bson::oid::ObjectId;
 use oximod::Model;
 use testresult::TestResult;
@@ -58,3 +60,40 @@ async fn saves_document_with_id_correctly() -> TestResult {
 
     Ok(())
 }
+
+// Run test: cargo nextest run saves_only_if_no_document_matches
+#[tokio::test]
+async fn saves_only_if_no_document_matches() -> TestResult {
+    use mongodb::bson::doc;
+
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize)]
+    #[db("test")]
+    #[collection("save_if_not_exists")]
+    pub struct Member {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        #[validate(min_length = 3)]
+        email: String,
+        name: String,
+    }
+
+    Member::clear().await?;
+
+    let member = Member::default().email("alice@example.com".to_string()).name("Alice".to_string());
+    assert!(member.save_if_not_exists(doc! { "email": "alice@example.com" }).await?);
+
+    let duplicate = Member::default().email("alice@example.com".to_string()).name("Other".to_string());
+    assert!(!duplicate.save_if_not_exists(doc! { "email": "alice@example.com" }).await?);
+
+    let stored = Member::find(doc! { "email": "alice@example.com" }).await?;
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].name, "Alice");
+
+    let invalid = Member::default().email("a".to_string());
+    assert!(invalid.save_if_not_exists(doc! { "email": "a" }).await.is_err());
+    assert_eq!(Member::count(doc! {}).await?, 1);
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use mongodb::bson::{ doc, oid::ObjectId };
+use oximod::{ _error::oximod_error::OximodError, Model };
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+mod common;
+use common::init;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("save_many_lenient")]
+pub struct Upload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    #[index(unique)]
+    sku: String,
+    #[validate(min = 0)]
+    quantity: i32,
+}
+
+// Run test: cargo nextest run saves_good_rows_and_reports_bad_ones
+#[tokio::test]
+async fn saves_good_rows_and_reports_bad_ones() -> TestResult {
+    init().await;
+
+    Upload::clear().await?;
+    Upload::new().sku("taken".to_string()).quantity(1).save().await?;
+
+    let rows = vec![
+        Upload::new().sku("a".to_string()).quantity(1),
+        Upload::new().sku("b".to_string()).quantity(-5),
+        Upload::new().sku("taken".to_string()).quantity(2),
+        Upload::new().sku("c".to_string()).quantity(3),
+    ];
+
+    let result = Upload::save_many_lenient(rows).await?;
+    assert!(!result.is_complete());
+    assert_eq!(result.inserted.len(), 2);
+
+    let failed: Vec<usize> = result.failures.iter().map(|(index, _)| *index).collect();
+    assert_eq!(failed, [1, 2]);
+    assert!(matches!(result.failures[0].1, OximodError::ValidationError(_)));
+    assert!(matches!(&result.failures[1].1, OximodError::DuplicateKey { key } if key == "sku_1"));
+
+    for id in &result.inserted {
+        assert!(Upload::exists(doc! { "_id": id }).await?);
+    }
+    assert_eq!(Upload::count(doc! {}).await?, 3);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run saves_nothing_for_empty_or_invalid_batches
+#[tokio::test]
+async fn saves_nothing_for_empty_or_invalid_batches() -> TestResult {
+    init().await;
+
+    let result = Upload::save_many_lenient(vec![]).await?;
+    assert!(result.is_complete());
+    assert!(result.inserted.is_empty());
+
+    let result = Upload::save_many_lenient(vec![Upload::new().sku("neg".to_string()).quantity(-1)]).await?;
+    assert!(result.inserted.is_empty());
+    assert_eq!(result.failures.len(), 1);
+    assert!(!Upload::exists(doc! { "sku": "neg" }).await?);
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use mongodb::bson::oid::ObjectId;
+use oximod::Model;
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+// Run test: cargo nextest run masks_sensitive_fields_in_debug_output
+#[tokio::test]
+async fn masks_sensitive_fields_in_debug_output() -> TestResult {
+    #[derive(Model, Serialize, Deserialize)]
+    #[db("test")]
+    #[collection("sensitive")]
+    pub struct Account {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        name: String,
+        #[sensitive]
+        password_hash: String,
+        #[sensitive]
+        api_key: Option<String>,
+    }
+
+    let account = Account::default()
+        .name("alice".to_string())
+        .password_hash("$argon2id$secret".to_string())
+        .api_key("sk_live_123".to_string());
+
+    let output = format!("{:?}", account);
+    assert_eq!(
+        output,
+        r#"Account { _id: None, name: "alice", password_hash: "***", api_key: "***" }"#
+    );
+    assert!(!output.contains("secret"));
+    assert!(!output.contains("sk_live"));
+
+    Ok(())
+}
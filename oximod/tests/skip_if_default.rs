@@ -0,0 +1,48 @@
+use mongodb::bson::{ doc, oid::ObjectId, Document };
+use oximod::Model;
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+#[derive(Model, Serialize, Deserialize, Debug, PartialEq)]
+#[db("test")]
+#[collection("skip_if_default")]
+pub struct Profile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    name: String,
+    #[skip_if_default]
+    #[serde(default)]
+    bio: String,
+    #[skip_if_default]
+    #[serde(default, rename = "loginCount")]
+    login_count: i32,
+    #[skip_if_default]
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+// Run test: cargo nextest run omits_fields_equal_to_their_default
+#[tokio::test]
+async fn omits_fields_equal_to_their_default() -> TestResult {
+    let profile = Profile::default().name("alice".to_string());
+    let document = Document::try_from(&profile)?;
+    assert_eq!(document, doc! { "name": "alice" });
+
+    let restored = Profile::try_from(document)?;
+    assert_eq!(restored, profile);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run keeps_fields_with_non_default_values
+#[tokio::test]
+async fn keeps_fields_with_non_default_values() -> TestResult {
+    let profile = Profile::default()
+        .name("alice".to_string())
+        .login_count(3)
+        .tags(vec!["admin".to_string()]);
+    let document = Document::try_from(&profile)?;
+    assert_eq!(document, doc! { "name": "alice", "loginCount": 3, "tags": ["admin"] });
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use mongodb::bson::{ doc, oid::ObjectId };
+use oximod::{ with_transaction, with_transaction_on, Model };
+use oximod::_error::oximod_error::OximodError;
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+mod common;
+use common::init;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("transaction_accounts")]
+pub struct Account {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    owner: String,
+    balance: i64,
+}
+
+// Run test: cargo nextest run commits_transaction_on_ok
+#[tokio::test]
+async fn commits_transaction_on_ok() -> TestResult {
+    init().await;
+    Account::clear().await?;
+
+    let alice = Account::new().owner("alice".to_string()).balance(500).save().await?;
+    let bob = Account::new().owner("bob".to_string()).balance(0).save().await?;
+
+    with_transaction(|session| Box::pin(async move {
+        Account::update_by_id_with_session(alice, doc! { "$inc": { "balance": -200 } }, session).await?;
+        Account::update_by_id_with_session(bob, doc! { "$inc": { "balance": 200 } }, session).await?;
+        Ok(())
+    })).await?;
+
+    assert_eq!(Account::find_by_id(alice).await?.unwrap().balance, 300);
+    assert_eq!(Account::find_by_id(bob).await?.unwrap().balance, 200);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run aborts_transaction_on_err
+#[tokio::test]
+async fn aborts_transaction_on_err() -> TestResult {
+    init().await;
+    Account::clear().await?;
+
+    let alice = Account::new().owner("alice".to_string()).balance(100).save().await?;
+
+    let result: Result<(), OximodError> = with_transaction(|session| Box::pin(async move {
+        Account::update_by_id_with_session(alice, doc! { "$inc": { "balance": -200 } }, session).await?;
+        Account::new().owner("carol".to_string()).balance(200).save_with_session(session).await?;
+
+        let account = Account::find_by_id_with_session(alice, session).await?.unwrap();
+        if account.balance < 0 {
+            return Err(OximodError::ValidationError("insufficient funds".to_string()));
+        }
+        Ok(())
+    })).await;
+
+    assert!(matches!(result, Err(OximodError::ValidationError(_))));
+    assert_eq!(Account::find_by_id(alice).await?.unwrap().balance, 100);
+    assert!(Account::find_one(doc! { "owner": "carol" }).await?.is_none());
+
+    Ok(())
+}
+
+// Run test: cargo nextest run rejects_transaction_on_unknown_client
+#[tokio::test]
+async fn rejects_transaction_on_unknown_client() -> TestResult {
+    let result: Result<(), OximodError> = with_transaction_on("transaction_missing", |session| Box::pin(async move {
+        Account::new().owner("dave".to_string()).balance(1).save_with_session(session).await?;
+        Ok(())
+    })).await;
+
+    assert!(matches!(result, Err(OximodError::GlobalClientMissing(_))));
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use mongodb::bson::{ doc, oid::ObjectId, Document };
+use oximod::Model;
+use oximod::_error::oximod_error::OximodError;
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+#[derive(Model, Serialize, Deserialize, Debug, PartialEq)]
+#[db("test")]
+#[collection("try_from")]
+pub struct User {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    name: String,
+    age: i32,
+}
+
+// Run test: cargo nextest run converts_document_to_model
+#[tokio::test]
+async fn converts_document_to_model() -> TestResult {
+    let user = User::try_from(doc! { "name": "Alice", "age": 30 })?;
+    assert_eq!(user, User::new().name("Alice".to_string()).age(30));
+
+    let err = User::try_from(doc! { "name": "Bob", "age": "thirty" }).unwrap_err();
+    assert!(matches!(err, OximodError::SerializationError(_)));
+
+    Ok(())
+}
+
+// Run test: cargo nextest run converts_model_to_document
+#[tokio::test]
+async fn converts_model_to_document() -> TestResult {
+    let user = User::new().name("Alice".to_string()).age(30);
+    let document = Document::try_from(&user)?;
+
+    assert_eq!(document, doc! { "name": "Alice", "age": 30 });
+    assert_eq!(User::try_from(document)?, user);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run names_document_id_in_deserialization_error
+#[tokio::test]
+async fn names_document_id_in_deserialization_error() -> TestResult {
+    let id = ObjectId::new();
+    let err = User::try_from(doc! { "_id": id, "name": "Bob" }).unwrap_err();
+
+    match err {
+        OximodError::SerializationError(message) => {
+            assert!(message.contains("missing field `age`"), "{message}");
+            assert!(message.contains(&id.to_hex()), "{message}");
+        }
+        other => panic!("expected a serialization error, got {other:?}"),
+    }
+
+    Ok(())
+}
@@ -45,3 +45,45 @@ async fn updates_multiple_documents_correctly() -> TestResult {
 
     Ok(())
 }
+
+// Run test: cargo nextest run returns_ids_of_updated_documents
+#[tokio::test]
+async fn returns_ids_of_updated_documents() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("update_returning_ids")]
+    pub struct Order {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        status: String,
+    }
+
+    Order::clear().await?;
+
+    let paid_a = Order::new().status("paid".to_string()).save().await?;
+    let paid_b = Order::new().status("paid".to_string()).save().await?;
+    let pending = Order::new().status("pending".to_string()).save().await?;
+
+    let mut ids = Order::update_returning_ids(
+        doc! { "status": "paid" },
+        doc! { "$set": { "status": "shipped" } }
+    ).await?;
+    ids.sort();
+    let mut expected = vec![paid_a, paid_b];
+    expected.sort();
+    assert_eq!(ids, expected);
+
+    assert_eq!(Order::count(doc! { "status": "shipped" }).await?, 2);
+    assert_eq!(Order::find_by_id(pending).await?.map(|order| order.status), Some("pending".to_string()));
+
+    // Nothing matches any more, so nothing is updated.
+    let ids = Order::update_returning_ids(
+        doc! { "status": "paid" },
+        doc! { "$set": { "status": "shipped" } }
+    ).await?;
+    assert!(ids.is_empty());
+
+    Ok(())
+}
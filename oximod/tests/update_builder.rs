@@ -0,0 +1,179 @@
+use mongodb::bson::{ doc, oid::ObjectId, Document };
+use oximod::{ _error::oximod_error::OximodError, Model };
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+mod common;
+use common::init;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("update_builder")]
+pub struct User {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    name: String,
+    age: i32,
+    nickname: Option<String>,
+    tags: Vec<String>,
+}
+
+// Run test: cargo nextest run builds_typed_set_updates
+#[tokio::test]
+async fn builds_typed_set_updates() -> TestResult {
+    let update = UserUpdate::new()
+        .name("Alice".to_string())
+        .age(30)
+        .nickname("Al")
+        .tags(vec!["admin".to_string()])
+        .build_update()?;
+
+    assert_eq!(
+        update,
+        doc! { "$set": { "name": "Alice", "age": 30, "nickname": "Al", "tags": ["admin"] } }
+    );
+
+    assert_eq!(UserUpdate::new().build_update()?, doc! {});
+
+    Ok(())
+}
+
+// Run test: cargo nextest run combines_set_with_unset_and_inc
+#[tokio::test]
+async fn combines_set_with_unset_and_inc() -> TestResult {
+    let update: Document = UserUpdate::new()
+        .name("Bob".to_string())
+        .unset(user_fields::NICKNAME)
+        .inc(user_fields::AGE, 1)
+        .try_into()?;
+
+    assert_eq!(
+        update,
+        doc! { "$set": { "name": "Bob" }, "$unset": { "nickname": "" }, "$inc": { "age": 1 } }
+    );
+
+    Ok(())
+}
+
+// Run test: cargo nextest run update_uses_serialized_field_names
+#[tokio::test]
+async fn update_uses_serialized_field_names() -> TestResult {
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("update_builder")]
+    #[serde(rename_all = "camelCase")]
+    pub struct Customer {
+        #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+        id: Option<ObjectId>,
+        first_name: String,
+        #[serde(rename = "mail")]
+        email_address: String,
+        login_count: u64,
+    }
+
+    let update = CustomerUpdate::new()
+        .first_name("Ada".to_string())
+        .email_address("ada@example.com".to_string())
+        .build_update()?;
+    assert_eq!(update, doc! { "$set": { "firstName": "Ada", "mail": "ada@example.com" } });
+
+    let result = CustomerUpdate::new().login_count(u64::MAX).first_name("Ada".to_string()).build_update();
+    assert!(matches!(result, Err(OximodError::SerializationError(_))));
+
+    let _ = Customer::new().id(ObjectId::new());
+
+    Ok(())
+}
+
+// Run test: cargo nextest run applies_typed_update
+#[tokio::test]
+async fn applies_typed_update() -> TestResult {
+    init().await;
+
+    User::clear().await?;
+
+    let id = User::new()
+        .name("Carol".to_string())
+        .age(40)
+        .nickname("Caz".to_string())
+        .save().await?;
+
+    let update = UserUpdate::new().name("Caroline".to_string()).unset(user_fields::NICKNAME).build_update()?;
+    User::update_by_id(id, update).await?;
+
+    let user = User::find_by_id(id).await?.expect("user exists");
+    assert_eq!(user.name, "Caroline");
+    assert_eq!(user.age, 40);
+    assert_eq!(user.nickname, None);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run builds_updates_for_models_with_a_set_field
+#[tokio::test]
+async fn builds_updates_for_models_with_a_set_field() -> TestResult {
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("update_builder")]
+    pub struct Puzzle {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        set: String,
+    }
+
+    let puzzle = Puzzle::new().set("easy".to_string());
+    assert_eq!(PuzzleUpdate::new().set(puzzle.set).build_update()?, doc! { "$set": { "set": "easy" } });
+
+    Ok(())
+}
+
+/// Stores a count as a decimal string, to check that update setters honor `serialize_with`.
+mod count_as_string {
+    use serde::{ Deserialize, Deserializer, Serializer };
+
+    pub fn serialize<S: Serializer>(count: &u32, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&count.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+// Run test: cargo nextest run update_normalizes_and_serializes_like_save
+#[tokio::test]
+async fn update_normalizes_and_serializes_like_save() -> TestResult {
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("update_builder_normalize")]
+    pub struct Account {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        #[normalize(trim, lowercase)]
+        email: String,
+        #[normalize(uppercase)]
+        code: Option<String>,
+        #[serde(with = "count_as_string")]
+        visits: u32,
+    }
+
+    let update = AccountUpdate::new()
+        .email(" User@X.com ".to_string())
+        .code("ab")
+        .visits(7)
+        .build_update()?;
+    assert_eq!(update, doc! { "$set": { "email": "user@x.com", "code": "AB", "visits": "7" } });
+
+    init().await;
+
+    Account::clear().await?;
+    let id = Account::new().email("old@x.com".to_string()).visits(1).save().await?;
+    Account::update_by_id(id, update).await?;
+
+    let account = Account::find_by_id(id).await?.expect("account exists");
+    assert_eq!(account.email, "user@x.com");
+    assert_eq!(account.code.as_deref(), Some("AB"));
+    assert_eq!(account.visits, 7);
+
+    Ok(())
+}
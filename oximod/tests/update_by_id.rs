@@ -1,3 +1,5 @@
+#![allow(clippy::clone_on_copy)]
+
 use mongodb::bson::{ doc, oid::ObjectId };
 use oximod::Model;
 use testresult::TestResult;
@@ -42,3 +44,37 @@ async fn updates_document_by_id_correctly() -> TestResult {
 
     Ok(())
 }
+
+// Run test: cargo nextest run updates_documents_by_ids
+#[tokio::test]
+async fn updates_documents_by_ids() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("update_by_ids")]
+    pub struct User {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        name: String,
+        active: bool,
+    }
+
+    User::clear().await?;
+
+    let mut ids = Vec::new();
+    for name in ["User1", "User2", "User3"] {
+        ids.push(User::default().name(name.to_string()).active(true).save().await?);
+    }
+
+    let result = User::update_by_ids(
+        vec![ids[0], ids[1]],
+        doc! { "$set": { "active": false } }
+    ).await?;
+    assert_eq!(result.matched_count, 2);
+    assert_eq!(result.modified_count, 2);
+
+    assert_eq!(User::count(doc! { "active": true }).await?, 1);
+
+    Ok(())
+}
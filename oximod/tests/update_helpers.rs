@@ -0,0 +1,35 @@
+use mongodb::bson::{ doc, Document };
+use oximod::update::{ self, add_to_set, inc, pull, push, unset };
+use testresult::TestResult;
+
+// Run test: cargo nextest run builds_single_operator_updates
+#[tokio::test]
+async fn builds_single_operator_updates() -> TestResult {
+    assert_eq!(Document::from(push("tags", "rust")), doc! { "$push": { "tags": "rust" } });
+    assert_eq!(Document::from(pull("tags", "go")), doc! { "$pull": { "tags": "go" } });
+    assert_eq!(Document::from(add_to_set("tags", "rust")), doc! { "$addToSet": { "tags": "rust" } });
+    assert_eq!(Document::from(inc("views", 1)), doc! { "$inc": { "views": 1 } });
+    assert_eq!(Document::from(unset("draft")), doc! { "$unset": { "draft": "" } });
+    assert_eq!(Document::from(update::set("title", "Hello")), doc! { "$set": { "title": "Hello" } });
+    Ok(())
+}
+
+// Run test: cargo nextest run composes_operators_into_one_update
+#[tokio::test]
+async fn composes_operators_into_one_update() -> TestResult {
+    let update: Document = push("tags", "rust")
+        .inc("edits", 1)
+        .inc("views", -2)
+        .set("draft", false)
+        .into();
+
+    assert_eq!(
+        update,
+        doc! {
+            "$push": { "tags": "rust" },
+            "$inc": { "edits": 1, "views": -2 },
+            "$set": { "draft": false },
+        }
+    );
+    Ok(())
+}
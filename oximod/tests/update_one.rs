@@ -41,3 +41,69 @@ async fn updates_first_matching_document_only() -> TestResult {
 
     Ok(())
 }
+
+// Run test: cargo nextest run returns_document_after_update
+#[tokio::test]
+async fn returns_document_after_update() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("update_one_returning")]
+    pub struct Counter {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        name: String,
+        value: i32,
+    }
+
+    Counter::clear().await?;
+    Counter::new().name("visits".to_string()).value(41).save().await?;
+
+    let counter = Counter::update_one_returning(
+        doc! { "name": "visits" },
+        doc! { "$inc": { "value": 1 } }
+    ).await?;
+    assert_eq!(counter.map(|counter| counter.value), Some(42));
+
+    let missing = Counter::update_one_returning(
+        doc! { "name": "clicks" },
+        doc! { "$inc": { "value": 1 } }
+    ).await?;
+    assert!(missing.is_none());
+
+    Ok(())
+}
+
+// Run test: cargo nextest run upserts_and_returns_object_id
+#[tokio::test]
+async fn upserts_and_returns_object_id() -> TestResult {
+    init().await;
+
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("upsert_one")]
+    pub struct Counter {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        name: String,
+        value: i32,
+    }
+
+    Counter::clear().await?;
+
+    let created = Counter::upsert_one(doc! { "name": "visits" }, doc! { "$inc": { "value": 1 } }).await?;
+    assert!(created.inserted());
+    assert_eq!(created.matched, 0);
+    let id = created.upserted_id.expect("inserted");
+
+    let updated = Counter::upsert_one(doc! { "name": "visits" }, doc! { "$inc": { "value": 1 } }).await?;
+    assert!(!updated.inserted());
+    assert_eq!((updated.matched, updated.modified), (1, 1));
+
+    let counter = Counter::find_by_id(id).await?.expect("stored");
+    assert_eq!(counter.value, 2);
+    assert_eq!(counter.name, "visits");
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use mongodb::bson::oid::ObjectId;
+use oximod::Model;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_affixes")]
+pub struct Product {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[validate(starts_with = "SKU-")]
+    sku: String,
+
+    #[validate(ends_with = "@example.com")]
+    contact: Option<String>,
+
+    #[validate(includes = "rust")]
+    tags: Option<String>,
+}
+
+// Run test: cargo nextest run test_starts_with_violation
+#[tokio::test]
+async fn test_starts_with_violation() -> TestResult {
+    let product = Product::default().sku("ABC-1234".to_string());
+
+    let err = product.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("must start with 'SKU-'"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_ends_with_violation
+#[tokio::test]
+async fn test_ends_with_violation() -> TestResult {
+    let product = Product::default()
+        .sku("SKU-1234".to_string())
+        .contact("sales@example.org".to_string());
+
+    let err = product.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("must end with '@example.com'"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_includes_violation
+#[tokio::test]
+async fn test_includes_violation() -> TestResult {
+    let product = Product::default().sku("SKU-1234".to_string()).tags("go,python".to_string());
+
+    let err = product.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("must include 'rust'"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_affixes_valid_and_none_skipped
+#[tokio::test]
+async fn test_affixes_valid_and_none_skipped() -> TestResult {
+    Product::default().sku("SKU-1234".to_string()).validate()?;
+
+    Product::default()
+        .sku("SKU-1234".to_string())
+        .contact("sales@example.com".to_string())
+        .tags("rust,mongodb".to_string())
+        .validate()?;
+    Ok(())
+}
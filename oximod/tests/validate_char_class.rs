@@ -0,0 +1,92 @@
+use mongodb::bson::oid::ObjectId;
+use oximod::Model;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_char_class")]
+pub struct Member {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[validate(alphanumeric)]
+    username: String,
+
+    #[validate(alpha)]
+    first_name: Option<String>,
+
+    #[validate(numeric)]
+    pin: Option<String>,
+}
+
+// Run test: cargo nextest run test_alphanumeric_violation
+#[tokio::test]
+async fn test_alphanumeric_violation() -> TestResult {
+    let member = Member::default().username("user_1".to_string());
+
+    let err = member.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("only alphanumeric characters"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_alpha_violation
+#[tokio::test]
+async fn test_alpha_violation() -> TestResult {
+    let member = Member::default().username("user1".to_string()).first_name("Ann3".to_string());
+
+    let err = member.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("only alphabetic characters"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_numeric_violation
+#[tokio::test]
+async fn test_numeric_violation() -> TestResult {
+    let member = Member::default().username("user1".to_string()).pin("12a4".to_string());
+
+    let err = member.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("only numeric characters"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_char_class_valid
+#[tokio::test]
+async fn test_char_class_valid() -> TestResult {
+    Member::default()
+        .username("user1".to_string())
+        .first_name("Ann".to_string())
+        .pin("1234".to_string())
+        .validate()?;
+
+    // Empty strings contain no offending characters and pass
+    Member::default().username(String::new()).validate()?;
+    Ok(())
+}
+
+// Run test: cargo nextest run test_char_class_with_non_empty
+#[tokio::test]
+async fn test_char_class_with_non_empty() -> TestResult {
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("validate_char_class")]
+    pub struct Voucher {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+
+        #[validate(alphanumeric, non_empty)]
+        code: String,
+    }
+
+    Voucher::default().code("SAVE10".to_string()).validate()?;
+
+    let err = Voucher::default().code(String::new()).validate();
+    assert!(format!("{:?}", err).contains("Field 'code' must be non-empty"));
+
+    let err = Voucher::default().code("  ".to_string()).validate();
+    assert!(err.is_err());
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use mongodb::bson::{ oid::ObjectId, DateTime };
+use oximod::Model;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_dates")]
+pub struct Booking {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[default_now]
+    #[validate(after_now)]
+    starts_at: DateTime,
+
+    #[default_now]
+    #[validate(after = "2020-01-01T00:00:00Z", before = "2100-01-01T00:00:00Z")]
+    booked_at: DateTime,
+
+    #[validate(before_now)]
+    cancelled_at: Option<DateTime>,
+}
+
+fn booking(starts_in_days: i64) -> Booking {
+    Booking::default()
+        .starts_at(DateTime::from_millis(DateTime::now().timestamp_millis() + starts_in_days * DAY_MS))
+        .booked_at(DateTime::now())
+}
+
+// Run test: cargo nextest run test_dates_within_bounds_pass
+#[tokio::test]
+async fn test_dates_within_bounds_pass() -> TestResult {
+    booking(1).validate()?;
+
+    // `None` is skipped by date rules.
+    let cancelled = booking(1).cancelled_at(DateTime::from_millis(DateTime::now().timestamp_millis() - DAY_MS));
+    cancelled.validate()?;
+
+    Ok(())
+}
+
+// Run test: cargo nextest run test_after_now_rejects_past_dates
+#[tokio::test]
+async fn test_after_now_rejects_past_dates() -> TestResult {
+    let err = booking(-1).validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("Field 'starts_at' must be after 20"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_before_now_rejects_future_dates
+#[tokio::test]
+async fn test_before_now_rejects_future_dates() -> TestResult {
+    let future = DateTime::from_millis(DateTime::now().timestamp_millis() + DAY_MS);
+    let err = booking(1).cancelled_at(future).validate();
+    assert!(format!("{:?}", err).contains("Field 'cancelled_at' must be before 20"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_fixed_date_bounds_are_exclusive
+#[tokio::test]
+async fn test_fixed_date_bounds_are_exclusive() -> TestResult {
+    let boundary = DateTime::parse_rfc3339_str("2020-01-01T00:00:00Z")?;
+    let err = booking(1).booked_at(boundary).validate();
+    assert!(format!("{:?}", err).contains("Field 'booked_at' must be after 2020-01-01T00:00:00Z"));
+
+    let late = DateTime::parse_rfc3339_str("2100-01-01T00:00:00Z")?;
+    let err = booking(1).booked_at(late).validate();
+    assert!(format!("{:?}", err).contains("Field 'booked_at' must be before 2100-01-01T00:00:00Z"));
+
+    booking(1).booked_at(DateTime::from_millis(boundary.timestamp_millis() + 1)).validate()?;
+    Ok(())
+}
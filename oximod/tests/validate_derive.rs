@@ -0,0 +1,68 @@
+use oximod::Validate;
+use serde::Deserialize;
+use testresult::TestResult;
+
+#[derive(Validate, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+#[validate(with = "passwords_match")]
+pub struct SignupRequest {
+    #[validate(min_length = 3, max_length = 20)]
+    user_name: String,
+
+    #[validate(email)]
+    contact_email: Option<String>,
+
+    password: String,
+    password_confirmation: String,
+
+    #[validate(nested)]
+    profile: Profile,
+}
+
+#[derive(Validate, Deserialize, Debug)]
+pub struct Profile {
+    #[validate(min = 13)]
+    age: i32,
+}
+
+fn passwords_match(request: &SignupRequest) -> Result<(), String> {
+    if request.password != request.password_confirmation {
+        return Err("Passwords do not match".to_string());
+    }
+    Ok(())
+}
+
+fn request(user_name: &str, email: &str, age: i32) -> SignupRequest {
+    SignupRequest {
+        user_name: user_name.to_string(),
+        contact_email: Some(email.to_string()),
+        password: "hunter22".to_string(),
+        password_confirmation: "hunter22".to_string(),
+        profile: Profile { age },
+    }
+}
+
+// Run test: cargo nextest run test_derived_validate_without_model
+#[tokio::test]
+async fn test_derived_validate_without_model() -> TestResult {
+    request("alice", "alice@example.com", 30).validate()?;
+
+    let err = format!("{:?}", request("al", "alice@example.com", 30).validate());
+    assert!(err.contains("Field 'userName' must be at least 3 characters long"));
+
+    let err = format!("{:?}", request("alice", "not-an-email", 30).validate());
+    assert!(err.contains("contactEmail"));
+
+    let mut mismatched = request("alice", "alice@example.com", 30);
+    mismatched.password_confirmation = "hunter23".to_string();
+    assert!(format!("{:?}", mismatched.validate()).contains("Passwords do not match"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_derived_validate_nested
+#[tokio::test]
+async fn test_derived_validate_nested() -> TestResult {
+    let err = format!("{:?}", Validate::validate(&request("alice", "alice@example.com", 9)));
+    assert!(err.contains("Field 'profile.age' must be at least 13"));
+    Ok(())
+}
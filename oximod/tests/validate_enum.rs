@@ -0,0 +1,76 @@
+use mongodb::bson::{ doc, oid::ObjectId, Document };
+use oximod::Model;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    Admin,
+    #[serde(alias = "member")]
+    User,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "kind")]
+pub enum Plan {
+    Free,
+    Paid { seats: i32 },
+}
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_enum")]
+pub struct Account {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[validate(enum = "Role")]
+    role: String,
+
+    #[validate(enum = "Role")]
+    fallback_role: Option<String>,
+
+    #[validate(enum = "Plan")]
+    plan: Option<Document>,
+}
+
+// Run test: cargo nextest run test_accepts_enum_variants
+#[tokio::test]
+async fn test_accepts_enum_variants() -> TestResult {
+    let account = Account::default().role("admin".to_string());
+    assert!(account.validate().is_ok());
+
+    let account = Account::default()
+        .role("user".to_string())
+        .fallback_role("admin".to_string())
+        .plan(doc! { "kind": "Paid", "seats": 5 });
+    assert!(account.validate().is_ok());
+
+    let account = Account::default().role("user".to_string()).plan(doc! { "kind": "Free" });
+    assert!(account.validate().is_ok());
+    Ok(())
+}
+
+// Run test: cargo nextest run test_rejects_unknown_variants
+#[tokio::test]
+async fn test_rejects_unknown_variants() -> TestResult {
+    let err = Account::default().role("owner".to_string()).validate();
+    assert!(format!("{:?}", err).contains("Field 'role' must be a variant of Role"));
+
+    // The Rust name isn't what serde stores.
+    assert!(Account::default().role("Admin".to_string()).validate().is_err());
+
+    // An alias deserializes but doesn't round-trip.
+    assert!(Account::default().role("member".to_string()).validate().is_err());
+
+    let err = Account::default().role("admin".to_string()).fallback_role("root".to_string()).validate();
+    assert!(format!("{:?}", err).contains("Field 'fallback_role' must be a variant of Role"));
+
+    // Internally tagged: the tag is required and the variant's fields must match.
+    let account = Account::default().role("admin".to_string()).plan(doc! { "kind": "Trial" });
+    assert!(account.validate().is_err());
+    let account = Account::default().role("admin".to_string()).plan(doc! { "kind": "Paid" });
+    assert!(account.validate().is_err());
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use mongodb::bson::oid::ObjectId;
+use oximod::Model;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_equals")]
+pub struct Registration {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    password: String,
+
+    #[validate(equals = "password")]
+    confirm_password: String,
+
+    recovery_email: Option<String>,
+
+    #[validate(equals = "recovery_email")]
+    confirm_recovery_email: Option<String>,
+}
+
+// Run test: cargo nextest run test_equals_mismatch
+#[tokio::test]
+async fn test_equals_mismatch() -> TestResult {
+    let registration = Registration::default()
+        .password("hunter22".to_string())
+        .confirm_password("hunter23".to_string());
+
+    let err = registration.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("must be equal to 'password'"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_equals_option_some_vs_none
+#[tokio::test]
+async fn test_equals_option_some_vs_none() -> TestResult {
+    let registration = Registration::default()
+        .password("hunter22".to_string())
+        .confirm_password("hunter22".to_string())
+        .recovery_email("me@example.com".to_string());
+
+    let err = registration.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("must be equal to 'recovery_email'"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_equals_valid
+#[tokio::test]
+async fn test_equals_valid() -> TestResult {
+    // None == None
+    Registration::default()
+        .password("hunter22".to_string())
+        .confirm_password("hunter22".to_string())
+        .validate()?;
+
+    Registration::default()
+        .password("hunter22".to_string())
+        .confirm_password("hunter22".to_string())
+        .recovery_email("me@example.com".to_string())
+        .confirm_recovery_email("me@example.com".to_string())
+        .validate()?;
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use mongodb::bson::oid::ObjectId;
+use oximod::Model;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_exclusive")]
+pub struct Metric {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[validate(exclusive_min = 0.0, exclusive_max = 1.0)]
+    ratio: f64,
+
+    #[validate(min = 0, exclusive_max = 100)]
+    percent: i32,
+}
+
+// Run test: cargo nextest run test_exclusive_min_boundary_fails
+#[tokio::test]
+async fn test_exclusive_min_boundary_fails() -> TestResult {
+    let metric = Metric::default().ratio(0.0).percent(10);
+
+    let err = metric.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("must be greater than 0"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_exclusive_max_boundary_fails
+#[tokio::test]
+async fn test_exclusive_max_boundary_fails() -> TestResult {
+    let metric = Metric::default().ratio(0.5).percent(100);
+
+    let err = metric.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("must be less than 100"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_exclusive_combined_with_inclusive
+#[tokio::test]
+async fn test_exclusive_combined_with_inclusive() -> TestResult {
+    // `min = 0` is inclusive, so the boundary passes
+    Metric::default().ratio(0.5).percent(0).validate()?;
+    Metric::default().ratio(0.999).percent(99).validate()?;
+
+    let metric = Metric::default().ratio(0.5).percent(-1);
+    assert!(format!("{:?}", metric.validate()).contains("must be at least 0"));
+    Ok(())
+}
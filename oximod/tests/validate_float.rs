@@ -0,0 +1,76 @@
+use mongodb::bson::oid::ObjectId;
+use oximod::Model;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_float")]
+pub struct Product {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[validate(positive, max = 999.99)]
+    price: f64,
+
+    #[validate(min = 0.5, max = 5)]
+    rating: f32,
+
+    #[validate(non_negative)]
+    discount: f64,
+}
+
+// Run test: cargo nextest run test_float_max_not_truncated
+#[tokio::test]
+async fn test_float_max_not_truncated() -> TestResult {
+    // 999.995 truncates to 999 through i64, so this only fails with a float comparison
+    let product = Product::default().price(999.995).rating(4.5);
+
+    let err = product.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("at most 999.99"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_float_min_violation
+#[tokio::test]
+async fn test_float_min_violation() -> TestResult {
+    let product = Product::default().price(10.0).rating(0.25);
+
+    let err = product.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("at least 0.5"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_float_sign_checks
+#[tokio::test]
+async fn test_float_sign_checks() -> TestResult {
+    let product = Product::default().price(0.0).rating(3.0);
+    assert!(format!("{:?}", product.validate()).contains("must be positive"));
+
+    let product = Product::default().price(1.0).rating(3.0).discount(-0.1);
+    assert!(format!("{:?}", product.validate()).contains("must be non-negative"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_float_nan_rejected
+#[tokio::test]
+async fn test_float_nan_rejected() -> TestResult {
+    let product = Product::default().price(f64::NAN).rating(3.0);
+
+    let err = product.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("not NaN"));
+
+    let product = Product::default().price(1.0).rating(3.0).discount(f64::NAN);
+    assert!(product.validate().is_err());
+    Ok(())
+}
+
+// Run test: cargo nextest run test_float_valid
+#[tokio::test]
+async fn test_float_valid() -> TestResult {
+    Product::default().price(19.99).rating(4.5).discount(0.0).validate()?;
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use mongodb::bson::oid::ObjectId;
+use oximod::Model;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_ip")]
+pub struct Host {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[validate(ip)]
+    address: String,
+
+    #[validate(ipv4)]
+    gateway: Option<String>,
+
+    #[validate(ipv6)]
+    link_local: Option<String>,
+}
+
+// Run test: cargo nextest run test_invalid_ip_rejected
+#[tokio::test]
+async fn test_invalid_ip_rejected() -> TestResult {
+    let host = Host::default().address("300.1.1.1".to_string());
+
+    let err = host.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("must be a valid IP (v4 or v6) address"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_wrong_ip_family_rejected
+#[tokio::test]
+async fn test_wrong_ip_family_rejected() -> TestResult {
+    let host = Host::default().address("10.0.0.2".to_string()).gateway("::1".to_string());
+    assert!(format!("{:?}", host.validate()).contains("must be a valid IPv4 address"));
+
+    let host = Host::default().address("10.0.0.2".to_string()).link_local("10.0.0.1".to_string());
+    assert!(format!("{:?}", host.validate()).contains("must be a valid IPv6 address"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_ip_valid
+#[tokio::test]
+async fn test_ip_valid() -> TestResult {
+    Host::default()
+        .address("2001:db8::1".to_string())
+        .gateway("10.0.0.1".to_string())
+        .link_local("fe80::1".to_string())
+        .validate()?;
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use mongodb::bson::oid::ObjectId;
+use oximod::Model;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_items")]
+pub struct Post {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[validate(min_items = 1, max_items = 3)]
+    tags: Vec<String>,
+
+    #[validate(min_items = 1)]
+    authors: Option<Vec<String>>,
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+// Run test: cargo nextest run test_min_items_violation
+#[tokio::test]
+async fn test_min_items_violation() -> TestResult {
+    let post = Post::default().tags(vec![]).authors(strings(&["ann"]));
+
+    let err = post.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("must contain at least 1 items"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_max_items_violation
+#[tokio::test]
+async fn test_max_items_violation() -> TestResult {
+    let post = Post::default().tags(strings(&["a", "b", "c", "d"])).authors(strings(&["ann"]));
+
+    let err = post.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("must contain at most 3 items"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_none_counts_as_zero_items
+#[tokio::test]
+async fn test_none_counts_as_zero_items() -> TestResult {
+    let post = Post::default().tags(strings(&["rust"]));
+
+    let err = post.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("'authors' must contain at least 1 items"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_items_valid
+#[tokio::test]
+async fn test_items_valid() -> TestResult {
+    Post::default().tags(strings(&["rust", "mongodb"])).authors(strings(&["ann"])).validate()?;
+    Ok(())
+}
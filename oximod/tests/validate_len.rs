@@ -0,0 +1,45 @@
+use mongodb::bson::oid::ObjectId;
+use oximod::Model;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_len")]
+pub struct Price {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[validate(len = 3)]
+    currency: String,
+
+    #[validate(len = 2)]
+    country: Option<String>,
+}
+
+// Run test: cargo nextest run test_len_violation
+#[tokio::test]
+async fn test_len_violation() -> TestResult {
+    for currency in ["EU", "EURO", ""] {
+        let price = Price::default().currency(currency.to_string());
+
+        let err = price.validate();
+        assert!(format!("{:?}", err).contains("Field 'currency' must be exactly 3 characters"));
+    }
+
+    let price = Price::default().currency("EUR".to_string()).country("DEU".to_string());
+    assert!(format!("{:?}", price.validate()).contains("Field 'country' must be exactly 2 characters"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_len_counts_chars_and_skips_none
+#[tokio::test]
+async fn test_len_counts_chars_and_skips_none() -> TestResult {
+    // "€" is three bytes but one character.
+    let price = Price::default().currency("€€€".to_string());
+    assert!(price.validate().is_ok());
+
+    let price = Price::default().currency("USD".to_string()).country("US".to_string());
+    assert!(price.validate().is_ok());
+    Ok(())
+}
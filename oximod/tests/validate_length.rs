@@ -82,3 +82,43 @@ async fn test_length_valid() -> TestResult {
 
     Ok(())
 }
+
+// Run test: cargo nextest run test_validate_without_saving
+#[tokio::test]
+async fn test_validate_without_saving() -> TestResult {
+    let invalid = User::default()
+        .name("abc".to_string()) // too short
+        .email("x@y.com".to_string())
+        .role(Role::Admin);
+
+    let err = invalid.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("at least 5 characters"));
+
+    let valid = User::default()
+        .name("ValidName".to_string())
+        .email("user@example.com".to_string())
+        .role(Role::Admin);
+
+    valid.validate()?;
+
+    Ok(())
+}
+
+// Run test: cargo nextest run test_length_counts_characters_not_bytes
+#[tokio::test]
+async fn test_length_counts_characters_not_bytes() -> TestResult {
+    // 5 characters, 10 bytes.
+    let user = User::default().name("Борис".to_string()).role(Role::User);
+    assert!(user.validate().is_ok());
+
+    // 10 characters, 40 bytes.
+    let user = User::default().name("🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀".to_string()).role(Role::User);
+    assert!(user.validate().is_ok());
+
+    // 4 characters, 8 bytes: too short despite its byte length.
+    let user = User::default().name("Анна".to_string()).role(Role::Guess);
+    assert!(format!("{:?}", user.validate()).contains("at least 5 characters"));
+
+    Ok(())
+}
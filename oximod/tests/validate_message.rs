@@ -0,0 +1,88 @@
+use mongodb::bson::oid::ObjectId;
+use oximod::Model;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_message")]
+pub struct Account {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[validate(min_length = 3, message = "Nombre de usuario demasiado corto")]
+    username: String,
+
+    #[validate(min = 18)]
+    age: i32,
+}
+
+// Run test: cargo nextest run test_custom_message_replaces_default
+#[tokio::test]
+async fn test_custom_message_replaces_default() -> TestResult {
+    let account = Account::default().username("ab".to_string()).age(30);
+
+    let err = account.validate();
+    assert!(err.is_err());
+    let err = format!("{:?}", err);
+    assert!(err.contains("Nombre de usuario demasiado corto"));
+    assert!(!err.contains("characters long"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_default_message_without_override
+#[tokio::test]
+async fn test_default_message_without_override() -> TestResult {
+    let account = Account::default().username("alice".to_string()).age(10);
+
+    let err = account.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("at least 18"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_messages_use_serialized_field_names
+#[tokio::test]
+async fn test_messages_use_serialized_field_names() -> TestResult {
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("validate_message")]
+    #[serde(rename_all = "camelCase")]
+    pub struct Signup {
+        #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+        id: Option<ObjectId>,
+
+        #[serde(rename = "emailAddress")]
+        #[validate(email)]
+        email: Option<String>,
+
+        #[validate(min_length = 8)]
+        new_password: String,
+
+        #[validate(equals = "new_password")]
+        confirm_password: String,
+    }
+
+    let signup = Signup::new()
+        .email("not-an-email".to_string())
+        .new_password("longenough".to_string())
+        .confirm_password("longenough".to_string());
+    let err = format!("{}", signup.validate().unwrap_err());
+    assert!(err.contains("'emailAddress'"), "{}", err);
+
+    let signup = Signup::new()
+        .email("a@b.co".to_string())
+        .new_password("short".to_string())
+        .confirm_password("short".to_string());
+    let err = format!("{}", signup.validate().unwrap_err());
+    assert!(err.contains("'newPassword'"), "{}", err);
+
+    let signup = Signup::new()
+        .email("a@b.co".to_string())
+        .new_password("longenough".to_string())
+        .confirm_password("different!".to_string());
+    let err = format!("{}", signup.validate().unwrap_err());
+    assert!(err.contains("'confirmPassword' must be equal to 'newPassword'"), "{}", err);
+
+    Ok(())
+}
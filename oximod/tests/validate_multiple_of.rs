@@ -0,0 +1,58 @@
+use mongodb::bson::oid::ObjectId;
+use oximod::Model;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_multiple_of")]
+pub struct Order {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[validate(multiple_of = 6)]
+    quantity: u16,
+
+    #[validate(multiple_of = 5)]
+    price_cents: i64,
+}
+
+// Run test: cargo nextest run test_multiple_of_violation
+#[tokio::test]
+async fn test_multiple_of_violation() -> TestResult {
+    let order = Order::default().quantity(7).price_cents(500);
+
+    let err = order.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("must be a multiple of 6"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_multiple_of_valid
+#[tokio::test]
+async fn test_multiple_of_valid() -> TestResult {
+    Order::default().quantity(12).price_cents(-1500).validate()?;
+    Ok(())
+}
+
+// Run test: cargo nextest run test_multiple_of_float
+#[tokio::test]
+async fn test_multiple_of_float() -> TestResult {
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("validate_multiple_of")]
+    pub struct Fee {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+
+        #[validate(multiple_of = 5)]
+        price: f64,
+    }
+
+    Fee::default().price(15.0).validate()?;
+
+    // Not truncated to 10 before the check.
+    let err = Fee::default().price(10.5).validate();
+    assert!(format!("{:?}", err).contains("must be a multiple of 5"));
+    Ok(())
+}
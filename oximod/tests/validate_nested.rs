@@ -0,0 +1,90 @@
+use mongodb::bson::oid::ObjectId;
+use oximod::{ Model, Validate };
+use oximod::_error::oximod_error::OximodError;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+#[derive(Model, Serialize, Deserialize, Debug, Clone)]
+#[db("test")]
+#[collection("validate_nested_addresses")]
+pub struct Address {
+    #[validate(min_length = 1)]
+    city: String,
+
+    #[validate(min_length = 5, max_length = 5)]
+    zip: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LineItem {
+    sku: String,
+}
+
+impl Validate for LineItem {
+    fn validate(&self) -> Result<(), OximodError> {
+        if self.sku.is_empty() {
+            return Err(OximodError::ValidationError("sku must not be empty".to_string()));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_nested_customers")]
+pub struct Customer {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[validate(nested)]
+    address: Address,
+
+    #[validate(nested)]
+    billing_address: Option<Address>,
+
+    #[validate(nested)]
+    items: Vec<LineItem>,
+}
+
+fn address(zip: &str) -> Address {
+    Address::new().city("Berlin".to_string()).zip(zip.to_string())
+}
+
+// Run test: cargo nextest run test_nested_error_names_field_path
+#[tokio::test]
+async fn test_nested_error_names_field_path() -> TestResult {
+    let customer = Customer::new().address(address("1234"));
+
+    let err = format!("{:?}", customer.validate());
+    assert!(err.contains("Field 'address.zip' must be at least 5 characters long"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_nested_option_and_vec
+#[tokio::test]
+async fn test_nested_option_and_vec() -> TestResult {
+    let customer = Customer::new().address(address("10115")).billing_address(address(""));
+    let err = format!("{:?}", customer.validate());
+    assert!(err.contains("Field 'billing_address.zip'"));
+
+    let customer = Customer::new()
+        .address(address("10115"))
+        .items(vec![LineItem { sku: "A-1".to_string() }, LineItem::default()]);
+    let err = format!("{:?}", customer.validate());
+    assert!(err.contains("sku must not be empty"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_nested_valid
+#[tokio::test]
+async fn test_nested_valid() -> TestResult {
+    Customer::new()
+        .address(address("10115"))
+        .billing_address(address("20095"))
+        .items(vec![LineItem { sku: "A-1".to_string() }])
+        .validate()?;
+
+    // The derived `Validate` impl is what nested fields call.
+    assert!(Validate::validate(&address("1")).is_err());
+    Ok(())
+}
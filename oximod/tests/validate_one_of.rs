@@ -0,0 +1,46 @@
+use mongodb::bson::oid::ObjectId;
+use oximod::Model;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_one_of")]
+pub struct Member {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[validate(one_of("admin", "user", "guest"))]
+    role: String,
+
+    #[validate(one_of("light", "dark"))]
+    theme: Option<String>,
+}
+
+// Run test: cargo nextest run test_invalid_enum_value
+#[tokio::test]
+async fn test_invalid_enum_value() -> TestResult {
+    let member = Member::default().role("superuser".to_string());
+
+    let err = member.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("Field 'role' must be one of: admin, user, guest"));
+
+    let member = Member::default().role("Admin".to_string());
+    assert!(member.validate().is_err());
+
+    let member = Member::default().role("user".to_string()).theme("blue".to_string());
+    assert!(format!("{:?}", member.validate()).contains("Field 'theme' must be one of: light, dark"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_valid_enum_value_and_none_skipped
+#[tokio::test]
+async fn test_valid_enum_value_and_none_skipped() -> TestResult {
+    let member = Member::default().role("guest".to_string());
+    assert!(member.validate().is_ok());
+
+    let member = Member::default().role("admin".to_string()).theme("dark".to_string());
+    assert!(member.validate().is_ok());
+    Ok(())
+}
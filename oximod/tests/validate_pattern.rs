@@ -2,7 +2,7 @@ mod common;
 
 use common::init;
 use mongodb::bson::oid::ObjectId;
-use oximod::Model;
+use oximod::{ _error::oximod_error::OximodError, Model };
 use serde::{ Deserialize, Serialize };
 use testresult::TestResult;
 
@@ -65,3 +65,38 @@ async fn test_valid_pattern_format() -> TestResult {
     assert_ne!(result, ObjectId::default());
     Ok(())
 }
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_pattern_invalid")]
+pub struct Broken {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[validate(pattern = r"^(SKU-\d{4}$")]
+    code: Option<String>,
+}
+
+// Run test: cargo nextest run test_pattern_reused_across_calls
+#[tokio::test]
+async fn test_pattern_reused_across_calls() -> TestResult {
+    for code in ["SKU-0001", "SKU-0002", "SKU-0003"] {
+        Product::default().code(code.to_string()).name("Product1").quantity(1).temperature(-1).validate()?;
+    }
+    assert!(Product::default().code("SKU-1").name("Product1").quantity(1).temperature(-1).validate().is_err());
+    Ok(())
+}
+
+// Run test: cargo nextest run test_invalid_regex_is_validation_error
+#[tokio::test]
+async fn test_invalid_regex_is_validation_error() -> TestResult {
+    // The broken regex is reported on every call instead of panicking.
+    for _ in 0..2 {
+        let result = Broken::default().code("SKU-1234").validate();
+        assert!(
+            matches!(&result, Err(OximodError::ValidationError(msg)) if msg.starts_with("Invalid regex pattern in validation for 'code'"))
+        );
+    }
+    Broken::default().validate()?;
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use std::sync::LazyLock;
+
+use mongodb::bson::oid::ObjectId;
+use oximod::{ _error::oximod_error::OximodError, Model };
+use regex::Regex;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+mod patterns {
+    use super::*;
+
+    pub static SLUG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[a-z0-9]+(-[a-z0-9]+)*$").unwrap());
+}
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_regex_path")]
+pub struct Article {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[validate(regex_path = "patterns::SLUG")]
+    slug: String,
+
+    #[validate(regex_path = "crate::patterns::SLUG", message = "Bad alias")]
+    alias: Option<String>,
+}
+
+// Run test: cargo nextest run matches_shared_regex
+#[tokio::test]
+async fn matches_shared_regex() -> TestResult {
+    Article::new().slug("hello-world".to_string()).validate()?;
+    Article::new().slug("hello".to_string()).alias("hi-there").validate()?;
+
+    let result = Article::new().slug("Hello World".to_string()).validate();
+    assert!(
+        matches!(&result, Err(OximodError::ValidationError(msg)) if msg == "Field 'slug' does not match the required pattern")
+    );
+
+    Ok(())
+}
+
+// Run test: cargo nextest run checks_optional_field_when_some
+#[tokio::test]
+async fn checks_optional_field_when_some() -> TestResult {
+    Article::new().slug("hello".to_string()).validate()?;
+
+    let result = Article::new().slug("hello".to_string()).alias("Hi There").validate();
+    assert!(matches!(&result, Err(OximodError::ValidationError(msg)) if msg == "Bad alias"));
+
+    Ok(())
+}
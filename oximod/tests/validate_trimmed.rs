@@ -0,0 +1,67 @@
+use mongodb::bson::{ doc, oid::ObjectId, Document };
+use oximod::Model;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_trimmed")]
+pub struct Contact {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[validate(trimmed)]
+    name: String,
+
+    #[validate(trimmed)]
+    nickname: Option<String>,
+
+    #[normalize(trim)]
+    #[serde(rename = "displayName")]
+    display_name: String,
+
+    #[normalize(trim)]
+    city: Option<String>,
+}
+
+// Run test: cargo nextest run test_trimmed_violation
+#[tokio::test]
+async fn test_trimmed_violation() -> TestResult {
+    for name in [" Alice", "Alice ", "\tAlice\n"] {
+        let contact = Contact::default().name(name.to_string());
+
+        let err = contact.validate();
+        assert!(format!("{:?}", err).contains("must not have leading or trailing whitespace"));
+    }
+
+    let contact = Contact::default().name("Alice".to_string()).nickname(" Al".to_string());
+    assert!(contact.validate().is_err());
+    Ok(())
+}
+
+// Run test: cargo nextest run test_trimmed_valid_and_none_skipped
+#[tokio::test]
+async fn test_trimmed_valid_and_none_skipped() -> TestResult {
+    let contact = Contact::default().name("Alice Smith".to_string());
+    assert!(contact.validate().is_ok());
+    Ok(())
+}
+
+// Run test: cargo nextest run test_normalize_trim_on_serialization
+#[tokio::test]
+async fn test_normalize_trim_on_serialization() -> TestResult {
+    let contact = Contact::default()
+        .name("Alice".to_string())
+        .display_name("  Alice S.  ".to_string())
+        .city(" Berlin".to_string());
+
+    let document = Document::try_from(&contact)?;
+    assert_eq!(
+        document,
+        doc! { "name": "Alice", "nickname": null, "displayName": "Alice S.", "city": "Berlin" }
+    );
+
+    // The struct itself is left untouched.
+    assert_eq!(contact.display_name, "  Alice S.  ");
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use mongodb::bson::{ doc, oid::ObjectId };
+use oximod::{ _error::oximod_error::OximodError, Model };
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+mod common;
+use common::init;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_unique_duplicates")]
+pub struct Member {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    #[validate(unique)]
+    email: String,
+    #[validate(unique, message = "That handle is taken")]
+    #[serde(rename = "handle")]
+    nickname: Option<String>,
+}
+
+// Run test: cargo nextest run rejects_duplicate_unique_values
+#[tokio::test]
+async fn rejects_duplicate_unique_values() -> TestResult {
+    init().await;
+
+    Member::clear().await?;
+    Member::new().email("a@example.com".to_string()).nickname("ace".to_string()).save().await?;
+
+    let duplicate_email = Member::new().email("a@example.com".to_string());
+    // The synchronous validator doesn't query the database.
+    duplicate_email.validate()?;
+    match duplicate_email.validate_unique().await {
+        Err(OximodError::ValidationError(message)) => {
+            assert_eq!(message, "Field 'email' must be unique");
+        }
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+
+    let duplicate_handle = Member::new().email("b@example.com".to_string()).nickname("ace".to_string());
+    match duplicate_handle.validate_unique().await {
+        Err(OximodError::ValidationError(message)) => assert_eq!(message, "That handle is taken"),
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+
+    // `None` values are not compared.
+    Member::new().email("c@example.com".to_string()).validate_unique().await?;
+    Member::new().email("c@example.com".to_string()).save().await?;
+    Member::new().email("d@example.com".to_string()).validate_unique().await?;
+
+    Ok(())
+}
+
+// Run test: cargo nextest run excludes_own_document_from_unique_check
+#[tokio::test]
+async fn excludes_own_document_from_unique_check() -> TestResult {
+    // A collection of its own, so `clear()` here can't race the other database test.
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("validate_unique_self")]
+    pub struct Member {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        #[validate(unique)]
+        email: String,
+        #[validate(unique, message = "That handle is taken")]
+        #[serde(rename = "handle")]
+        nickname: Option<String>,
+    }
+
+    init().await;
+
+    Member::clear().await?;
+    let id = Member::new().email("self@example.com".to_string()).save().await?;
+
+    let mut member = Member::find_by_id(id).await?.expect("member exists");
+    member.validate_unique().await?;
+
+    member.nickname = Some("solo".to_string());
+    member.validate_unique().await?;
+    Member::replace_one(doc! { "_id": id }, &member).await?;
+
+    Ok(())
+}
+
+// Run test: cargo nextest run models_without_unique_fields_skip_the_query
+#[tokio::test]
+async fn models_without_unique_fields_skip_the_query() -> TestResult {
+    #[derive(Model, Serialize, Deserialize, Debug)]
+    #[db("test")]
+    #[collection("validate_unique")]
+    pub struct Note {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        _id: Option<ObjectId>,
+        body: String,
+    }
+
+    // Nothing is queried, so this needs neither a client nor a server.
+    Note::new().body("hello".to_string()).validate_unique().await?;
+
+    Ok(())
+}
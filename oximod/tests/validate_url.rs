@@ -0,0 +1,66 @@
+use mongodb::bson::oid::ObjectId;
+use oximod::Model;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_url")]
+pub struct Profile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[validate(url)]
+    website: Option<String>,
+
+    #[validate(url(schemes = "https"))]
+    avatar: String,
+}
+
+// Run test: cargo nextest run test_relative_url_rejected
+#[tokio::test]
+async fn test_relative_url_rejected() -> TestResult {
+    let profile = Profile::default()
+        .website("/about".to_string())
+        .avatar("https://cdn.example.com/a.png".to_string());
+
+    let err = profile.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("must be a valid absolute URL (http, https)"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_url_scheme_restriction
+#[tokio::test]
+async fn test_url_scheme_restriction() -> TestResult {
+    let profile = Profile::default().avatar("http://cdn.example.com/a.png".to_string());
+
+    let err = profile.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("(https)"));
+
+    let profile = Profile::default()
+        .website("ftp://files.example.com".to_string())
+        .avatar("https://cdn.example.com/a.png".to_string());
+    assert!(profile.validate().is_err());
+    Ok(())
+}
+
+// Run test: cargo nextest run test_empty_url_rejected
+#[tokio::test]
+async fn test_empty_url_rejected() -> TestResult {
+    let profile = Profile::default().avatar(String::new());
+
+    assert!(profile.validate().is_err());
+    Ok(())
+}
+
+// Run test: cargo nextest run test_url_valid
+#[tokio::test]
+async fn test_url_valid() -> TestResult {
+    Profile::default()
+        .website("http://example.com/about?tab=1".to_string())
+        .avatar("https://cdn.example.com/a.png".to_string())
+        .validate()?;
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use mongodb::bson::oid::ObjectId;
+use oximod::Model;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_uuid")]
+pub struct Device {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[validate(uuid)]
+    serial: Option<String>,
+
+    #[validate(uuid(version = 4))]
+    token: String,
+}
+
+// Run test: cargo nextest run test_malformed_uuid_rejected
+#[tokio::test]
+async fn test_malformed_uuid_rejected() -> TestResult {
+    let device = Device::default()
+        .serial("123e4567-e89b-12d3-a456-42661417400".to_string()) // last group too short
+        .token("9b2f8c1e-4a7d-4f3b-9c2e-1d5a6b7c8d9e".to_string());
+
+    let err = device.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("must be a valid UUID"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_uuid_version_mismatch
+#[tokio::test]
+async fn test_uuid_version_mismatch() -> TestResult {
+    let device = Device::default().token("123e4567-e89b-12d3-a456-426614174000".to_string()); // version 1
+
+    let err = device.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("must be a valid version 4 UUID"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_uuid_valid
+#[tokio::test]
+async fn test_uuid_valid() -> TestResult {
+    Device::default()
+        .serial("123E4567-E89B-12D3-A456-426614174000".to_string())
+        .token("9b2f8c1e-4a7d-4f3b-9c2e-1d5a6b7c8d9e".to_string())
+        .validate()?;
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use mongodb::bson::oid::ObjectId;
+use oximod::Model;
+use serde::{ Deserialize, Serialize };
+use testresult::TestResult;
+
+mod rules {
+    use super::Booking;
+
+    pub fn ends_after_start(booking: &Booking) -> Result<(), String> {
+        if booking.end <= booking.start {
+            return Err("Booking must end after it starts".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn guests_fit_room(booking: &Booking) -> Result<(), String> {
+        if booking.guests > 4 {
+            return Err(format!("{} guests do not fit in one room", booking.guests));
+        }
+        Ok(())
+    }
+
+    pub fn no_reserved_words(booking: &Booking) -> Result<(), String> {
+        if booking.note.contains("admin") {
+            return Err("reserved".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("validate_with")]
+#[validate(with = "rules::ends_after_start")]
+pub struct Booking {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    start: i64,
+
+    end: i64,
+
+    #[validate(with = "rules::guests_fit_room")]
+    guests: u8,
+
+    #[validate(
+        with = "rules::no_reserved_words",
+        with = "rules::guests_fit_room",
+        message = "Invalid note"
+    )]
+    note: String,
+}
+
+// Run test: cargo nextest run test_struct_level_with_violation
+#[tokio::test]
+async fn test_struct_level_with_violation() -> TestResult {
+    let booking = Booking::default()
+        .start(2_000)
+        .end(1_000)
+        .guests(2);
+
+    let err = booking.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("Booking must end after it starts"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_field_level_with_violation
+#[tokio::test]
+async fn test_field_level_with_violation() -> TestResult {
+    let booking = Booking::default()
+        .start(1_000)
+        .end(2_000)
+        .guests(6);
+
+    let err = booking.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("6 guests do not fit in one room"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_chained_with_uses_custom_message
+#[tokio::test]
+async fn test_chained_with_uses_custom_message() -> TestResult {
+    let booking = Booking::default()
+        .start(1_000)
+        .end(2_000)
+        .guests(2)
+        .note("call admin".to_string());
+
+    let err = booking.validate();
+    assert!(err.is_err());
+    assert!(format!("{:?}", err).contains("Invalid note"));
+    Ok(())
+}
+
+// Run test: cargo nextest run test_with_valid
+#[tokio::test]
+async fn test_with_valid() -> TestResult {
+    Booking::default()
+        .start(1_000)
+        .end(2_000)
+        .guests(2)
+        .note("late arrival".to_string())
+        .validate()?;
+    Ok(())
+}
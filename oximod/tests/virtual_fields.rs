@@ -0,0 +1,105 @@
+use mongodb::bson::{ doc, oid::ObjectId, Document };
+use oximod::{ Model, Validate };
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+mod common;
+use common::init;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("virtual_fields")]
+pub struct Person {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    first: String,
+    last: String,
+    /// Computed from `first` and `last`; never stored.
+    #[serde(skip)]
+    full_name: String,
+}
+
+impl Person {
+    /// A computed virtual: always up to date, nothing to keep in sync.
+    fn display_name(&self) -> String {
+        format!("{} {}", self.first, self.last)
+    }
+
+    /// Fills the cached virtual after loading.
+    fn with_full_name(mut self) -> Self {
+        self.full_name = self.display_name();
+        self
+    }
+}
+
+// Run test: cargo nextest run skipped_fields_are_not_stored
+#[tokio::test]
+async fn skipped_fields_are_not_stored() -> TestResult {
+    let person = Person::new()
+        .first("Ada".to_string())
+        .last("Lovelace".to_string())
+        .full_name("ignored".to_string());
+
+    let document = Document::try_from(&person)?;
+    assert_eq!(document, doc! { "first": "Ada", "last": "Lovelace" });
+
+    let loaded = Person::try_from(document)?.with_full_name();
+    assert_eq!(loaded.full_name, "Ada Lovelace");
+    assert_eq!(loaded.display_name(), "Ada Lovelace");
+
+    // Only stored fields get update setters, filters, and key constants.
+    let update = PersonUpdate::new().first("Grace".to_string()).build_update()?;
+    assert_eq!(update, doc! { "$set": { "first": "Grace" } });
+    assert_eq!(PersonFilter::new().last().eq("Lovelace").build(), doc! { "last": { "$eq": "Lovelace" } });
+    assert_eq!(person_fields::FIRST, "first");
+
+    Ok(())
+}
+
+// Run test: cargo nextest run saves_and_loads_models_with_virtuals
+#[tokio::test]
+async fn saves_and_loads_models_with_virtuals() -> TestResult {
+    init().await;
+
+    Person::clear().await?;
+    let id = Person::new()
+        .first("Alan".to_string())
+        .last("Turing".to_string())
+        .full_name("stale".to_string())
+        .save().await?;
+
+    let stored = Person::get_collection()?.find_one(doc! { "_id": id }).await?.expect("stored");
+    assert!(!stored.contains_key("full_name"));
+
+    let person = Person::find_by_id(id).await?.expect("person exists").with_full_name();
+    assert_eq!(person.full_name, "Alan Turing");
+
+    Ok(())
+}
+
+#[derive(Validate, Deserialize, Debug)]
+pub struct RenameRequest {
+    #[validate(min_length = 1)]
+    first: String,
+    #[validate(min_length = 1)]
+    last: String,
+    #[serde(skip)]
+    full_name: String,
+}
+
+// Run test: cargo nextest run validation_ignores_skipped_fields
+#[tokio::test]
+async fn validation_ignores_skipped_fields() -> TestResult {
+    let request = RenameRequest {
+        first: "Ada".to_string(),
+        last: "Lovelace".to_string(),
+        full_name: String::new(),
+    };
+    request.validate()?;
+    assert!(request.full_name.is_empty());
+
+    let person = Person::new().first("Ada".to_string()).last("Lovelace".to_string());
+    person.validate()?;
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use mongodb::bson::{ doc, oid::ObjectId };
+use mongodb::change_stream::event::OperationType;
+use oximod::_futures_util::StreamExt;
+use oximod::Model;
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+mod common;
+use common::init;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("watch")]
+pub struct Product {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+    name: String,
+    price: i32,
+}
+
+// Run test: cargo nextest run watches_filtered_changes_and_resumes
+#[tokio::test]
+async fn watches_filtered_changes_and_resumes() -> TestResult {
+    init().await;
+    Product::clear().await?;
+
+    let mut stream = Product::watch(
+        vec![doc! { "$match": { "operationType": "insert" } }],
+        None
+    ).await?;
+
+    Product::new().name("Lamp".to_string()).price(40).save().await?;
+    Product::update_one(doc! { "name": "Lamp" }, doc! { "$set": { "price": 35 } }).await?;
+    Product::new().name("Desk".to_string()).price(120).save().await?;
+
+    let event = stream.next().await.unwrap()?;
+    assert_eq!(event.operation_type, OperationType::Insert);
+    assert_eq!(event.full_document.unwrap().name, "Lamp");
+
+    let token = stream.resume_token();
+    drop(stream);
+
+    let mut resumed = Product::watch(vec![doc! { "$match": { "operationType": "insert" } }], token).await?;
+    let event = resumed.next().await.unwrap()?;
+    assert_eq!(event.full_document.unwrap().name, "Desk");
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use mongodb::bson::{ doc, oid::ObjectId };
+use oximod::Model;
+use testresult::TestResult;
+use serde::{ Deserialize, Serialize };
+
+mod common;
+use common::init;
+
+#[derive(Model, Serialize, Deserialize, Debug)]
+#[db("test")]
+#[collection("tenant_users")]
+pub struct User {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    _id: Option<ObjectId>,
+
+    #[index(unique)]
+    #[validate(min_length = 2)]
+    name: String,
+
+    active: bool,
+}
+
+// Run test: cargo nextest run routes_crud_to_runtime_collection
+#[tokio::test]
+async fn routes_crud_to_runtime_collection() -> TestResult {
+    init().await;
+
+    let tenant = User::with_collection("tenant_users_42")?;
+    tenant.delete(doc! {}).await?;
+    User::clear().await?;
+
+    tenant.sync_indexes().await?;
+    tenant.save(&User::new().name("Alice".to_string()).active(true)).await?;
+    tenant.save(&User::new().name("Bob".to_string()).active(false)).await?;
+
+    // The declared collection is untouched
+    assert_eq!(User::count(doc! {}).await?, 0);
+    assert_eq!(tenant.count(doc! {}).await?, 2);
+    assert_eq!(tenant.collection().name(), "tenant_users_42");
+
+    let active = tenant.find(doc! { "active": true }).await?;
+    assert_eq!(active.len(), 1);
+    assert_eq!(active[0].name, "Alice");
+
+    tenant.update_one(doc! { "name": "Bob" }, doc! { "$set": { "active": true } }).await?;
+    assert!(tenant.find_one(doc! { "name": "Bob" }).await?.is_some_and(|user| user.active));
+
+    // Validation still applies
+    assert!(tenant.save(&User::new().name("X".to_string())).await.is_err());
+
+    let deleted = tenant.delete_one(doc! { "name": "Alice" }).await?;
+    assert_eq!(deleted.deleted_count, 1);
+
+    Ok(())
+}
+
+// Run test: cargo nextest run routes_crud_to_runtime_database
+#[tokio::test]
+async fn routes_crud_to_runtime_database() -> TestResult {
+    init().await;
+
+    let region = User::using_db("test_region_eu")?;
+    region.delete(doc! {}).await?;
+    User::clear().await?;
+
+    region.save(&User::new().name("Chloe".to_string()).active(true)).await?;
+
+    assert_eq!(region.collection().namespace().db, "test_region_eu");
+    assert_eq!(region.collection().name(), "tenant_users");
+    assert_eq!(region.count(doc! {}).await?, 1);
+    assert_eq!(User::count(doc! {}).await?, 0);
+
+    Ok(())
+}
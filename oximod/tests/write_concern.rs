@@ -0,0 +1,38 @@
+use mongodb::{
+    bson::{ self, doc },
+    error::{ Error, ErrorKind, WriteConcernError, WriteError, WriteFailure },
+};
+use oximod::_error::oximod_error::OximodError;
+use oximod::_feature::model::write_error::write_error;
+use testresult::TestResult;
+
+// Run test: cargo nextest run maps_write_concern_failures
+#[tokio::test]
+async fn maps_write_concern_failures() -> TestResult {
+    let concern: WriteConcernError = bson::from_document(
+        doc! { "code": 64, "codeName": "WriteConcernFailed", "errmsg": "waiting for replication timed out" }
+    )?;
+    let e = Error::from(ErrorKind::Write(WriteFailure::WriteConcernError(concern)));
+
+    let mapped = write_error(e, OximodError::ConnectionError);
+    assert!(
+        matches!(&mapped, OximodError::WriteConcernError(msg) if msg.contains("waiting for replication timed out"))
+    );
+
+    Ok(())
+}
+
+// Run test: cargo nextest run leaves_other_write_failures_to_fallback
+#[tokio::test]
+async fn leaves_other_write_failures_to_fallback() -> TestResult {
+    let failure: WriteError = bson::from_document(
+        doc! { "code": 121, "codeName": "DocumentValidationFailure", "errmsg": "Document failed validation" }
+    )?;
+    let e = Error::from(ErrorKind::Write(WriteFailure::WriteError(failure)));
+    assert!(matches!(write_error(e, OximodError::ConnectionError), OximodError::ConnectionError(_)));
+
+    let e = Error::from(std::io::Error::other("connection reset"));
+    assert!(matches!(write_error(e, OximodError::ConnectionError), OximodError::ConnectionError(_)));
+
+    Ok(())
+}
@@ -38,4 +38,35 @@ pub enum OximodError {
     /// This typically occurs when data does not meet constraints like `min_length`, `max`, `enum`, etc.
     #[error("Validation error: {0}")]
     ValidationError(String),
+
+    /// A transaction could not be started, committed, or aborted.
+    /// Transactions require a replica set or sharded cluster.
+    #[error("Transaction error: {0}")]
+    TransactionError(String),
+
+    /// An operation inside a transaction failed with a transient error.
+    /// `with_transaction` retries the whole transaction when it sees this error.
+    #[error("Transient transaction error: {0}")]
+    TransientTransactionError(String),
+
+    /// A database command such as `explain` failed.
+    /// This may indicate a malformed command or insufficient privileges.
+    #[error("Command error: {0}")]
+    CommandError(String),
+
+    /// A string could not be parsed as a MongoDB `ObjectId`.
+    /// This usually means user input (e.g. a URL path segment) is not a 24-character hex id.
+    #[error("Invalid id: {0}")]
+    InvalidId(String),
+
+    /// A write violated a unique index.
+    /// `key` names the violated index (e.g. `email_1`), so callers can report which value is taken.
+    #[error("Duplicate key: {key}")]
+    DuplicateKey { key: String },
+
+    /// A write could not be confirmed at the requested write concern.
+    /// With `w: "majority"` this usually means replication timed out; the write may still have
+    /// been applied on the primary, so check before retrying writes that aren't idempotent.
+    #[error("Write concern error: {0}")]
+    WriteConcernError(String),
 }
@@ -1,5 +1,21 @@
 use std::backtrace::Backtrace;
 use std::error::Error;
+use std::sync::OnceLock;
+
+#[doc(hidden)]
+/// Whether `attach_printables!` should capture a backtrace and print suggestions.
+///
+/// Follows the same rules as [`Backtrace::capture`]: `RUST_LIB_BACKTRACE` takes precedence over
+/// `RUST_BACKTRACE`, and any value other than `0` enables output. Read once and cached.
+pub fn printables_enabled() -> bool {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    *ENABLED.get_or_init(|| {
+        ["RUST_LIB_BACKTRACE", "RUST_BACKTRACE"]
+            .iter()
+            .find_map(|var| std::env::var(var).ok())
+            .is_some_and(|value| value != "0")
+    })
+}
 
 /// A trait for attaching helpful debugging output to errors.
 pub trait Printable {
@@ -1,2 +1,5 @@
 pub mod conn;
-pub mod model;
\ No newline at end of file
+pub mod id;
+pub mod model;
+pub mod update;
+pub mod validate;
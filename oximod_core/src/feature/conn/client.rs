@@ -1,8 +1,13 @@
-use std::sync::{Arc, OnceLock};
-use mongodb::Client;
-use crate::{error::oximod_error::OximodError, Printable, attach_printables};
+use std::{ collections::HashMap, sync::{ Arc, OnceLock, RwLock }, time::Duration };
+use mongodb::{ bson::doc, error::ErrorKind, Client };
+use crate::{error::oximod_error::OximodError, Printable};
 
-static CLIENT: OnceLock<Arc<Client>> = OnceLock::new();
+static CLIENT: RwLock<Option<Arc<Client>>> = RwLock::new(None);
+static CLIENTS: OnceLock<RwLock<HashMap<String, Arc<Client>>>> = OnceLock::new();
+
+fn clients() -> &'static RwLock<HashMap<String, Arc<Client>>> {
+    CLIENTS.get_or_init(|| RwLock::new(HashMap::new()))
+}
 
 #[doc(hidden)]
 /// Initializes a MongoDB client using the provided URI.
@@ -28,10 +33,23 @@ async fn init_db(mongo_uri: String) -> Result<Client, OximodError> {
 /// successfully invoked. If not, it will return a [`OximodError::GlobalClientMissing`] error.
 ///
 /// # Errors
-/// Returns a [`OximodError::GlobalClientMissing`] if no client has been set.
+/// Returns a [`OximodError::GlobalClientMissing`] if no client has been set, or while the
+/// `mock` backend is on.
 pub fn get_global_client() -> Result<Arc<Client>, OximodError> {
-    let client = CLIENT.get()
-        .cloned()
+    #[cfg(feature = "mock")]
+    if super::mock::is_enabled() {
+        return Err(super::mock::unsupported());
+    }
+
+    let global = CLIENT.read().map_err(|_|
+        attach_printables!(
+            OximodError::GlobalClientMissing("Global client lock is poisoned.".to_string()),
+            "A thread panicked while setting the global client. Restart the application."
+        )
+    )?;
+
+    let client = global
+        .clone()
         .ok_or_else(||
             attach_printables!(
                 OximodError::GlobalClientMissing("Failed to clone arc".to_string()),
@@ -41,6 +59,30 @@ pub fn get_global_client() -> Result<Arc<Client>, OximodError> {
     Ok(client)
 }
 
+/// Checks that the global MongoDB client can actually reach the server.
+///
+/// Runs `{ ping: 1 }` against the `admin` database, which makes it suitable for
+/// readiness probes and health-check endpoints.
+///
+/// # Errors
+/// - Returns [`OximodError::GlobalClientMissing`] if no global client has been set.
+/// - Returns [`OximodError::ConnectionError`] if the server does not respond to the ping.
+pub async fn ping() -> Result<(), OximodError> {
+    let client = get_global_client()?;
+
+    client
+        .database("admin")
+        .run_command(doc! { "ping": 1 }).await
+        .map_err(|e|
+            attach_printables!(
+                OximodError::ConnectionError(format!("{}", e)),
+                "Ensure the MongoDB server is running and reachable from this host."
+            )
+        )?;
+
+    Ok(())
+}
+
 /// Sets the global MongoDB client used internally across the crate.
 ///
 /// This function should be called **once**, typically at the start of your application.
@@ -51,16 +93,243 @@ pub fn get_global_client() -> Result<Arc<Client>, OximodError> {
 ///
 /// # Errors
 /// - Returns [`OximodError::ConnectionError`] if the client cannot connect.
-/// - Returns [`OximodError::GlobalClientInitError`] if called more than once.
+/// - Returns [`OximodError::GlobalClientInitError`] if a global client is already set; see [`reset_global_client`].
 pub async fn set_global_client(mongo_uri: String) -> Result<(), OximodError> {
     let client = init_db(mongo_uri).await?;
 
-    CLIENT.set(client.into()).map_err(|_| 
+    set_global_client_with(client)
+}
+
+/// Sets the global MongoDB client, retrying until the server answers a `ping`.
+///
+/// Meant for container startup, where MongoDB may not accept connections yet. Each attempt
+/// builds a client and pings the `admin` database; failed attempts are followed by a wait
+/// that starts at `backoff` and doubles every time. Only failures to reach the server are
+/// retried; a malformed URI or invalid options fail on the first attempt.
+///
+/// # Arguments
+/// * `mongo_uri` - A valid MongoDB connection string.
+/// * `max_retries` - How many times to retry after the first attempt fails.
+/// * `backoff` - The wait before the first retry.
+///
+/// # Errors
+/// - Returns [`OximodError::ConnectionError`] from the last attempt once `max_retries` retries have
+///   failed. Its message starts with the number of attempts made.
+/// - Returns [`OximodError::ConnectionError`] right away for errors other than connection or
+///   server selection failures, such as a malformed URI.
+/// - Returns [`OximodError::GlobalClientInitError`] if a global client is already set.
+pub async fn set_global_client_with_retry(
+    mongo_uri: String,
+    max_retries: u32,
+    backoff: Duration
+) -> Result<(), OximodError> {
+    let mut delay = backoff;
+    let mut attempt = 1;
+
+    loop {
+        let result = match Client::with_uri_str(&mongo_uri).await {
+            Ok(client) =>
+                client
+                    .database("admin")
+                    .run_command(doc! { "ping": 1 }).await
+                    .map(|_| client),
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(client) => {
+                return set_global_client_with(client);
+            }
+            Err(e) if !is_connect_error(&e) => {
+                return Err(
+                    attach_printables!(
+                        OximodError::ConnectionError(format!("{}", e)),
+                        "Retrying won't help with this error. Check the connection string and its options."
+                    )
+                );
+            }
+            Err(e) if attempt > max_retries => {
+                return Err(
+                    attach_printables!(
+                        OximodError::ConnectionError(
+                            format!("Gave up after {} attempt(s): {}", attempt, e)
+                        ),
+                        "Ensure the MongoDB server is running and reachable, or raise `max_retries`."
+                    )
+                );
+            }
+            Err(_) => {
+                tokio::time::sleep(delay).await;
+                delay = delay.saturating_mul(2);
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Whether `e` means the server couldn't be reached yet, as opposed to a malformed URI,
+/// invalid options, or rejected credentials, which no amount of waiting will fix.
+fn is_connect_error(e: &mongodb::error::Error) -> bool {
+    matches!(
+        e.kind.as_ref(),
+        ErrorKind::ServerSelection { .. } |
+            ErrorKind::Io(_) |
+            ErrorKind::DnsResolve { .. } |
+            ErrorKind::ConnectionPoolCleared { .. }
+    )
+}
+
+/// Sets the global MongoDB client from an already-built [`Client`].
+///
+/// Use this instead of [`set_global_client`] when the client needs options a bare URI
+/// can't express, such as custom TLS settings, compressors, pool tuning, or auth mechanisms.
+///
+/// # Arguments
+/// * `client` - A client built by the caller, e.g. with `Client::with_options`.
+///
+/// # Errors
+/// Returns [`OximodError::GlobalClientInitError`] if a global client is already set.
+pub fn set_global_client_with(client: Client) -> Result<(), OximodError> {
+    let mut global = CLIENT.write().map_err(|_|
+        attach_printables!(
+            OximodError::GlobalClientInitError("Global client lock is poisoned.".to_string()),
+            "A thread panicked while setting the global client. Restart the application."
+        )
+    )?;
+
+    if global.is_some() {
+        return Err(
+            attach_printables!(
+                OximodError::GlobalClientInitError("CLIENT is already set.".to_string()),
+                "Ensure `set_global_client` is only called once, or call `reset_global_client` first in tests."
+            )
+        );
+    }
+
+    *global = Some(client.into());
+
+    Ok(())
+}
+
+/// Clears the global MongoDB client so that [`set_global_client`] can be called again.
+///
+/// Intended for test suites that need a fresh client between tests. Operations already in
+/// flight keep the `Arc<Client>` they obtained and finish on the old client; anything that
+/// calls [`get_global_client`] afterwards sees [`OximodError::GlobalClientMissing`] until a
+/// new client is set. Avoid this in production code, where swapping clients mid-flight
+/// splits work across two connection pools.
+///
+/// With the `mock` feature, this also switches the mock backend off and discards its documents.
+///
+/// # Returns
+/// The previous client, if one was set.
+///
+/// # Errors
+/// Returns [`OximodError::GlobalClientInitError`] if the global client lock is poisoned.
+pub fn reset_global_client() -> Result<Option<Arc<Client>>, OximodError> {
+    #[cfg(feature = "mock")]
+    super::mock::disable();
+
+    let mut global = CLIENT.write().map_err(|_|
+        attach_printables!(
+            OximodError::GlobalClientInitError("Global client lock is poisoned.".to_string()),
+            "A thread panicked while setting the global client. Restart the application."
+        )
+    )?;
+
+    Ok(global.take())
+}
+
+/// Registers an additional MongoDB client under the given name.
+///
+/// Named clients let a single process talk to several clusters, e.g. a primary
+/// cluster through the global client and an analytics cluster through a named one.
+/// Models pick a named client with `#[db("db_name", client = "name")]`.
+///
+/// # Arguments
+/// * `name` - The name models use to refer to this client.
+/// * `mongo_uri` - A valid MongoDB connection string.
+///
+/// # Errors
+/// - Returns [`OximodError::ConnectionError`] if the client cannot connect.
+/// - Returns [`OximodError::GlobalClientInitError`] if a client with this name is already registered.
+pub async fn set_client(name: &str, mongo_uri: String) -> Result<(), OximodError> {
+    let client = init_db(mongo_uri).await?;
+
+    set_client_with(name, client)
+}
+
+/// Registers an already-built [`Client`] under the given name.
+///
+/// Client-wide settings such as the server selection timeout can't be changed per
+/// collection, so a model that needs its own, e.g. a short timeout for a flaky analytics
+/// cluster, gets a client of its own. Build it with `Client::with_options`, register it
+/// here, and bind the model with `#[db("db_name", client = "name")]`. Other models keep
+/// the global client's settings.
+///
+/// # Arguments
+/// * `name` - The name models use to refer to this client.
+/// * `client` - A client built by the caller.
+///
+/// # Errors
+/// Returns [`OximodError::GlobalClientInitError`] if a client with this name is already registered.
+///
+/// # Example
+/// ```rust, no_run
+/// let mut options = ClientOptions::parse(analytics_uri).await?;
+/// options.server_selection_timeout = Some(Duration::from_secs(2));
+/// set_client_with("analytics", Client::with_options(options)?)?;
+/// ```
+pub fn set_client_with(name: &str, client: Client) -> Result<(), OximodError> {
+    let mut registry = clients().write().map_err(|_|
         attach_printables!(
-            OximodError::GlobalClientInitError("CLIENT set method failed.".to_string()),
-            "Ensure `set_global_client` is only called once, or restart the application."
+            OximodError::GlobalClientInitError("Client registry lock is poisoned.".to_string()),
+            "A thread panicked while registering a client. Restart the application."
         )
     )?;
 
+    if registry.contains_key(name) {
+        return Err(
+            attach_printables!(
+                OximodError::GlobalClientInitError(format!("Client `{}` is already set.", name)),
+                "Ensure `set_client` is only called once per name, or pick a different name."
+            )
+        );
+    }
+
+    registry.insert(name.to_string(), client.into());
+
     Ok(())
 }
+
+/// Retrieves a named MongoDB client previously registered with [`set_client`].
+///
+/// # Arguments
+/// * `name` - The name the client was registered under.
+///
+/// # Errors
+/// Returns a [`OximodError::GlobalClientMissing`] if no client has been registered under `name`,
+/// or while the `mock` backend is on.
+pub fn get_client(name: &str) -> Result<Arc<Client>, OximodError> {
+    #[cfg(feature = "mock")]
+    if super::mock::is_enabled() {
+        return Err(super::mock::unsupported());
+    }
+
+    let registry = clients().read().map_err(|_|
+        attach_printables!(
+            OximodError::GlobalClientMissing("Client registry lock is poisoned.".to_string()),
+            "A thread panicked while registering a client. Restart the application."
+        )
+    )?;
+
+    registry
+        .get(name)
+        .cloned()
+        .ok_or_else(||
+            attach_printables!(
+                OximodError::GlobalClientMissing(format!("No client named `{}`.", name)),
+                "Ensure you call `set_client` with this name before using models bound to it."
+            )
+        )
+}
@@ -0,0 +1,189 @@
+//! An in-memory stand-in for MongoDB, for unit tests that shouldn't need a server.
+//!
+//! Enabled with the `mock` feature and switched on at runtime with
+//! [`set_global_client_mock`]. While it is on, `save`, `find`, `find_one`, and `find_by_id`
+//! of every model read and write an in-memory map per collection instead of the server.
+//! Filters may only compare fields for equality, e.g. `{ "name": "alice", "address.city": "Oslo" }`.
+//!
+//! Every other operation fails with [`OximodError::GlobalClientMissing`], because the
+//! client getters refuse to hand out a real client while the mock is on. Indexes are not
+//! created, so unique indexes are not enforced.
+
+use std::{ collections::{ BTreeMap, HashMap }, sync::RwLock };
+use mongodb::bson::{ oid::ObjectId, Bson, Document };
+use crate::{ error::oximod_error::OximodError, Printable };
+
+/// The stored documents of each `(database, collection)`, ordered by `_id`, while the mock is on.
+type Store = HashMap<(String, String), BTreeMap<ObjectId, Document>>;
+
+static STORE: RwLock<Option<Store>> = RwLock::new(None);
+
+/// Switches every model over to the in-memory backend.
+///
+/// Calling it again keeps the stored documents, so tests running in parallel can each call it.
+/// Like tests against a real server, give each test its own collection to keep them apart.
+/// [`reset_global_client`](crate::feature::conn::client::reset_global_client) switches the
+/// mock off and discards its documents.
+///
+/// # Errors
+/// Returns [`OximodError::GlobalClientInitError`] if the store lock is poisoned.
+///
+/// # Example
+/// ```rust, no_run
+/// oximod::set_global_client_mock()?;
+/// let id = User::new().name("alice".to_string()).save().await?;
+/// assert!(User::find_by_id(id).await?.is_some());
+/// ```
+pub fn set_global_client_mock() -> Result<(), OximodError> {
+    let mut store = STORE.write().map_err(|_|
+        attach_printables!(
+            OximodError::GlobalClientInitError("Mock store lock is poisoned.".to_string()),
+            "A thread panicked while using the mock backend. Restart the test run."
+        )
+    )?;
+
+    store.get_or_insert_with(HashMap::new);
+    Ok(())
+}
+
+#[doc(hidden)]
+/// Whether [`set_global_client_mock`] has switched the models over to the in-memory backend.
+pub fn is_enabled() -> bool {
+    STORE.read().map(|store| store.is_some()).unwrap_or(false)
+}
+
+/// Switches the mock off and discards its documents.
+pub(crate) fn disable() {
+    if let Ok(mut store) = STORE.write() {
+        store.take();
+    }
+}
+
+/// The error the client getters return while the mock is on.
+pub(crate) fn unsupported() -> OximodError {
+    attach_printables!(
+        OximodError::GlobalClientMissing(
+            "The mock backend is active and only supports save, find, find_one, and find_by_id.".to_string()
+        ),
+        "Test this operation against a real MongoDB server instead."
+    )
+}
+
+#[doc(hidden)]
+/// Stores `document`, giving it a fresh `ObjectId` first if it has no `_id`.
+///
+/// # Errors
+/// - Returns [`OximodError::DuplicateKey`] if a document with the same `_id` is stored.
+/// - Returns [`OximodError::SerializationError`] if the `_id` is not an `ObjectId`.
+pub fn insert(db: &str, collection: &str, document: &mut Document) -> Result<ObjectId, OximodError> {
+    if !document.contains_key("_id") {
+        document.insert("_id", ObjectId::new());
+    }
+    let Some(Bson::ObjectId(id)) = document.get("_id").cloned() else {
+        return Err(
+            attach_printables!(
+                OximodError::SerializationError("inserted_id is not an ObjectId".to_string()),
+                "The mock backend only stores documents with an ObjectId _id."
+            )
+        );
+    };
+
+    let mut store = STORE.write().map_err(|_| poisoned())?;
+    let documents = store
+        .get_or_insert_with(HashMap::new)
+        .entry((db.to_string(), collection.to_string()))
+        .or_default();
+    if documents.contains_key(&id) {
+        return Err(OximodError::DuplicateKey { key: "_id_".to_string() });
+    }
+    documents.insert(id, document.clone());
+    Ok(id)
+}
+
+#[doc(hidden)]
+/// Returns the stored documents matching `filter`, in `_id` order.
+///
+/// # Errors
+/// Returns [`OximodError::CommandError`] if `filter` uses an operator such as `$gt` or `$or`.
+pub fn find(db: &str, collection: &str, filter: &Document) -> Result<Vec<Document>, OximodError> {
+    check_filter(filter)?;
+
+    let store = STORE.read().map_err(|_| poisoned())?;
+    let documents = store
+        .as_ref()
+        .and_then(|store| store.get(&(db.to_string(), collection.to_string())));
+    Ok(
+        documents
+            .into_iter()
+            .flat_map(|documents| documents.values())
+            .filter(|document| filter.iter().all(|(key, expected)| field_matches(document, key, expected)))
+            .cloned()
+            .collect()
+    )
+}
+
+fn poisoned() -> OximodError {
+    attach_printables!(
+        OximodError::ConnectionError("Mock store lock is poisoned.".to_string()),
+        "A thread panicked while using the mock backend. Restart the test run."
+    )
+}
+
+/// Rejects filters the mock can't evaluate, so they fail loudly instead of matching wrongly.
+fn check_filter(filter: &Document) -> Result<(), OximodError> {
+    let is_operator = |key: &str| key.starts_with('$');
+    let unsupported = filter.iter().find(|(key, value)| {
+        is_operator(key) ||
+            matches!(value, Bson::Document(inner) if inner.keys().any(|key| is_operator(key)))
+    });
+
+    match unsupported {
+        Some((key, _)) =>
+            Err(
+                attach_printables!(
+                    OximodError::CommandError(
+                        format!("The mock backend only supports equality filters, but '{}' uses an operator.", key)
+                    ),
+                    "Rewrite the filter as plain field/value pairs, or test it against a real MongoDB server."
+                )
+            ),
+        None => Ok(()),
+    }
+}
+
+/// Whether the value at the dotted `path` equals `expected`, as MongoDB's equality match would:
+/// a missing field matches `null`, an array matches any of its elements, and numbers compare by value.
+fn field_matches(document: &Document, path: &str, expected: &Bson) -> bool {
+    let mut current = None;
+    let mut parent = Some(document);
+    for part in path.split('.') {
+        current = parent.and_then(|document| document.get(part));
+        parent = current.and_then(Bson::as_document);
+    }
+
+    match current {
+        Some(actual) => values_match(actual, expected),
+        None => *expected == Bson::Null,
+    }
+}
+
+fn values_match(actual: &Bson, expected: &Bson) -> bool {
+    match (actual, expected) {
+        (Bson::Array(items), _) if !matches!(expected, Bson::Array(_)) =>
+            items.iter().any(|item| values_match(item, expected)),
+        _ =>
+            match (as_number(actual), as_number(expected)) {
+                (Some(actual), Some(expected)) => actual == expected,
+                _ => actual == expected,
+            }
+    }
+}
+
+fn as_number(value: &Bson) -> Option<f64> {
+    match value {
+        Bson::Int32(value) => Some(*value as f64),
+        Bson::Int64(value) => Some(*value as f64),
+        Bson::Double(value) => Some(*value),
+        _ => None,
+    }
+}
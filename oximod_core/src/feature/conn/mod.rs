@@ -1 +1,4 @@
 pub mod client;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod transaction;
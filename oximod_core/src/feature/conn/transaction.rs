@@ -0,0 +1,160 @@
+use std::{ future::Future, pin::Pin, time::{ Duration, Instant } };
+use mongodb::{
+    error::{ Error, ErrorKind, TRANSIENT_TRANSACTION_ERROR, UNKNOWN_TRANSACTION_COMMIT_RESULT },
+    Client,
+    ClientSession,
+};
+use crate::{
+    error::oximod_error::OximodError,
+    feature::{ conn::client::{ get_client, get_global_client }, model::write_error::write_error },
+    Printable,
+};
+
+/// How long [`with_transaction`] keeps retrying transient failures, matching the MongoDB drivers.
+const MAX_RETRY_TIME: Duration = Duration::from_secs(120);
+
+/// The boxed future returned by a [`with_transaction`] callback.
+pub type TransactionFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, OximodError>> + Send + 'a>>;
+
+/// Runs `callback` inside a MongoDB transaction on the global client.
+///
+/// The transaction is committed when the callback returns `Ok` and aborted when it returns `Err`.
+/// Following MongoDB's guidance, the whole callback is retried when an operation fails with a
+/// `TransientTransactionError`, and the commit is retried when its outcome is unknown, for up
+/// to two minutes. Use the `*_with_session` model methods inside the callback so the operations
+/// join the transaction. A session only works with models on the same client; for models bound
+/// with `#[db("...", client = "name")]`, use [`with_transaction_on`] instead.
+///
+/// # Arguments
+/// * `callback` - Receives the session and returns a boxed future, e.g. `|session| Box::pin(async move { ... })`.
+///   It may run more than once, so it should not have side effects outside the database.
+///
+/// # Errors
+/// - Returns [`OximodError::GlobalClientMissing`] if no global client has been set.
+/// - Returns [`OximodError::TransactionError`] if the transaction cannot be started or committed.
+/// - Returns the callback's error after aborting the transaction.
+pub async fn with_transaction<T, F>(callback: F) -> Result<T, OximodError>
+    where F: for<'a> FnMut(&'a mut ClientSession) -> TransactionFuture<'a, T>
+{
+    let client = get_global_client()?;
+    run_transaction(&client, callback).await
+}
+
+/// Runs `callback` inside a MongoDB transaction on the client registered under `name`.
+///
+/// Behaves like [`with_transaction`], for models bound with `#[db("...", client = "name")]`.
+/// Every model used inside the callback must be bound to the same client.
+///
+/// # Arguments
+/// * `name` - The name the client was registered under with
+///   [`set_client`](crate::feature::conn::client::set_client).
+/// * `callback` - As for [`with_transaction`].
+///
+/// # Errors
+/// - Returns [`OximodError::GlobalClientMissing`] if no client has been registered under `name`.
+/// - Otherwise the same errors as [`with_transaction`].
+///
+/// # Example
+/// ```rust, no_run
+/// with_transaction_on("analytics", |session| Box::pin(async move {
+///     Event::new().kind("signup".to_string()).save_with_session(session).await?;
+///     Ok(())
+/// })).await?;
+/// ```
+pub async fn with_transaction_on<T, F>(name: &str, callback: F) -> Result<T, OximodError>
+    where F: for<'a> FnMut(&'a mut ClientSession) -> TransactionFuture<'a, T>
+{
+    let client = get_client(name)?;
+    run_transaction(&client, callback).await
+}
+
+async fn run_transaction<T, F>(client: &Client, mut callback: F) -> Result<T, OximodError>
+    where F: for<'a> FnMut(&'a mut ClientSession) -> TransactionFuture<'a, T>
+{
+    let mut session = client.start_session().await.map_err(|e|
+        attach_printables!(
+            OximodError::TransactionError(format!("{}", e)),
+            "Failed to start a session. Check that the mongodb server is reachable."
+        )
+    )?;
+    let started = Instant::now();
+
+    'transaction: loop {
+        session.start_transaction().await.map_err(|e|
+            attach_printables!(
+                OximodError::TransactionError(format!("{}", e)),
+                "Failed to start a transaction. Transactions require a replica set or sharded cluster."
+            )
+        )?;
+
+        let value = match callback(&mut session).await {
+            Ok(value) => value,
+            Err(e) => {
+                // The transaction may already be aborted by the server; nothing to report then.
+                let _ = session.abort_transaction().await;
+                if
+                    matches!(e, OximodError::TransientTransactionError(_)) &&
+                    started.elapsed() < MAX_RETRY_TIME
+                {
+                    continue 'transaction;
+                }
+                return Err(e);
+            }
+        };
+
+        loop {
+            match session.commit_transaction().await {
+                Ok(()) => {
+                    return Ok(value);
+                }
+                Err(e) if
+                    e.contains_label(UNKNOWN_TRANSACTION_COMMIT_RESULT) &&
+                    started.elapsed() < MAX_RETRY_TIME
+                => {
+                    continue;
+                }
+                Err(e) if
+                    e.contains_label(TRANSIENT_TRANSACTION_ERROR) &&
+                    started.elapsed() < MAX_RETRY_TIME
+                => {
+                    continue 'transaction;
+                }
+                Err(e) => {
+                    return Err(
+                        attach_printables!(
+                            OximodError::TransactionError(format!("{}", e)),
+                            "Failed to commit the transaction. No changes were applied."
+                        )
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[doc(hidden)]
+/// Maps a driver error raised inside a session to an [`OximodError`].
+///
+/// Errors labelled `TransientTransactionError` become [`OximodError::TransientTransactionError`]
+/// so [`with_transaction`] can retry them. A session started on a different client than the
+/// model's becomes an [`OximodError::TransactionError`] saying so; everything else goes through
+/// [`write_error`](crate::feature::model::write_error::write_error).
+pub fn session_error(e: Error, fallback: fn(String) -> OximodError) -> OximodError {
+    if e.contains_label(TRANSIENT_TRANSACTION_ERROR) {
+        OximodError::TransientTransactionError(e.to_string())
+    } else if is_client_mismatch(&e) {
+        OximodError::TransactionError(
+            "The session was started on a different client than the model's. Use `with_transaction_on(name, ..)` for models bound with `#[db(\"...\", client = \"name\")]`.".to_string()
+        )
+    } else {
+        write_error(e, fallback)
+    }
+}
+
+/// Whether the driver rejected the session because it belongs to another client.
+fn is_client_mismatch(e: &Error) -> bool {
+    matches!(
+        e.kind.as_ref(),
+        ErrorKind::InvalidArgument { message, .. } if message.contains("same client")
+    )
+}
@@ -0,0 +1,26 @@
+use mongodb::bson::oid::ObjectId;
+use crate::{ error::oximod_error::OximodError, Printable };
+
+/// Parses a hex string such as a URL path segment into an [`ObjectId`].
+///
+/// Unlike `ObjectId::parse_str`, a failure is an [`OximodError::InvalidId`], which API
+/// layers can tell apart from database errors and map to `400 Bad Request`.
+///
+/// # Arguments
+/// * `id` - The 24-character hexadecimal representation of an `ObjectId`.
+///
+/// # Errors
+/// Returns [`OximodError::InvalidId`] naming the rejected input if `id` is not a valid `ObjectId`.
+///
+/// # Example
+/// ```rust, no_run
+/// let id = oximod::parse_object_id("652efcddfc13ae2c82000001")?;
+/// ```
+pub fn parse_object_id(id: &str) -> Result<ObjectId, OximodError> {
+    ObjectId::parse_str(id).map_err(|e|
+        attach_printables!(
+            OximodError::InvalidId(format!("'{}': {}", id, e)),
+            "An ObjectId is a 24-character hexadecimal string. Check the id passed in by the caller."
+        )
+    )
+}
@@ -0,0 +1,67 @@
+use mongodb::bson::{ doc, Bson, Document };
+use crate::{ error::oximod_error::OximodError, feature::model::Model, Printable };
+
+#[doc(hidden)]
+/// Turns a stored key such as `"price"` or `"address.zip"` into the `"$price"` field path
+/// used inside aggregation expressions.
+///
+/// Keys starting with `$` are rejected, so a caller-supplied name can't turn into a
+/// variable like `$$ROOT` or an operator expression.
+pub fn field_path(field: &str) -> Result<String, OximodError> {
+    if field.is_empty() || field.starts_with('$') || field.contains('\0') || field.split('.').any(str::is_empty) {
+        return Err(
+            attach_printables!(
+                OximodError::AggregationError(format!("Invalid field name '{}'", field)),
+                "Pass a stored key such as \"price\" or \"address.zip\", without a leading '$'."
+            )
+        );
+    }
+
+    Ok(format!("${}", field))
+}
+
+/// Runs `{ $match: filter }, { $group: { _id: null, value: { <op>: "$field" } } }` and
+/// returns `value`, or `None` when nothing matches.
+pub(crate) async fn group_value<M: Model + Sized>(
+    op: &str,
+    field: &str,
+    filter: Document
+) -> Result<Option<Bson>, OximodError> {
+    let mut accumulator = Document::new();
+    accumulator.insert(op, field_path(field)?);
+    let pipeline = vec![
+        doc! { "$match": filter },
+        doc! { "$group": { "_id": Bson::Null, "value": accumulator } }
+    ];
+
+    let mut groups = M::aggregate_as::<Document>(pipeline).await?;
+    Ok(groups.pop().and_then(|mut group| group.remove("value")))
+}
+
+/// Converts a numeric aggregation result to `f64`; `$avg` over non-numeric values yields `Null`.
+pub(crate) fn as_f64(value: Bson) -> Result<Option<f64>, OximodError> {
+    match value {
+        Bson::Null => Ok(None),
+        Bson::Int32(n) => Ok(Some(n as f64)),
+        Bson::Int64(n) => Ok(Some(n as f64)),
+        Bson::Double(n) => Ok(Some(n)),
+        Bson::Decimal128(n) =>
+            n
+                .to_string()
+                .parse()
+                .map(Some)
+                .map_err(|_|
+                    attach_printables!(
+                        OximodError::AggregationError(format!("Cannot convert {} to f64", n)),
+                        "Use `aggregate` directly to read Decimal128 results without loss."
+                    )
+                ),
+        other =>
+            Err(
+                attach_printables!(
+                    OximodError::AggregationError(format!("Expected a number, got {}", other)),
+                    "Make sure the field holds numeric values."
+                )
+            ),
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+use mongodb::{
+    bson::{ oid::ObjectId, Bson, Document },
+    error::{ ErrorKind, InsertManyError },
+    Collection,
+};
+use crate::{
+    error::oximod_error::OximodError,
+    feature::model::{ write_error::{ indexed_write_error, write_error }, Model },
+    Printable,
+};
+
+/// The outcome of [`Model::save_many_lenient`](crate::feature::model::Model::save_many_lenient).
+///
+/// Every input index ends up in exactly one of the two lists, unless a `post_save` hook fails
+/// for a document that was already stored, which then appears in both.
+#[derive(Debug, Default)]
+pub struct BatchResult {
+    /// The ids of the stored documents, in input order.
+    pub inserted: Vec<ObjectId>,
+    /// The input index of each rejected document and why, in input order.
+    pub failures: Vec<(usize, OximodError)>,
+}
+
+impl BatchResult {
+    /// Whether every document was stored.
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+#[doc(hidden)]
+/// Validates, serializes, and runs the `pre_save` hooks of every model, then inserts the ones
+/// that passed with a single unordered `insert_many`, so one bad document doesn't stop the rest.
+///
+/// Documents without an `_id` get a fresh `ObjectId` first, as the driver would, so the ids of
+/// the stored documents are known even when some writes fail. If the outcome of the call as a
+/// whole is unknown, e.g. after a network error, every document sent is reported as failed
+/// although some may have been stored.
+pub async fn insert_lenient<M: Model>(
+    collection: &Collection<Document>,
+    models: Vec<M>
+) -> BatchResult {
+    let mut result = BatchResult::default();
+
+    let mut pending = Vec::with_capacity(models.len());
+    for (index, model) in models.iter().enumerate() {
+        match prepare(model).await {
+            Ok(document) => pending.push((index, document)),
+            Err(e) => result.failures.push((index, e)),
+        }
+    }
+
+    if pending.is_empty() {
+        return result;
+    }
+
+    let outcome = collection
+        .insert_many(pending.iter().map(|(_, document)| document))
+        .ordered(false).await;
+
+    let mut failed: HashMap<usize, OximodError> = match outcome {
+        Ok(_) => HashMap::new(),
+        Err(e) =>
+            match e.kind.as_ref() {
+                ErrorKind::InsertMany(
+                    InsertManyError { write_errors: Some(write_errors), write_concern_error: None, .. },
+                ) =>
+                    write_errors
+                        .iter()
+                        .map(|write_error| (write_error.index, indexed_write_error(write_error)))
+                        .collect(),
+                _ =>
+                    (0..pending.len())
+                        .map(|position| {
+                            (
+                                position,
+                                attach_printables!(
+                                    write_error(e.clone(), OximodError::ConnectionError),
+                                    "Failed to insert documents. Check if the mongodb server is reachable; some documents may have been stored."
+                                ),
+                            )
+                        })
+                        .collect(),
+            }
+    };
+
+    for (position, (index, mut document)) in pending.into_iter().enumerate() {
+        if let Some(e) = failed.remove(&position) {
+            result.failures.push((index, e));
+            continue;
+        }
+
+        let Some(Bson::ObjectId(id)) = document.get("_id").cloned() else {
+            result.failures.push((
+                index,
+                attach_printables!(
+                    OximodError::SerializationError("inserted_id is not an ObjectId".to_string()),
+                    "The document was stored with a custom _id, which save_many_lenient can't report. Use get_collection().insert_many for custom ids."
+                ),
+            ));
+            continue;
+        };
+
+        result.inserted.push(id);
+        if let Err(e) = M::_hooks().run_post_save(&mut document).await {
+            result.failures.push((index, e));
+        }
+    }
+
+    result.failures.sort_by_key(|(index, _)| *index);
+    result
+}
+
+async fn prepare<M: Model>(model: &M) -> Result<Document, OximodError> {
+    model.validate()?;
+
+    let mut document = model._to_document()?;
+    M::_hooks().run_pre_save(&mut document).await?;
+
+    if !document.contains_key("_id") {
+        document.insert("_id", ObjectId::new());
+    }
+    Ok(document)
+}
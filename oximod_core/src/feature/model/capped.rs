@@ -0,0 +1,117 @@
+use mongodb::{
+    bson::{ doc, Document },
+    error::ErrorKind,
+    options::CreateCollectionOptions,
+    Collection,
+    Database,
+};
+use crate::{ error::oximod_error::OximodError, Printable };
+
+/// The server error code for creating a collection that already exists.
+const NAMESPACE_EXISTS: i32 = 48;
+
+#[doc(hidden)]
+/// Creates `collection` as a capped collection of `size` bytes and at most `max` documents,
+/// unless it already exists.
+///
+/// An existing collection is accepted if it is capped with at least `size` bytes (the server
+/// rounds sizes up to a multiple of 256) and, when `max` is given, the same `max`. Anything
+/// else is an [`OximodError::ConnectionError`], since inserts would otherwise go to a
+/// collection that never rolls over.
+pub async fn ensure_capped(
+    collection: &Collection<Document>,
+    size: u64,
+    max: Option<u64>
+) -> Result<(), OximodError> {
+    let name = collection.name();
+    let db = collection.client().database(&collection.namespace().db);
+
+    if let Some(options) = existing_options(&db, name).await? {
+        return check_options(name, &options, size, max);
+    }
+
+    let options = CreateCollectionOptions::builder().capped(true).size(size).max(max).build();
+    match db.create_collection(name).with_options(options).await {
+        Ok(()) => Ok(()),
+        Err(e) if matches!(e.kind.as_ref(), ErrorKind::Command(c) if c.code == NAMESPACE_EXISTS) => {
+            // Another process created it between the lookup and the create.
+            match existing_options(&db, name).await? {
+                Some(options) => check_options(name, &options, size, max),
+                None => Ok(()),
+            }
+        }
+        Err(e) =>
+            Err(
+                attach_printables!(
+                    OximodError::ConnectionError(e.to_string()),
+                    "Failed to create the capped collection. Check that the user may run `create` on this database."
+                )
+            ),
+    }
+}
+
+async fn existing_options(
+    db: &Database,
+    name: &str
+) -> Result<Option<CreateCollectionOptions>, OximodError> {
+    let mut cursor = db
+        .list_collections()
+        .filter(doc! { "name": name }).await
+        .map_err(|e| {
+            attach_printables!(
+                OximodError::ConnectionError(e.to_string()),
+                "Failed to look up the collection. Check if the mongodb server is reachable."
+            )
+        })?;
+
+    let found = cursor.advance().await.map_err(|e| {
+        attach_printables!(
+            OximodError::ConnectionError(e.to_string()),
+            "Failed to read the collection's options."
+        )
+    })?;
+    if !found {
+        return Ok(None);
+    }
+
+    let spec = cursor.deserialize_current().map_err(|e| {
+        attach_printables!(
+            OximodError::ConnectionError(e.to_string()),
+            "Failed to read the collection's options."
+        )
+    })?;
+
+    Ok(Some(spec.options))
+}
+
+fn check_options(
+    name: &str,
+    options: &CreateCollectionOptions,
+    size: u64,
+    max: Option<u64>
+) -> Result<(), OximodError> {
+    let capped = options.capped == Some(true);
+    let size_matches = options.size.is_some_and(|existing| existing >= size);
+    let max_matches = max.is_none() || options.max == max;
+
+    if capped && size_matches && max_matches {
+        return Ok(());
+    }
+
+    Err(
+        attach_printables!(
+            OximodError::ConnectionError(
+                format!(
+                    "collection '{}' already exists with capped: {}, size: {:?}, max: {:?}; expected capped with size {} and max {:?}",
+                    name,
+                    capped,
+                    options.size,
+                    options.max,
+                    size,
+                    max
+                )
+            ),
+            "A capped collection must be created before the first insert. Drop the collection or convert it with the `convertToCapped` command."
+        )
+    )
+}
@@ -0,0 +1,45 @@
+use std::{ pin::Pin, task::{ Context, Poll } };
+use futures_core::Stream;
+use mongodb::change_stream::{ event::{ ChangeStreamEvent, ResumeToken }, ChangeStream as DriverChangeStream };
+use serde::de::DeserializeOwned;
+use crate::{ error::oximod_error::OximodError, Printable };
+
+/// A stream of change events for a model, returned by [`Model::watch`](crate::feature::model::Model::watch).
+///
+/// Each item is a [`ChangeStreamEvent`](https://docs.rs/mongodb/latest/mongodb/change_stream/event/struct.ChangeStreamEvent.html)
+/// whose `full_document` is deserialized into the model when present.
+/// Store [`ChangeStream::resume_token`] to restart the stream after a restart or disconnect.
+pub struct ChangeStream<T> where T: DeserializeOwned {
+    inner: DriverChangeStream<ChangeStreamEvent<T>>,
+}
+
+impl<T> ChangeStream<T> where T: DeserializeOwned {
+    #[doc(hidden)]
+    pub fn new(inner: DriverChangeStream<ChangeStreamEvent<T>>) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the token of the most recently seen event, for use with `watch(..., Some(token))`.
+    pub fn resume_token(&self) -> Option<ResumeToken> {
+        self.inner.resume_token()
+    }
+}
+
+impl<T> Stream for ChangeStream<T> where T: DeserializeOwned + Unpin + Send + Sync {
+    type Item = Result<ChangeStreamEvent<T>, OximodError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.inner)
+            .poll_next(cx)
+            .map(|event| {
+                event.map(|event| {
+                    event.map_err(|e|
+                        attach_printables!(
+                            OximodError::ConnectionError(e.to_string()),
+                            "Change stream failed. Change streams require a replica set or sharded cluster."
+                        )
+                    )
+                })
+            })
+    }
+}
@@ -0,0 +1,33 @@
+use std::marker::PhantomData;
+
+#[doc(hidden)]
+/// Never implements [`ClearAllowed`]; see that trait.
+pub struct ClearDisabled<'a>(PhantomData<&'a ()>);
+
+#[doc(hidden)]
+/// Turns calls to `clear()` on models declared with `#[model(allow_clear = false)]` into a
+/// compile error.
+///
+/// The derive shadows `Model::clear` with an inherent `clear()` bounded on
+/// `for<'a> ClearDisabled<'a>: ClearAllowed`. The bound can never hold, but because it is
+/// higher-ranked it is only checked where the function is called.
+#[diagnostic::on_unimplemented(
+    message = "`clear()` is disabled for this model by `#[model(allow_clear = false)]`",
+    label = "clear() is disabled",
+    note = "delete with an explicit filter, e.g. `Model::delete(doc! {{ ... }})`, or remove `allow_clear = false`"
+)]
+pub trait ClearAllowed {}
+
+#[doc(hidden)]
+/// Never implements [`DropAllowed`]; see that trait.
+pub struct DropDisabled<'a>(PhantomData<&'a ()>);
+
+#[doc(hidden)]
+/// Turns calls to `drop_collection()` on models declared with `#[model(allow_clear = false)]`
+/// into a compile error, the same way [`ClearAllowed`] guards `clear()`.
+#[diagnostic::on_unimplemented(
+    message = "`drop_collection()` is disabled for this model by `#[model(allow_clear = false)]`",
+    label = "drop_collection() is disabled",
+    note = "drop it through `Model::get_collection()?.drop()` if you really mean to, or remove `allow_clear = false`"
+)]
+pub trait DropAllowed {}
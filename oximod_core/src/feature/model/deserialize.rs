@@ -0,0 +1,29 @@
+use mongodb::bson::{ self, Bson, Document };
+use serde::de::DeserializeOwned;
+use crate::error::oximod_error::OximodError;
+
+#[doc(hidden)]
+/// Deserializes a stored document into `T`.
+///
+/// On failure the [`OximodError::SerializationError`] names the document's `_id`, so the one
+/// bad document in a `find` can be looked up. serde's message names the field for missing,
+/// unknown, or duplicate fields; type mismatches only name the expected type.
+pub fn from_document<T: DeserializeOwned>(document: Document) -> Result<T, OximodError> {
+    let id = document.get("_id").cloned();
+    bson::from_document(document).map_err(|e| {
+        match id {
+            Some(id) => OximodError::SerializationError(format!("{e} (document _id: {id})")),
+            None => OximodError::SerializationError(e.to_string()),
+        }
+    })
+}
+
+/// A stored document that [`Model::find_lenient`](crate::feature::model::Model::find_lenient)
+/// skipped because it didn't match the model.
+#[derive(Debug)]
+pub struct DeserializeError {
+    /// The document's `_id`, or `None` if it has none.
+    pub id: Option<Bson>,
+    /// Why the document couldn't be deserialized.
+    pub error: OximodError,
+}
@@ -0,0 +1,118 @@
+use mongodb::bson::{ doc, Bson, Document };
+
+/// The query document accumulated by a generated `<Model>Filter` builder.
+///
+/// Field conditions are collected per key, so `age().gt(18).age().lt(65)` produces
+/// `{ "age": { "$gt": 18, "$lt": 65 } }`. Groups added with [`Filter::and`] and
+/// [`Filter::or`] are combined with the field conditions under `$and`.
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    conditions: Document,
+    clauses: Vec<Document>,
+}
+
+impl Filter {
+    /// Adds `{ key: { op: value } }`, merging with existing operators on the same key.
+    pub fn push(&mut self, key: &str, op: &str, value: Bson) {
+        match self.conditions.get_mut(key) {
+            Some(Bson::Document(ops)) => {
+                ops.insert(op, value);
+            }
+            _ => {
+                self.conditions.insert(key, doc! { op: value });
+            }
+        }
+    }
+
+    /// Requires every one of `filters` to match.
+    pub fn and(&mut self, filters: impl IntoIterator<Item = Document>) {
+        self.clauses.push(doc! { "$and": filters.into_iter().collect::<Vec<_>>() });
+    }
+
+    /// Requires at least one of `filters` to match.
+    pub fn or(&mut self, filters: impl IntoIterator<Item = Document>) {
+        self.clauses.push(doc! { "$or": filters.into_iter().collect::<Vec<_>>() });
+    }
+
+    /// Produces the final query document.
+    pub fn build(self) -> Document {
+        if self.clauses.is_empty() {
+            return self.conditions;
+        }
+
+        let mut all = Vec::with_capacity(self.clauses.len() + 1);
+        if !self.conditions.is_empty() {
+            all.push(self.conditions);
+        }
+        all.extend(self.clauses);
+
+        if all.len() == 1 {
+            all.remove(0)
+        } else {
+            doc! { "$and": all }
+        }
+    }
+}
+
+/// Implemented by the `<Model>Filter` builders generated by `#[derive(Model)]`.
+pub trait FilterBuilder: Sized {
+    /// Gives [`FilterField`] access to the underlying [`Filter`].
+    fn filter_mut(&mut self) -> &mut Filter;
+}
+
+/// A field selected on a filter builder, waiting for a comparison operator.
+///
+/// Each operator adds the condition and hands the builder back, so calls chain:
+/// `UserFilter::new().name().eq("Alice").age().gte(18)`.
+pub struct FilterField<F> {
+    builder: F,
+    key: &'static str,
+}
+
+impl<F: FilterBuilder> FilterField<F> {
+    #[doc(hidden)]
+    pub fn new(builder: F, key: &'static str) -> Self {
+        Self { builder, key }
+    }
+
+    fn op(mut self, op: &str, value: Bson) -> F {
+        self.builder.filter_mut().push(self.key, op, value);
+        self.builder
+    }
+
+    /// Matches values equal to `value` (`$eq`).
+    pub fn eq(self, value: impl Into<Bson>) -> F {
+        self.op("$eq", value.into())
+    }
+
+    /// Matches values not equal to `value` (`$ne`).
+    pub fn ne(self, value: impl Into<Bson>) -> F {
+        self.op("$ne", value.into())
+    }
+
+    /// Matches values greater than `value` (`$gt`).
+    pub fn gt(self, value: impl Into<Bson>) -> F {
+        self.op("$gt", value.into())
+    }
+
+    /// Matches values greater than or equal to `value` (`$gte`).
+    pub fn gte(self, value: impl Into<Bson>) -> F {
+        self.op("$gte", value.into())
+    }
+
+    /// Matches values less than `value` (`$lt`).
+    pub fn lt(self, value: impl Into<Bson>) -> F {
+        self.op("$lt", value.into())
+    }
+
+    /// Matches values less than or equal to `value` (`$lte`).
+    pub fn lte(self, value: impl Into<Bson>) -> F {
+        self.op("$lte", value.into())
+    }
+
+    /// Matches any of `values` (`$in`).
+    pub fn in_<V: Into<Bson>>(self, values: impl IntoIterator<Item = V>) -> F {
+        let values = values.into_iter().map(Into::into).collect::<Vec<Bson>>();
+        self.op("$in", Bson::Array(values))
+    }
+}
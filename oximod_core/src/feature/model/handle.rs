@@ -0,0 +1,221 @@
+use std::marker::PhantomData;
+use mongodb::{
+    bson::{ oid::ObjectId, Document },
+    results::{ DeleteResult, UpdateResult },
+    Collection,
+    Cursor,
+};
+use serde::{ de::DeserializeOwned, Serialize };
+use crate::{
+    error::oximod_error::OximodError,
+    feature::{ model::{ deserialize, schema::apply_json_schema, write_error::write_error, Model }, validate::Validate },
+    Printable,
+};
+
+/// A model bound to a collection or database chosen at runtime, returned by
+/// [`Model::with_collection`](crate::feature::model::Model::with_collection) and
+/// [`Model::using_db`](crate::feature::model::Model::using_db).
+///
+/// The handle runs the same validation and hooks as the model's own methods, so a tenant's
+/// `users_tenant42` behaves like the declared `users` collection. Indexes are not created
+/// on `save`; call [`CollectionHandle::sync_indexes`] once when the collection is provisioned.
+pub struct CollectionHandle<M> {
+    collection: Collection<Document>,
+    model: PhantomData<fn() -> M>,
+}
+
+impl<M> CollectionHandle<M>
+    where M: Model + Serialize + DeserializeOwned + Send + Sync
+{
+    #[doc(hidden)]
+    pub fn new(collection: Collection<Document>) -> Self {
+        Self { collection, model: PhantomData }
+    }
+
+    /// Returns the underlying collection, for operations the handle doesn't cover.
+    pub fn collection(&self) -> &Collection<Document> {
+        &self.collection
+    }
+
+    /// Creates the model's declared indexes on this collection, and installs its
+    /// [`Model::json_schema`] validator if it has one.
+    pub async fn sync_indexes(&self) -> Result<(), OximodError> {
+        M::_create_indexes(&self.collection).await?;
+        if let Some(schema) = M::json_schema() {
+            apply_json_schema(&self.collection, schema).await?;
+        }
+        Ok(())
+    }
+
+    /// Validates and inserts `model`, like [`Model::save`].
+    pub async fn save(&self, model: &M) -> Result<ObjectId, OximodError> {
+        crate::instrument!(self.collection.name(), "save", {
+            Validate::validate(model)?;
+
+            let mut document = model._to_document()?;
+
+            M::_hooks().run_pre_save(&mut document).await?;
+
+            let result = self.collection.insert_one(&document).await.map_err(|e|
+                attach_printables!(
+                    write_error(e, OximodError::ConnectionError),
+                    "Failed to insert document. Check if the mongodb server is reachable and the collection exists."
+                )
+            )?;
+
+            let id = result.inserted_id.as_object_id().ok_or_else(||
+                attach_printables!(
+                    OximodError::SerializationError("inserted_id is not an ObjectId".to_string()),
+                    "Expected inserted_id to be an ObjectId but received something else. This may happen if you're using a custom _id."
+                )
+            )?;
+
+            document.insert("_id", id);
+            M::_hooks().run_post_save(&mut document).await?;
+
+            Ok(id)
+        })
+    }
+
+    /// Finds every document matching `filter`, like [`Model::find`].
+    pub async fn find(&self, filter: impl Into<Document>) -> Result<Vec<M>, OximodError> {
+        crate::instrument!(self.collection.name(), "find", {
+            let cursor = self.collection.find(filter.into()).await.map_err(|e|
+                attach_printables!(
+                    OximodError::ConnectionError(e.to_string()),
+                    "Failed to execute find query. Double-check your filter syntax or collection state."
+                )
+            )?;
+
+            collect_cursor(cursor).await
+        })
+    }
+
+    /// Finds the first document matching `filter`, like [`Model::find_one`].
+    pub async fn find_one(&self, filter: impl Into<Document>) -> Result<Option<M>, OximodError> {
+        crate::instrument!(self.collection.name(), "find_one", {
+            let result = self.collection.find_one(filter.into()).await.map_err(|e|
+                attach_printables!(
+                    OximodError::ConnectionError(e.to_string()),
+                    "Failed to run find_one query. Ensure your filter is structured properly and the collection is accessible."
+                )
+            )?;
+
+            result.map(from_document).transpose()
+        })
+    }
+
+    /// Applies `update` to every matching document, like [`Model::update`].
+    pub async fn update(
+        &self,
+        filter: impl Into<Document>,
+        update: impl Into<Document>
+    ) -> Result<UpdateResult, OximodError> {
+        crate::instrument!(self.collection.name(), "update", {
+            self.collection.update_many(filter.into(), update.into()).await.map_err(|e|
+                attach_printables!(
+                    write_error(e, OximodError::ConnectionError),
+                    "Failed to update documents. Check your update operators and filter structure."
+                )
+            )
+        })
+    }
+
+    /// Applies `update` to the first matching document, like [`Model::update_one`].
+    pub async fn update_one(
+        &self,
+        filter: impl Into<Document>,
+        update: impl Into<Document>
+    ) -> Result<UpdateResult, OximodError> {
+        crate::instrument!(self.collection.name(), "update_one", {
+            self.collection.update_one(filter.into(), update.into()).await.map_err(|e|
+                attach_printables!(
+                    write_error(e, OximodError::ConnectionError),
+                    "Failed to update a document. Make sure your update syntax is valid and the filter matches at least one document."
+                )
+            )
+        })
+    }
+
+    /// Deletes every matching document, like [`Model::delete`].
+    pub async fn delete(&self, filter: impl Into<Document>) -> Result<DeleteResult, OximodError> {
+        crate::instrument!(self.collection.name(), "delete", {
+            let filter = filter.into();
+            M::_hooks().run_pre_delete(&filter).await?;
+
+            let result = self.collection.delete_many(filter.clone()).await.map_err(|e|
+                attach_printables!(
+                    write_error(e, OximodError::ConnectionError),
+                    "Failed to delete documents. Ensure your filter is valid and matches the correct documents."
+                )
+            )?;
+
+            M::_hooks().run_post_delete(&filter).await?;
+            Ok(result)
+        })
+    }
+
+    /// Deletes the first matching document, like [`Model::delete_one`].
+    pub async fn delete_one(&self, filter: impl Into<Document>) -> Result<DeleteResult, OximodError> {
+        crate::instrument!(self.collection.name(), "delete_one", {
+            let filter = filter.into();
+            M::_hooks().run_pre_delete(&filter).await?;
+
+            let result = self.collection.delete_one(filter.clone()).await.map_err(|e|
+                attach_printables!(
+                    write_error(e, OximodError::ConnectionError),
+                    "Failed to delete a document. Check your filter and make sure the document exists."
+                )
+            )?;
+
+            M::_hooks().run_post_delete(&filter).await?;
+            Ok(result)
+        })
+    }
+
+    /// Counts the documents matching `filter`, like [`Model::count`].
+    pub async fn count(&self, filter: impl Into<Document>) -> Result<u64, OximodError> {
+        crate::instrument!(self.collection.name(), "count", {
+            self.collection.count_documents(filter.into()).await.map_err(|e|
+                attach_printables!(
+                    OximodError::ConnectionError(e.to_string()),
+                    "Failed to count documents. Make sure the filter is well-formed and the collection is accessible."
+                )
+            )
+        })
+    }
+}
+
+pub(crate) fn from_document<M: DeserializeOwned>(document: Document) -> Result<M, OximodError> {
+    deserialize::from_document(document).map_err(|e|
+        attach_printables!(
+            e,
+            "Could not deserialize document into model. Check for type mismatches or missing #[serde] attributes."
+        )
+    )
+}
+
+/// Drains `cursor`, deserializing every document into `M`.
+pub(crate) async fn collect_cursor<M: DeserializeOwned>(
+    mut cursor: Cursor<Document>
+) -> Result<Vec<M>, OximodError> {
+    let mut results = Vec::new();
+    while
+        cursor.advance().await.map_err(|e|
+            attach_printables!(
+                OximodError::ConnectionError(e.to_string()),
+                "Cursor failed to retrieve a document. This may indicate a network error mid-stream."
+            )
+        )?
+    {
+        let document = cursor.deserialize_current().map_err(|e|
+            attach_printables!(
+                OximodError::ConnectionError(e.to_string()),
+                "Cursor failed to read the current document."
+            )
+        )?;
+        results.push(from_document(document)?);
+    }
+
+    Ok(results)
+}
@@ -0,0 +1,89 @@
+use std::{ future::Future, pin::Pin, sync::{ Arc, RwLock } };
+use mongodb::bson::Document;
+use crate::error::oximod_error::OximodError;
+
+/// The future returned by a lifecycle hook.
+pub type HookFuture = Pin<Box<dyn Future<Output = Result<(), OximodError>> + Send>>;
+
+/// A hook run around `save`. It receives the serialized document; `pre_save` hooks may
+/// modify it synchronously before returning their future, `post_save` hooks see it with `_id` set.
+pub type SaveHook = Arc<dyn (Fn(&mut Document) -> HookFuture) + Send + Sync>;
+
+/// A hook run around `delete`/`delete_one`. It receives the delete filter.
+pub type DeleteHook = Arc<dyn (Fn(&Document) -> HookFuture) + Send + Sync>;
+
+/// The lifecycle hooks registered for one model, run in registration order.
+///
+/// Each model generated by `#[derive(Model)]` owns one `Hooks` registry.
+#[derive(Default)]
+pub struct Hooks {
+    pre_save: RwLock<Vec<SaveHook>>,
+    post_save: RwLock<Vec<SaveHook>>,
+    pre_delete: RwLock<Vec<DeleteHook>>,
+    post_delete: RwLock<Vec<DeleteHook>>,
+}
+
+impl Hooks {
+    pub fn add_pre_save(&self, hook: SaveHook) {
+        push(&self.pre_save, hook);
+    }
+
+    pub fn add_post_save(&self, hook: SaveHook) {
+        push(&self.post_save, hook);
+    }
+
+    pub fn add_pre_delete(&self, hook: DeleteHook) {
+        push(&self.pre_delete, hook);
+    }
+
+    pub fn add_post_delete(&self, hook: DeleteHook) {
+        push(&self.post_delete, hook);
+    }
+
+    /// Runs the `pre_save` hooks, stopping at the first error.
+    pub async fn run_pre_save(&self, document: &mut Document) -> Result<(), OximodError> {
+        for hook in snapshot(&self.pre_save) {
+            hook(document).await?;
+        }
+        Ok(())
+    }
+
+    /// Runs the `post_save` hooks, stopping at the first error.
+    pub async fn run_post_save(&self, document: &mut Document) -> Result<(), OximodError> {
+        for hook in snapshot(&self.post_save) {
+            hook(document).await?;
+        }
+        Ok(())
+    }
+
+    /// Runs the `pre_delete` hooks, stopping at the first error.
+    pub async fn run_pre_delete(&self, filter: &Document) -> Result<(), OximodError> {
+        for hook in snapshot(&self.pre_delete) {
+            hook(filter).await?;
+        }
+        Ok(())
+    }
+
+    /// Runs the `post_delete` hooks, stopping at the first error.
+    pub async fn run_post_delete(&self, filter: &Document) -> Result<(), OximodError> {
+        for hook in snapshot(&self.post_delete) {
+            hook(filter).await?;
+        }
+        Ok(())
+    }
+}
+
+fn push<H>(hooks: &RwLock<Vec<H>>, hook: H) {
+    hooks
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(hook);
+}
+
+/// Clones the registered hooks so the lock isn't held across `.await`.
+fn snapshot<H: Clone>(hooks: &RwLock<Vec<H>>) -> Vec<H> {
+    hooks
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
@@ -1,15 +1,45 @@
 use async_trait;
 use mongodb::{
-    bson::{ self, oid::ObjectId, Document },
-    results::{ DeleteResult, UpdateResult },
+    bson::{ self, oid::ObjectId, Bson, Document },
+    change_stream::event::ResumeToken,
+    results::{ DeleteResult, SummaryBulkWriteResult, UpdateResult },
+    ClientSession,
     Collection,
     Cursor,
+    IndexModel,
 };
 use crate::error::oximod_error::OximodError;
+use crate::feature::validate::Validate;
+use crate::Printable;
+
+pub mod aggregate;
+pub mod batch;
+pub mod capped;
+pub mod change_stream;
+pub mod clear_guard;
+pub mod deserialize;
+pub mod filter;
+pub mod handle;
+pub mod hooks;
+pub mod page;
+pub mod query;
+pub mod schema;
+pub mod upsert;
+pub mod write_error;
+pub mod write_op;
+use write_op::WriteOp;
+use hooks::{ HookFuture, Hooks };
+use handle::CollectionHandle;
+use query::QueryBuilder;
+use page::Page;
+use upsert::UpsertOutcome;
+use change_stream::ChangeStream;
 
 /// An asynchronous trait for MongoDB models enabling CRUD operations, typically implemented via the #[derive(Model)] macro.
+///
+/// Every model is also [`Validate`], whose rules `save()` checks before inserting.
 #[async_trait::async_trait]
-pub trait Model {
+pub trait Model: Validate {
     /// Retrieves the MongoDB collection associated with the model.
     ///
     /// This method is typically used internally by the framework, but it can be called
@@ -27,6 +57,313 @@ pub trait Model {
     /// println!("Total documents: {}", count);
     /// ```
     fn get_collection() -> Result<Collection<Document>, OximodError>;
+    /// Retrieves the model's declared database, for database-level operations such as
+    /// `list_collection_names` or `run_command` that a collection doesn't offer.
+    ///
+    /// Uses the same client as [`Model::get_collection`], including a named client from
+    /// `#[db("...", client = "...")]`. The model's `read_preference` and `write_concern` apply
+    /// to its collection only, not to this handle.
+    ///
+    /// # Returns
+    /// - [`Database`](https://docs.rs/mongodb/latest/mongodb/struct.Database.html): A handle to the model's database.
+    /// - [`OximodError`](crate::error::oximod_error::OximodError): If the model's client is not initialized.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let db = User::get_database()?;
+    /// let stats = db.run_command(doc! { "dbStats": 1 }).await?;
+    /// println!("Collections: {:?}", db.list_collection_names().await?);
+    /// ```
+    fn get_database() -> Result<mongodb::Database, OximodError>;
+    /// Returns the collection name declared with `#[collection("...")]`.
+    ///
+    /// Use it wherever a pipeline or command has to name the collection, such as `$lookup`,
+    /// `$unionWith`, or `$out`, so the name is never spelled out twice.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// assert_eq!(User::collection_name(), "users");
+    /// ```
+    fn collection_name() -> &'static str;
+    /// Checks whether the model's collection has been created in its database.
+    ///
+    /// Runs `listCollections` filtered to the collection's name. Useful in migration code
+    /// that branches on a first run versus an existing deployment. MongoDB creates
+    /// collections implicitly on the first insert or index build, so the answer can change as
+    /// soon as any model method writes.
+    ///
+    /// # Returns
+    /// - `true` if the collection exists, `false` otherwise.
+    /// - [`OximodError::ConnectionError`](crate::error::oximod_error::OximodError::ConnectionError) if the collections can't be listed.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// if !User::collection_exists().await? {
+    ///     run_initial_migration().await?;
+    /// }
+    /// ```
+    async fn collection_exists() -> Result<bool, OximodError> where Self: Sized {
+        let name = Self::collection_name();
+        let names = Self::get_database()?
+            .list_collection_names()
+            .filter(bson::doc! { "name": name }).await
+            .map_err(|e| {
+                attach_printables!(
+                    OximodError::ConnectionError(e.to_string()),
+                    "Failed to list collections. Check if the mongodb server is reachable and the user may run `listCollections`."
+                )
+            })?;
+
+        Ok(names.iter().any(|existing| existing == name))
+    }
+    /// Starts a chainable query over the model's collection.
+    ///
+    /// A fluent façade over `find`, `count`, `update`, and `delete` that lets a filtered
+    /// subset be built once and reused. Filters added with `.filter(...)` are merged with `$and`.
+    ///
+    /// # Returns
+    /// - An empty [`QueryBuilder`] matching every document.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let active = User::query().filter(doc! { "active": true });
+    /// let newest = active.clone().sort(doc! { "_id": -1 }).limit(10).all().await?;
+    /// let adults = active.clone().filter(doc! { "age": { "$gte": 18 } }).count().await?;
+    /// active.update(doc! { "$set": { "notified": true } }).await?;
+    /// ```
+    fn query() -> QueryBuilder<Self> where Self: Sized {
+        QueryBuilder::default()
+    }
+    /// Retrieves a collection with the given name in the model's database, using the model's
+    /// client and collection options (read preference, write concern).
+    ///
+    /// Useful for multi-tenant setups where each tenant's data lives in its own collection.
+    /// [`Model::with_collection`] wraps the result in a handle with the usual CRUD methods.
+    ///
+    /// # Parameters
+    /// - `name`: The collection name to use instead of the declared `#[collection("...")]`.
+    ///
+    /// # Returns
+    /// - [`Collection<Document>`](https://docs.rs/mongodb/latest/mongodb/struct.Collection.html): A handle to the named collection.
+    /// - [`OximodError`](crate::error::oximod_error::OximodError): If the model's client is not initialized.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let collection = User::get_collection_named(&format!("users_{}", tenant_id))?;
+    /// ```
+    fn get_collection_named(name: &str) -> Result<Collection<Document>, OximodError>;
+    /// Binds the model to a collection chosen at runtime.
+    ///
+    /// The returned [`CollectionHandle`] offers `save`, `find`, `find_one`, `update`,
+    /// `update_one`, `delete`, `delete_one`, and `count`, running the same validation and hooks
+    /// as the model's own methods. The declared collection keeps working unchanged.
+    ///
+    /// # Parameters
+    /// - `name`: The collection name to use instead of the declared `#[collection("...")]`.
+    ///
+    /// # Returns
+    /// - A [`CollectionHandle`] for the named collection.
+    /// - [`OximodError`](crate::error::oximod_error::OximodError): If the model's client is not initialized.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let users = User::with_collection(&format!("users_{}", tenant_id))?;
+    /// users.save(&User::new().name("Alice".to_string())).await?;
+    /// let active = users.find(doc! { "active": true }).await?;
+    /// ```
+    fn with_collection(name: &str) -> Result<CollectionHandle<Self>, OximodError>
+        where Self: Sized + serde::Serialize + serde::de::DeserializeOwned + Send + Sync
+    {
+        Ok(CollectionHandle::new(Self::get_collection_named(name)?))
+    }
+    /// Retrieves the model's declared collection in another database on the same client.
+    ///
+    /// Useful for routing a model to per-region or per-customer databases at runtime.
+    /// [`Model::using_db`] wraps the result in a handle with the usual CRUD methods.
+    ///
+    /// # Parameters
+    /// - `db`: The database name to use instead of the declared `#[db("...")]`.
+    ///
+    /// # Returns
+    /// - [`Collection<Document>`](https://docs.rs/mongodb/latest/mongodb/struct.Collection.html): The declared collection in `db`.
+    /// - [`OximodError`](crate::error::oximod_error::OximodError): If the model's client is not initialized.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let collection = User::get_collection_in_db("app_eu")?;
+    /// ```
+    fn get_collection_in_db(db: &str) -> Result<Collection<Document>, OximodError>;
+    /// Binds the model to its declared collection in a database chosen at runtime.
+    ///
+    /// Returns the same [`CollectionHandle`] as [`Model::with_collection`].
+    ///
+    /// # Parameters
+    /// - `db`: The database name to use instead of the declared `#[db("...")]`.
+    ///
+    /// # Returns
+    /// - A [`CollectionHandle`] for the declared collection in `db`.
+    /// - [`OximodError`](crate::error::oximod_error::OximodError): If the model's client is not initialized.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let users = User::using_db(&format!("app_{}", region))?;
+    /// let user = users.find_one(doc! { "email": email }).await?;
+    /// ```
+    fn using_db(db: &str) -> Result<CollectionHandle<Self>, OximodError>
+        where Self: Sized + serde::Serialize + serde::de::DeserializeOwned + Send + Sync
+    {
+        Ok(CollectionHandle::new(Self::get_collection_in_db(db)?))
+    }
+    #[doc(hidden)]
+    /// The hooks registered with `pre_save`, `post_save`, `pre_delete`, and `post_delete`.
+    fn _hooks() -> &'static Hooks;
+    #[doc(hidden)]
+    /// Serializes the model into the document `save()` inserts.
+    fn _to_document(&self) -> Result<Document, OximodError>;
+    #[doc(hidden)]
+    /// Creates the model's declared indexes on `collection`.
+    async fn _create_indexes(collection: &Collection<Document>) -> Result<(), OximodError>;
+    /// Creates every index declared on the model (`#[index]`, `#[compound_index]`, `#[text_index]`).
+    ///
+    /// Call this once at application startup, especially for models marked with
+    /// `#[model(auto_create_indexes = false)]`, whose `save()` no longer creates indexes.
+    /// Creating an index that already exists with the same specification is a no-op.
+    ///
+    /// For models marked `#[model(json_schema = true)]`, this also installs
+    /// [`Model::json_schema`] as the collection's server-side validator.
+    ///
+    /// # Returns
+    /// - `Ok(())` once all indexes exist.
+    /// - [`OximodError::IndexError`](crate::error::oximod_error::OximodError::IndexError) if an index could not be created.
+    /// - [`OximodError::CommandError`](crate::error::oximod_error::OximodError::CommandError) if the validator could not be installed.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// User::sync_indexes().await?;
+    /// ```
+    async fn sync_indexes() -> Result<(), OximodError>;
+    /// Returns the indexes declared on the model, exactly as [`Model::sync_indexes`] would create them.
+    ///
+    /// Nothing is sent to MongoDB, so index declarations can be checked in unit tests or
+    /// compared against [`Model::list_indexes`] to find drift. The default `_id_` index is not included.
+    ///
+    /// # Returns
+    /// - One [`IndexModel`](https://docs.rs/mongodb/latest/mongodb/struct.IndexModel.html) per `#[index]`, `#[compound_index]`, and `#[text_index]`.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let indexes = User::declared_indexes();
+    /// assert_eq!(indexes[0].keys, doc! { "email": 1 });
+    /// ```
+    fn declared_indexes() -> Vec<IndexModel>;
+    /// Returns the `$jsonSchema` generated for a model marked `#[model(json_schema = true)]`,
+    /// or `None` for other models.
+    ///
+    /// The schema is built from the field types and the `#[validate]` rules that have a JSON
+    /// Schema equivalent, such as `min_length` (`minLength`), `min` (`minimum`), and
+    /// `required`. [`Model::sync_indexes`] installs it, so writes that bypass oximod are
+    /// checked too.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let schema = User::json_schema().expect("json_schema = true");
+    /// assert_eq!(schema.get_document("properties")?.get_document("name")?, &doc! { "bsonType": "string", "minLength": 3 });
+    /// ```
+    fn json_schema() -> Option<Document>;
+    /// Lists the indexes that currently exist on the model's collection.
+    ///
+    /// This reflects the database, not the declared attributes, which makes it useful for
+    /// migration scripts that reconcile declared indexes with what is actually deployed.
+    ///
+    /// # Returns
+    /// - A `Vec` of [`IndexModel`](https://docs.rs/mongodb/latest/mongodb/struct.IndexModel.html), including the default `_id_` index.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// for index in User::list_indexes().await? {
+    ///     println!("{:?}", index.keys);
+    /// }
+    /// ```
+    async fn list_indexes() -> Result<Vec<IndexModel>, OximodError>;
+    /// Drops the index with the given name from the model's collection.
+    ///
+    /// # Parameters
+    /// - `name`: The index name, e.g. `"email_idx"` or a generated name like `"age_-1"`.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// User::drop_index("email_idx").await?;
+    /// ```
+    async fn drop_index(name: &str) -> Result<(), OximodError>;
+    /// Drops every index on the model's collection except the mandatory `_id_` index.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// User::drop_all_indexes().await?;
+    /// User::sync_indexes().await?; // recreate the declared ones
+    /// ```
+    async fn drop_all_indexes() -> Result<(), OximodError>;
+    /// Registers a hook that runs before every [`Model::save`] and [`Model::save_with_session`],
+    /// after validation.
+    ///
+    /// The hook receives the serialized document and may modify it synchronously, e.g. to hash
+    /// a password, before returning its future. Hooks run in registration order and an `Err`
+    /// aborts the save before anything is written. Inside
+    /// [`with_transaction`](crate::feature::conn::transaction::with_transaction) the callback,
+    /// and so its hooks, may be retried.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// User::pre_save(|doc| {
+    ///     let hashed = hash(doc.get_str("password").unwrap_or_default());
+    ///     doc.insert("password", hashed);
+    ///     Box::pin(async { Ok(()) })
+    /// });
+    /// ```
+    fn pre_save<F>(hook: F) where F: Fn(&mut bson::Document) -> HookFuture + Send + Sync + 'static;
+    /// Registers a hook that runs after every successful [`Model::save`] and
+    /// [`Model::save_with_session`].
+    ///
+    /// The hook receives the inserted document with its `_id` set. An `Err` is returned from
+    /// `save`, but the document has already been written. Within a transaction the hook runs
+    /// before the commit, so the write may still be rolled back.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// User::post_save(|doc| {
+    ///     let id = doc.get_object_id("_id").ok();
+    ///     Box::pin(async move { audit_log("user.created", id).await })
+    /// });
+    /// ```
+    fn post_save<F>(hook: F) where F: Fn(&mut bson::Document) -> HookFuture + Send + Sync + 'static;
+    /// Registers a hook that runs before every [`Model::delete`], [`Model::delete_one`], and
+    /// [`Model::delete_one_with_session`] (and therefore the `delete_by_id` variants).
+    ///
+    /// The hook receives the delete filter. Hooks run in registration order and an `Err`
+    /// aborts the delete before anything is removed.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// User::pre_delete(|filter| {
+    ///     let filter = filter.clone();
+    ///     Box::pin(async move { ensure_not_protected(filter).await })
+    /// });
+    /// ```
+    fn pre_delete<F>(hook: F) where F: Fn(&bson::Document) -> HookFuture + Send + Sync + 'static;
+    /// Registers a hook that runs after every successful [`Model::delete`], [`Model::delete_one`],
+    /// and [`Model::delete_one_with_session`].
+    ///
+    /// The hook receives the delete filter. Within a transaction the hook runs before the commit,
+    /// so the delete may still be rolled back.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// User::post_delete(|filter| {
+    ///     let filter = filter.clone();
+    ///     Box::pin(async move { audit_log("user.deleted", filter).await })
+    /// });
+    /// ```
+    fn post_delete<F>(hook: F) where F: Fn(&bson::Document) -> HookFuture + Send + Sync + 'static;
     /// Inserts the current model instance into the MongoDB collection.
     ///
     /// # Returns
@@ -38,8 +375,94 @@ pub trait Model {
     /// println!("Inserted user ID: {}", id);
     /// ```
     async fn save(&self) -> Result<ObjectId, OximodError>;
+    /// Inserts the model only if no document matches `filter`, in a single atomic upsert.
+    ///
+    /// Sends `update_one(filter, { "$setOnInsert": <model> })` with `upsert: true`, so there is
+    /// no window between checking and inserting for another writer to slip into. Validation
+    /// and `pre_save` hooks run first, as in [`Model::save`]; `post_save` hooks run only when
+    /// a document was inserted. Equality conditions in `filter` are copied into the new
+    /// document, so they must agree with the model's own values.
+    ///
+    /// # Parameters
+    /// - `filter`: A BSON document identifying the existing document, e.g. `{ "email": ... }`.
+    ///
+    /// # Returns
+    /// - `true` if the model was inserted, `false` if a matching document already existed.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let created = user.save_if_not_exists(doc! { "email": &user.email }).await?;
+    /// if !created {
+    ///     println!("Already registered");
+    /// }
+    /// ```
+    async fn save_if_not_exists(
+        &self,
+        filter: impl Into<bson::Document> + Send
+    ) -> Result<bool, OximodError>;
+    /// Returns the document matching `filter`, inserting `default` first if there is none.
+    ///
+    /// Sends `findOneAndUpdate(filter, { "$setOnInsert": <default> })` with `upsert: true` and
+    /// `ReturnDocument::After`, so the lookup and the insert are one atomic operation. `default`
+    /// is validated and passed through the `pre_save` hooks first, even if it ends up unused;
+    /// `post_save` hooks run only when it was inserted. Equality conditions in `filter` are
+    /// copied into the new document, so they must agree with `default`'s own values.
+    ///
+    /// Two concurrent upserts can both insert unless the filtered fields have a unique index.
+    /// With one, e.g. `#[index(unique)]`, the server retries the losing upsert as an update, so
+    /// every caller gets the same document.
+    ///
+    /// # Parameters
+    /// - `filter`: A BSON document identifying the existing document, e.g. `{ "key": "site" }`.
+    /// - `default`: The model to insert when nothing matches.
+    ///
+    /// # Returns
+    /// - The existing document, or `default` as stored, including its new `_id`.
+    /// - [`OximodError::ValidationError`](crate::error::oximod_error::OximodError::ValidationError) if `default` breaks a rule; nothing is read or written.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let settings = Settings::find_one_or_create(
+    ///     doc! { "key": "site" },
+    ///     Settings::new().key("site".to_string()).theme("light".to_string())
+    /// ).await?;
+    /// ```
+    async fn find_one_or_create(
+        filter: impl Into<bson::Document> + Send,
+        default: Self
+    ) -> Result<Self, OximodError>
+        where Self: Sized;
+    /// Saves every model it can, collecting the ones that fail instead of stopping at the first.
+    ///
+    /// Each model is validated, serialized, and passed through the `pre_save` hooks, as in
+    /// [`Model::save`]. The ones that pass are written with one unordered `insert_many`, so a
+    /// duplicate key in one row doesn't stop the others. Use it for imports, where the good
+    /// rows should land and the bad ones be reported. For all-or-nothing inserts, call
+    /// [`Model::save_with_session`] inside
+    /// [`with_transaction`](crate::feature::conn::transaction::with_transaction) instead.
+    ///
+    /// # Parameters
+    /// - `models`: The models to insert. Indexes in the result refer to positions in this vector.
+    ///
+    /// # Returns
+    /// - [`BatchResult`](batch::BatchResult): The ids of the stored documents and the index and error of each failed one.
+    /// - [`OximodError`](crate::error::oximod_error::OximodError): Only if nothing could be attempted, e.g. the client is missing or index creation failed.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let result = User::save_many_lenient(rows).await?;
+    /// for (index, error) in &result.failures {
+    ///     eprintln!("row {}: {}", index, error);
+    /// }
+    /// println!("imported {}", result.inserted.len());
+    /// ```
+    async fn save_many_lenient(models: Vec<Self>) -> Result<batch::BatchResult, OximodError>
+        where Self: Sized;
     /// Updates all documents in the collection that match the given filter.
     ///
+    /// Update operators are sent as-is, so `#[validate(...)]` rules are **not** checked.
+    /// Use [`Model::replace_one`] to write a validated model instead.
+    ///
     /// # Parameters
     /// - `filter`: A BSON document specifying which documents to match.
     /// - `update`: A BSON document with the update operations to apply.
@@ -56,8 +479,80 @@ pub trait Model {
         filter: impl Into<bson::Document> + Send,
         update: impl Into<bson::Document> + Send
     ) -> Result<UpdateResult, OximodError>;
+    /// Updates all documents matching the filter and returns the ids of the documents it targeted.
+    ///
+    /// First collects the ids of the matching documents with a find projected to `_id`, then
+    /// runs [`Model::update`] restricted to those ids, so a document that starts matching in
+    /// between is neither updated nor reported. A document that stops matching in between is
+    /// still reported although the update skips it. Ids of documents the update matched but
+    /// left unchanged, because they already held the new values, are returned too.
+    ///
+    /// # Parameters
+    /// - `filter`: A BSON document specifying which documents to match.
+    /// - `update`: A BSON document with the update operations to apply.
+    ///
+    /// # Returns
+    /// - The `_id` of every targeted document, or an empty `Vec` (without updating) if none matched.
+    /// - [`OximodError::SerializationError`](crate::error::oximod_error::OximodError::SerializationError) if a matching document has a non-`ObjectId` `_id`; nothing is updated.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let ids = Order::update_returning_ids(doc! { "status": "paid" }, doc! { "$set": { "status": "shipped" } }).await?;
+    /// for id in ids {
+    ///     enqueue_shipping_email(id).await?;
+    /// }
+    /// ```
+    async fn update_returning_ids(
+        filter: impl Into<bson::Document> + Send,
+        update: impl Into<bson::Document> + Send
+    ) -> Result<Vec<ObjectId>, OximodError>
+        where Self: Sized
+    {
+        let filter = filter.into();
+        let mut cursor = Self::get_collection()?
+            .find(filter.clone())
+            .projection(bson::doc! { "_id": 1 }).await
+            .map_err(|e|
+                attach_printables!(
+                    OximodError::ConnectionError(e.to_string()),
+                    "Failed to execute find query. Double-check your filter syntax or collection state."
+                )
+            )?;
+
+        let mut ids = Vec::new();
+        while
+            cursor.advance().await.map_err(|e|
+                attach_printables!(
+                    OximodError::ConnectionError(e.to_string()),
+                    "Cursor failed to retrieve a document. This may indicate a network error mid-stream."
+                )
+            )?
+        {
+            match cursor.current().get("_id") {
+                Ok(Some(bson::RawBsonRef::ObjectId(id))) => ids.push(id),
+                _ => {
+                    return Err(
+                        attach_printables!(
+                            OximodError::SerializationError("_id is not an ObjectId".to_string()),
+                            "A matching document has a custom _id, which update_returning_ids can't report. Use update together with a find instead."
+                        )
+                    );
+                }
+            }
+        }
+
+        if !ids.is_empty() {
+            Self::update(
+                bson::doc! { "$and": [filter, { "_id": { "$in": ids.clone() } }] },
+                update
+            ).await?;
+        }
+        Ok(ids)
+    }
     /// Updates the **first document** in the collection that matches the given filter.
     ///
+    /// Like [`Model::update`], this does not run `#[validate(...)]` rules; see [`Model::replace_one`].
+    ///
     /// # Parameters
     /// - `filter`: A BSON document to find a single matching document.
     /// - `update`: The update operations to apply (e.g., `$set`, `$unset`, etc.).
@@ -74,6 +569,76 @@ pub trait Model {
         filter: impl Into<bson::Document> + Send,
         update: impl Into<bson::Document> + Send
     ) -> Result<UpdateResult, OximodError>;
+    /// Applies `update` to the first matching document, or inserts one if nothing matches.
+    ///
+    /// Sends `update_one` with `upsert: true`. On insert, equality conditions in `filter` and
+    /// the update's fields (including `$setOnInsert`) make up the new document. Like
+    /// [`Model::update`], this does not run `#[validate(...)]` rules.
+    ///
+    /// # Parameters
+    /// - `filter`: A BSON document to find a single matching document.
+    /// - `update`: The update operations to apply (e.g., `$set`, `$inc`, `$setOnInsert`).
+    ///
+    /// # Returns
+    /// - An [`UpsertOutcome`] whose `upserted_id` is the new document's `ObjectId`, if one was inserted.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let outcome = Counter::upsert_one(doc! { "name": "visits" }, doc! { "$inc": { "value": 1 } }).await?;
+    /// if let Some(id) = outcome.upserted_id {
+    ///     println!("Created counter {}", id);
+    /// }
+    /// ```
+    async fn upsert_one(
+        filter: impl Into<bson::Document> + Send,
+        update: impl Into<bson::Document> + Send
+    ) -> Result<UpsertOutcome, OximodError>;
+    /// Updates the first document matching the filter and returns it as it is **after** the update.
+    ///
+    /// Uses `findOneAndUpdate` with `ReturnDocument::After`, so the update and the read are a
+    /// single atomic operation. Like [`Model::update_one`], `#[validate(...)]` rules are not checked.
+    ///
+    /// # Parameters
+    /// - `filter`: A BSON document to find a single matching document.
+    /// - `update`: The update operations to apply (e.g., `$set`, `$inc`, etc.).
+    ///
+    /// # Returns
+    /// - `Some(Self)` with the updated document, or `None` if the filter matched nothing.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let counter = Counter::update_one_returning(doc! { "name": "visits" }, doc! { "$inc": { "value": 1 } }).await?;
+    /// ```
+    async fn update_one_returning(
+        filter: impl Into<bson::Document> + Send,
+        update: impl Into<bson::Document> + Send
+    ) -> Result<Option<Self>, OximodError>
+        where Self: Sized;
+    /// Replaces the first document matching the filter with a validated model.
+    ///
+    /// Runs `validate()` on `replacement` before writing, making it the safe way to update
+    /// documents whose rules must hold (e.g. keeping malformed emails out). The replacement's
+    /// `_id` is ignored, so the matched document keeps its own.
+    ///
+    /// # Parameters
+    /// - `filter`: A BSON document to find a single matching document.
+    /// - `replacement`: The model to store in place of the matched document.
+    ///
+    /// # Returns
+    /// - [`UpdateResult`](https://docs.rs/mongodb/latest/mongodb/results/struct.UpdateResult.html) with `matched_count` and `modified_count`.
+    /// - [`OximodError::ValidationError`](crate::error::oximod_error::OximodError::ValidationError) if `replacement` breaks a rule; nothing is written.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let mut user = User::find_by_id(id).await?.unwrap();
+    /// user.email = Some("new@example.com".to_string());
+    /// User::replace_one(doc! { "_id": id }, &user).await?;
+    /// ```
+    async fn replace_one(
+        filter: impl Into<bson::Document> + Send,
+        replacement: &Self
+    ) -> Result<UpdateResult, OximodError>
+        where Self: Sized + Sync;
     /// Deletes all documents in the collection that match the given filter.
     ///
     /// # Parameters
@@ -119,6 +684,138 @@ pub trait Model {
     /// ```
     async fn find(filter: impl Into<bson::Document> + Send) -> Result<Vec<Self>, OximodError>
         where Self: Sized;
+    /// Streams every document matching `filter` to `f`, `batch_size` documents at a time.
+    ///
+    /// Unlike [`Model::find`], at most one batch is held in memory, which suits migrations over
+    /// large collections. The cursor fetches `batch_size` documents per round trip and each
+    /// batch is passed to `f` once filled; the final batch may be smaller. A `batch_size` of
+    /// `0` is treated as `1`.
+    ///
+    /// Iteration stops at the first error, whether from the cursor, deserialization, or `f`,
+    /// and that error is returned. Batches already processed are not rolled back.
+    ///
+    /// # Parameters
+    /// - `filter`: A BSON query document used to match documents.
+    /// - `batch_size`: The number of documents per batch.
+    /// - `f`: Called with each batch; return `Err` to stop early.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// User::for_each_batch(doc! {}, 500, |users| async move {
+    ///     for user in users {
+    ///         migrate(user).await?;
+    ///     }
+    ///     Ok(())
+    /// }).await?;
+    /// ```
+    async fn for_each_batch<F, Fut>(
+        filter: impl Into<bson::Document> + Send,
+        batch_size: u32,
+        mut f: F
+    ) -> Result<(), OximodError>
+        where
+            Self: Sized + serde::de::DeserializeOwned + Send,
+            F: FnMut(Vec<Self>) -> Fut + Send,
+            Fut: std::future::Future<Output = Result<(), OximodError>> + Send
+    {
+        let batch_size = batch_size.max(1);
+        let mut cursor = Self::get_collection()?
+            .find(filter.into())
+            .batch_size(batch_size).await
+            .map_err(|e|
+                attach_printables!(
+                    OximodError::ConnectionError(e.to_string()),
+                    "Failed to execute find query. Double-check your filter syntax or collection state."
+                )
+            )?;
+
+        let mut batch = Vec::with_capacity(batch_size as usize);
+        while
+            cursor.advance().await.map_err(|e|
+                attach_printables!(
+                    OximodError::ConnectionError(e.to_string()),
+                    "Cursor failed to retrieve a document. This may indicate a network error mid-stream."
+                )
+            )?
+        {
+            let document = cursor.deserialize_current().map_err(|e|
+                attach_printables!(
+                    OximodError::ConnectionError(e.to_string()),
+                    "Cursor failed to read the current document."
+                )
+            )?;
+            batch.push(handle::from_document(document)?);
+
+            if batch.len() == (batch_size as usize) {
+                f(std::mem::replace(&mut batch, Vec::with_capacity(batch_size as usize))).await?;
+            }
+        }
+
+        if !batch.is_empty() {
+            f(batch).await?;
+        }
+
+        Ok(())
+    }
+    /// Finds all documents matching the filter, skipping the ones that don't deserialize.
+    ///
+    /// Unlike [`Model::find`], a document that doesn't match the model is recorded instead of
+    /// failing the whole query, so old and new document shapes can be read side by side during a
+    /// schema migration. Network and cursor errors still return `Err`.
+    ///
+    /// # Parameters
+    /// - `filter`: A BSON query document used to match documents.
+    ///
+    /// # Returns
+    /// - The models that deserialized, in cursor order, and a
+    ///   [`DeserializeError`](deserialize::DeserializeError) with the `_id` of each one that didn't.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let (users, skipped) = User::find_lenient(doc! {}).await?;
+    /// for skip in &skipped {
+    ///     eprintln!("skipped {:?}: {}", skip.id, skip.error);
+    /// }
+    /// ```
+    async fn find_lenient(
+        filter: impl Into<bson::Document> + Send
+    ) -> Result<(Vec<Self>, Vec<deserialize::DeserializeError>), OximodError>
+        where Self: Sized + serde::de::DeserializeOwned + Send
+    {
+        let mut cursor = Self::get_collection()?
+            .find(filter.into()).await
+            .map_err(|e|
+                attach_printables!(
+                    OximodError::ConnectionError(e.to_string()),
+                    "Failed to execute find query. Double-check your filter syntax or collection state."
+                )
+            )?;
+
+        let mut models = Vec::new();
+        let mut skipped = Vec::new();
+        while
+            cursor.advance().await.map_err(|e|
+                attach_printables!(
+                    OximodError::ConnectionError(e.to_string()),
+                    "Cursor failed to retrieve a document. This may indicate a network error mid-stream."
+                )
+            )?
+        {
+            let document = cursor.deserialize_current().map_err(|e|
+                attach_printables!(
+                    OximodError::ConnectionError(e.to_string()),
+                    "Cursor failed to read the current document."
+                )
+            )?;
+            let id = document.get("_id").cloned();
+            match deserialize::from_document(document) {
+                Ok(model) => models.push(model),
+                Err(error) => skipped.push(deserialize::DeserializeError { id, error }),
+            }
+        }
+
+        Ok((models, skipped))
+    }
     /// Finds the **first document** in the collection that matches the given filter.
     ///
     /// # Parameters
@@ -135,28 +832,66 @@ pub trait Model {
     /// ```
     async fn find_one(filter: impl Into<bson::Document> + Send) -> Result<Option<Self>, OximodError>
         where Self: Sized;
-    /// Finds a document in the collection by its MongoDB `_id` field.
+    /// Finds the matching document with the smallest `_id`, i.e. the **oldest** one.
+    ///
+    /// `ObjectId`s start with their creation timestamp, so sorting by `_id` approximates
+    /// insertion order. With custom `_id`s (strings, numbers, UUIDs) the order follows those
+    /// values instead and says nothing about when a document was created.
     ///
     /// # Parameters
-    /// - `id`: The [`ObjectId`](https://docs.rs/mongodb/latest/mongodb/bson/oid/struct.ObjectId.html) of the document.
+    /// - `filter`: A BSON document restricting which documents are considered.
     ///
     /// # Returns
-    /// - `Some(Self)` if found, or `None` if no document matches the ID.
+    /// - `Some(Self)` for the first document in `_id` order, or `None` if nothing matches.
     ///
     /// # Example
     /// ```rust, no_run
-    /// let id = ObjectId::parse_str("652efcddfc13ae2c82000001")?;
-    /// let user = User::find_by_id(id).await?;
-    /// if let Some(u) = user {
-    ///     println!("Found: {}", u.name);
-    /// }
+    /// let oldest = User::first(doc! { "active": true }).await?;
     /// ```
-    async fn find_by_id(id: ObjectId) -> Result<Option<Self>, OximodError> where Self: Sized;
-    /// Updates a document by its MongoDB `_id` field.
+    async fn first(filter: impl Into<bson::Document> + Send) -> Result<Option<Self>, OximodError>
+        where Self: Sized;
+    /// Finds the matching document with the largest `_id`, i.e. the **newest** one.
+    ///
+    /// Sorts by `_id` descending, which only approximates creation order for `ObjectId`s:
+    /// ids generated within the same second on different machines may interleave, and
+    /// custom `_id`s are not time-based at all. See [`Model::first`].
     ///
     /// # Parameters
-    /// - `id`: The [`ObjectId`](https://docs.rs/mongodb/latest/mongodb/bson/oid/struct.ObjectId.html) of the document to update.
-    /// - `update`: A BSON document containing update operations (e.g., `$set`).
+    /// - `filter`: A BSON document restricting which documents are considered.
+    ///
+    /// # Returns
+    /// - `Some(Self)` for the last document in `_id` order, or `None` if nothing matches.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// if let Some(post) = Post::last(doc! { "author": "alice" }).await? {
+    ///     println!("Latest post: {}", post.title);
+    /// }
+    /// ```
+    async fn last(filter: impl Into<bson::Document> + Send) -> Result<Option<Self>, OximodError>
+        where Self: Sized;
+    /// Finds a document in the collection by its MongoDB `_id` field.
+    ///
+    /// # Parameters
+    /// - `id`: The [`ObjectId`](https://docs.rs/mongodb/latest/mongodb/bson/oid/struct.ObjectId.html) of the document.
+    ///
+    /// # Returns
+    /// - `Some(Self)` if found, or `None` if no document matches the ID.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let id = ObjectId::parse_str("652efcddfc13ae2c82000001")?;
+    /// let user = User::find_by_id(id).await?;
+    /// if let Some(u) = user {
+    ///     println!("Found: {}", u.name);
+    /// }
+    /// ```
+    async fn find_by_id(id: ObjectId) -> Result<Option<Self>, OximodError> where Self: Sized;
+    /// Updates a document by its MongoDB `_id` field.
+    ///
+    /// # Parameters
+    /// - `id`: The [`ObjectId`](https://docs.rs/mongodb/latest/mongodb/bson/oid/struct.ObjectId.html) of the document to update.
+    /// - `update`: A BSON document containing update operations (e.g., `$set`).
     ///
     /// # Returns
     /// - [`UpdateResult`](https://docs.rs/mongodb/latest/mongodb/results/struct.UpdateResult.html) with details on the matched and modified document.
@@ -186,6 +921,86 @@ pub trait Model {
     /// assert_eq!(result.deleted_count, 1);
     /// ```
     async fn delete_by_id(id: ObjectId) -> Result<DeleteResult, OximodError>;
+    /// Finds a document by an `_id` given as a hex string, e.g. a URL path segment.
+    ///
+    /// # Parameters
+    /// - `id`: The 24-character hex representation of the document's `ObjectId`.
+    ///
+    /// # Returns
+    /// - `Some(Self)` if found, or `None` if no document has that id.
+    /// - [`OximodError::InvalidId`](crate::error::oximod_error::OximodError::InvalidId) if `id` is not a valid `ObjectId`.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let user = User::find_by_id_str("652efcddfc13ae2c82000001").await?;
+    /// ```
+    async fn find_by_id_str(id: &str) -> Result<Option<Self>, OximodError> where Self: Sized;
+    /// Updates a document by an `_id` given as a hex string. See [`Model::update_by_id`].
+    ///
+    /// # Parameters
+    /// - `id`: The 24-character hex representation of the document's `ObjectId`.
+    /// - `update`: A BSON document with the update operations to apply.
+    ///
+    /// # Returns
+    /// - [`UpdateResult`](https://docs.rs/mongodb/latest/mongodb/results/struct.UpdateResult.html) with `matched_count` and `modified_count`.
+    /// - [`OximodError::InvalidId`](crate::error::oximod_error::OximodError::InvalidId) if `id` is not a valid `ObjectId`.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// User::update_by_id_str(&path_id, doc! { "$set": { "active": false } }).await?;
+    /// ```
+    async fn update_by_id_str(
+        id: &str,
+        update: impl Into<bson::Document> + Send
+    ) -> Result<UpdateResult, OximodError>;
+    /// Deletes a document by an `_id` given as a hex string. See [`Model::delete_by_id`].
+    ///
+    /// # Parameters
+    /// - `id`: The 24-character hex representation of the document's `ObjectId`.
+    ///
+    /// # Returns
+    /// - [`DeleteResult`](https://docs.rs/mongodb/latest/mongodb/results/struct.DeleteResult.html) with the deletion outcome.
+    /// - [`OximodError::InvalidId`](crate::error::oximod_error::OximodError::InvalidId) if `id` is not a valid `ObjectId`.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// User::delete_by_id_str(&path_id).await?;
+    /// ```
+    async fn delete_by_id_str(id: &str) -> Result<DeleteResult, OximodError>;
+    /// Updates every document whose `_id` is in `ids`, with a single `$in` query.
+    ///
+    /// Like [`Model::update`], this does not run `#[validate(...)]` rules.
+    ///
+    /// # Parameters
+    /// - `ids`: The [`ObjectId`](https://docs.rs/mongodb/latest/mongodb/bson/oid/struct.ObjectId.html)s of the documents to update.
+    /// - `update`: A BSON document with the update operations to apply.
+    ///
+    /// # Returns
+    /// - [`UpdateResult`](https://docs.rs/mongodb/latest/mongodb/results/struct.UpdateResult.html) containing matched and modified counts.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let result = User::update_by_ids(selected_ids, doc! { "$set": { "active": false } }).await?;
+    /// println!("Deactivated {} users", result.modified_count);
+    /// ```
+    async fn update_by_ids(
+        ids: Vec<ObjectId>,
+        update: impl Into<bson::Document> + Send
+    ) -> Result<UpdateResult, OximodError>;
+    /// Deletes every document whose `_id` is in `ids`, with a single `$in` query.
+    ///
+    /// # Parameters
+    /// - `ids`: The [`ObjectId`](https://docs.rs/mongodb/latest/mongodb/bson/oid/struct.ObjectId.html)s of the documents to delete.
+    ///
+    /// # Returns
+    /// - [`DeleteResult`](https://docs.rs/mongodb/latest/mongodb/results/struct.DeleteResult.html) with the number of deleted documents.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let result = User::delete_by_ids(selected_ids).await?;
+    /// println!("Deleted {} users", result.deleted_count);
+    /// ```
+    async fn delete_by_ids(ids: Vec<ObjectId>) -> Result<DeleteResult, OximodError>;
     /// Counts the number of documents in the collection that match the given filter.
     ///
     /// # Parameters
@@ -200,8 +1015,32 @@ pub trait Model {
     /// println!("Active users: {}", count);
     /// ```
     async fn count(filter: impl Into<bson::Document> + Send) -> Result<u64, OximodError>;
+    /// Counts the documents matching `filter` with driver options such as `limit`, `skip`,
+    /// `hint`, or `collation`.
+    ///
+    /// [`Model::count`] is this with default options.
+    ///
+    /// # Parameters
+    /// - `filter`: A BSON document to filter the documents to be counted.
+    /// - `options`: [`CountOptions`](https://docs.rs/mongodb/latest/mongodb/options/struct.CountOptions.html) forwarded to `count_documents`.
+    ///
+    /// # Returns
+    /// - The number of matching documents, capped at `limit` if one is set.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let options = CountOptions::builder().limit(101).hint(Hint::Name("status_1".to_string())).build();
+    /// let more_than_100 = Order::count_with_options(doc! { "status": "open" }, options).await? > 100;
+    /// ```
+    async fn count_with_options(
+        filter: impl Into<bson::Document> + Send,
+        options: mongodb::options::CountOptions
+    ) -> Result<u64, OximodError>;
     /// Checks if any document in the collection matches the given filter.
     ///
+    /// Runs `count_documents` with a limit of 1, so no document is fetched or deserialized;
+    /// a stored document that no longer matches the model's shape still counts as existing.
+    ///
     /// # Parameters
     /// - `filter`: A BSON document to match against.
     ///
@@ -216,9 +1055,84 @@ pub trait Model {
     /// }
     /// ```
     async fn exists(filter: impl Into<bson::Document> + Send) -> Result<bool, OximodError>;
+    /// Checks if any document matches the given filter; an alias of [`Model::exists`] that
+    /// reads naturally next to [`Model::none`] in guard clauses.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// if User::any(doc! { "email": &email }).await? {
+    ///     return Err(AppError::EmailTaken);
+    /// }
+    /// ```
+    async fn any(filter: impl Into<bson::Document> + Send) -> Result<bool, OximodError>
+        where Self: Sized
+    {
+        Self::exists(filter).await
+    }
+    /// Checks that no document matches the given filter, the negation of [`Model::exists`].
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// if Invoice::none(doc! { "customer_id": id, "paid": false }).await? {
+    ///     Customer::delete_by_id(id).await?;
+    /// }
+    /// ```
+    async fn none(filter: impl Into<bson::Document> + Send) -> Result<bool, OximodError>
+        where Self: Sized
+    {
+        Ok(!Self::exists(filter).await?)
+    }
+    /// Checks that every document matching `total_filter` also matches `filter`.
+    ///
+    /// Looks for a counterexample, a document matching `total_filter` but not `filter`, with
+    /// a single [`Model::exists`] on `{ $and: [total_filter, { $nor: [filter] }] }`. That is
+    /// the same answer as comparing the two counts, read from one query, so a write between
+    /// two counts can't skew it. Documents lacking a field that `filter` requires don't match
+    /// it. Vacuously `true` when nothing matches `total_filter`.
+    ///
+    /// # Parameters
+    /// - `filter`: The condition every selected document must meet, e.g. `{ "verified": true }`.
+    /// - `total_filter`: The documents to check, e.g. `{ "role": "admin" }`.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// // Only allow the action once every admin has verified their email.
+    /// let ready = User::all_match(doc! { "verified": true }, doc! { "role": "admin" }).await?;
+    /// ```
+    async fn all_match(
+        filter: impl Into<bson::Document> + Send,
+        total_filter: impl Into<bson::Document> + Send
+    ) -> Result<bool, OximodError>
+        where Self: Sized
+    {
+        let counterexample = bson::doc! { "$and": [total_filter.into(), { "$nor": [filter.into()] }] };
+        Ok(!Self::exists(counterexample).await?)
+    }
+    /// Checks the fields marked `#[validate(unique)]` against the collection.
+    ///
+    /// This is separate from the synchronous `validate()` because it queries MongoDB. Each
+    /// value is compared as `save()` would store it, and the document's own `_id` is excluded,
+    /// so checking a loaded model before writing it back passes. `None` values are not checked.
+    /// Call it before `save()` for a friendly message. Keep `#[index(unique)]` as well, since
+    /// another write can land between the check and yours.
+    ///
+    /// # Returns
+    /// - `Ok(())` if no other document shares a unique value, or the model has no unique fields.
+    /// - [`OximodError::ValidationError`](crate::error::oximod_error::OximodError::ValidationError) naming the first duplicated field, e.g. `Field 'email' must be unique`.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// user.validate_unique().await?;
+    /// user.save().await?;
+    /// ```
+    async fn validate_unique(&self) -> Result<(), OximodError>;
     /// Deletes all documents from the model's collection.
     ///
-    /// This is useful for resetting test data or clearing out a dataset.
+    /// This is useful for resetting test data or clearing out a dataset. With the `tracing`
+    /// feature, every run emits a `warn` event with the number of deleted documents.
+    ///
+    /// Models declared with `#[model(allow_clear = false)]` reject `User::clear()` at compile
+    /// time; calls through a generic `T: Model` return an [`OximodError::CommandError`] instead.
     ///
     /// # Returns
     /// - [`DeleteResult`](https://docs.rs/mongodb/latest/mongodb/results/struct.DeleteResult.html) with the number of deleted documents.
@@ -229,6 +1143,28 @@ pub trait Model {
     /// println!("Cleared {} documents", result.deleted_count);
     /// ```
     async fn clear() -> Result<DeleteResult, OximodError>;
+    /// Drops the model's collection, including its indexes.
+    ///
+    /// Unlike [`Model::clear`], which deletes the documents but keeps the collection, its
+    /// indexes, and options such as a `$jsonSchema` validator or capped size, this removes the
+    /// collection itself. The next write recreates it; indexes come back on the next `save()`
+    /// or [`Model::sync_indexes`]. Dropping a collection that doesn't exist succeeds. With the
+    /// `tracing` feature, every run emits a `warn` event.
+    ///
+    /// Models declared with `#[model(allow_clear = false)]` reject `User::drop_collection()` at
+    /// compile time, as they do `clear()`; calls through a generic `T: Model` return an
+    /// [`OximodError::CommandError`] instead.
+    ///
+    /// # Returns
+    /// - `Ok(())` once the collection is gone.
+    /// - [`OximodError::ConnectionError`](crate::error::oximod_error::OximodError::ConnectionError) if the drop fails.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// // Test teardown
+    /// User::drop_collection().await?;
+    /// ```
+    async fn drop_collection() -> Result<(), OximodError>;
     /// Executes an aggregation pipeline on the model's MongoDB collection.
     ///
     /// # Parameters
@@ -251,4 +1187,509 @@ pub trait Model {
     async fn aggregate(
         pipeline: impl Into<Vec<bson::Document>> + Send
     ) -> Result<Cursor<bson::Document>, OximodError>;
+    /// Runs an aggregation pipeline whose stages are serde types instead of `doc!` literals.
+    ///
+    /// Each stage is serialized with `bson::to_document`, so it must serialize to a map,
+    /// typically a struct or an externally tagged enum such as `{ "$match": { ... } }`.
+    /// Use an enum to mix different kinds of stages in one pipeline.
+    ///
+    /// # Parameters
+    /// - `stages`: The pipeline stages in order.
+    ///
+    /// # Returns
+    /// - A [`Cursor`](https://docs.rs/mongodb/latest/mongodb/struct.Cursor.html) over the result documents.
+    /// - [`OximodError::SerializationError`](crate::error::oximod_error::OximodError::SerializationError)
+    ///   naming the index of the first stage that failed to serialize; nothing is sent in that case.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// #[derive(Serialize)]
+    /// enum Stage {
+    ///     #[serde(rename = "$match")]
+    ///     Match(Document),
+    ///     #[serde(rename = "$limit")]
+    ///     Limit(i64),
+    /// }
+    ///
+    /// let cursor = User::aggregate_typed(vec![
+    ///     Stage::Match(doc! { "active": true }),
+    ///     Stage::Limit(10),
+    /// ]).await?;
+    /// ```
+    async fn aggregate_typed(
+        stages: Vec<impl serde::Serialize + Send>
+    ) -> Result<Cursor<bson::Document>, OximodError>
+        where Self: Sized
+    {
+        let pipeline = stages
+            .iter()
+            .enumerate()
+            .map(|(index, stage)|
+                bson::to_document(stage).map_err(|e|
+                    attach_printables!(
+                        OximodError::SerializationError(format!("stage {}: {}", index, e)),
+                        "Each stage must serialize to a document, e.g. a struct or an externally tagged enum."
+                    )
+                )
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::aggregate(pipeline).await
+    }
+    /// Builds a `$lookup` stage that joins the documents of `Other` into this model's documents.
+    ///
+    /// The joined collection is `Other::collection_name()`, so renaming a collection updates
+    /// every pipeline that joins it. `$lookup` only joins collections of the same database,
+    /// so both models should share their `#[db("...")]`.
+    ///
+    /// # Parameters
+    /// - `local_field`: The stored key in this model's documents, e.g. `"user_id"`.
+    /// - `foreign_field`: The stored key in `Other`'s documents to match it against, e.g. `"_id"`.
+    /// - `as_field`: The key of the output array holding the matching `Other` documents.
+    ///
+    /// # Returns
+    /// - The `{ "$lookup": { ... } }` stage document.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let orders = Order::aggregate_as::<Document>(vec![
+    ///     doc! { "$match": { "status": "paid" } },
+    ///     Order::lookup_stage::<User>("user_id", "_id", "user"),
+    ///     doc! { "$unwind": "$user" },
+    /// ]).await?;
+    /// ```
+    fn lookup_stage<Other: Model>(local_field: &str, foreign_field: &str, as_field: &str) -> Document
+        where Self: Sized
+    {
+        bson::doc! {
+            "$lookup": {
+                "from": Other::collection_name(),
+                "localField": local_field,
+                "foreignField": foreign_field,
+                "as": as_field,
+            }
+        }
+    }
+    /// Counts the documents matching `filter`, grouped by the value of one field.
+    ///
+    /// Builds a `$match` / `$group` / `$sort` pipeline, so "count by status" needs no hand-written
+    /// aggregation. Documents missing the field are counted under `Bson::Null`. Names starting
+    /// with `$` are rejected with [`OximodError::AggregationError`], as in [`Model::sum`].
+    ///
+    /// # Parameters
+    /// - `group_by`: The stored key to group on, e.g. `"status"` or `"address.city"`.
+    /// - `filter`: A BSON document restricting which documents are counted.
+    ///
+    /// # Returns
+    /// - `(group value, count)` pairs, sorted by count in descending order.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// for (status, count) in Order::count_grouped("status", doc! {}).await? {
+    ///     println!("{}: {}", status, count);
+    /// }
+    /// ```
+    async fn count_grouped(
+        group_by: &str,
+        filter: impl Into<bson::Document> + Send
+    ) -> Result<Vec<(Bson, u64)>, OximodError>;
+    /// Sums a numeric field over the documents matching `filter`.
+    ///
+    /// Runs a single `$group` stage. `field` is a stored key such as `"total"` or
+    /// `"items.price"`; names starting with `$` are rejected with
+    /// [`OximodError::AggregationError`] so they can't be read as expressions.
+    /// Non-numeric values are ignored, as with MongoDB's `$sum`.
+    ///
+    /// # Returns
+    /// - The sum, or `0.0` when no documents match.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let revenue = Order::sum("total", doc! { "status": "paid" }).await?;
+    /// ```
+    async fn sum(field: &str, filter: impl Into<bson::Document> + Send) -> Result<f64, OximodError>
+        where Self: Sized
+    {
+        match aggregate::group_value::<Self>("$sum", field, filter.into()).await? {
+            Some(value) => Ok(aggregate::as_f64(value)?.unwrap_or(0.0)),
+            None => Ok(0.0),
+        }
+    }
+    /// Averages a numeric field over the documents matching `filter`.
+    ///
+    /// See [`Model::sum`] for how `field` is interpreted.
+    ///
+    /// # Returns
+    /// - The average, or `None` when no documents match or none hold a number.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let mean_age = User::avg("age", doc! { "active": true }).await?;
+    /// ```
+    async fn avg(
+        field: &str,
+        filter: impl Into<bson::Document> + Send
+    ) -> Result<Option<f64>, OximodError>
+        where Self: Sized
+    {
+        match aggregate::group_value::<Self>("$avg", field, filter.into()).await? {
+            Some(value) => aggregate::as_f64(value),
+            None => Ok(None),
+        }
+    }
+    /// Finds the smallest value of a field over the documents matching `filter`.
+    ///
+    /// Returns the raw [`Bson`] so non-numeric fields such as dates and strings work too.
+    /// See [`Model::sum`] for how `field` is interpreted.
+    ///
+    /// # Returns
+    /// - The minimum, or `None` when no documents match.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let first_signup = User::min("created_at", doc! {}).await?;
+    /// ```
+    async fn min(
+        field: &str,
+        filter: impl Into<bson::Document> + Send
+    ) -> Result<Option<Bson>, OximodError>
+        where Self: Sized
+    {
+        let value = aggregate::group_value::<Self>("$min", field, filter.into()).await?;
+        Ok(value.filter(|value| *value != Bson::Null))
+    }
+    /// Finds the largest value of a field over the documents matching `filter`.
+    ///
+    /// See [`Model::min`].
+    ///
+    /// # Returns
+    /// - The maximum, or `None` when no documents match.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let top_score = Player::max("score", doc! { "season": 3 }).await?;
+    /// ```
+    async fn max(
+        field: &str,
+        filter: impl Into<bson::Document> + Send
+    ) -> Result<Option<Bson>, OximodError>
+        where Self: Sized
+    {
+        let value = aggregate::group_value::<Self>("$max", field, filter.into()).await?;
+        Ok(value.filter(|value| *value != Bson::Null))
+    }
+    /// Runs an aggregation pipeline and deserializes every result into `T`.
+    ///
+    /// Unlike [`Model::aggregate`], this drains the cursor, so it suits pipelines with a
+    /// bounded number of results such as `$group` summaries.
+    ///
+    /// # Parameters
+    /// - `pipeline`: A vector of BSON documents defining the aggregation stages.
+    ///
+    /// # Returns
+    /// - A `Vec<T>` with one entry per result document.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// #[derive(Deserialize)]
+    /// struct AgeCount { _id: i32, count: i64 }
+    ///
+    /// let counts: Vec<AgeCount> = User::aggregate_as(vec![
+    ///     doc! { "$group": { "_id": "$age", "count": { "$sum": 1 } } }
+    /// ]).await?;
+    /// ```
+    async fn aggregate_as<T>(
+        pipeline: impl Into<Vec<bson::Document>> + Send
+    ) -> Result<Vec<T>, OximodError>
+        where T: serde::de::DeserializeOwned + Send;
+    /// Runs an aggregation pipeline and returns one page of its results with their total count.
+    ///
+    /// Appends a `$facet` stage that takes the page and runs `$count` over the same results,
+    /// so the pipeline runs once. End the pipeline with a `$sort` to keep pages stable. The
+    /// page's documents are returned in a single result document, so a page may hold at most
+    /// 16 MB.
+    ///
+    /// # Parameters
+    /// - `pipeline`: The aggregation stages producing the results to paginate.
+    /// - `page`: The 1-based number of the page to return.
+    /// - `per_page`: The number of results per page.
+    ///
+    /// # Returns
+    /// - A [`Page`] with the page's documents and the total number of results. A page past the end is empty.
+    /// - [`OximodError::AggregationError`](crate::error::oximod_error::OximodError::AggregationError) if `page` or `per_page` is `0`; nothing is sent.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let page = User::aggregate_paginated(vec![
+    ///     doc! { "$match": { "active": true } },
+    ///     doc! { "$sort": { "name": 1 } },
+    /// ], 2, 20).await?;
+    /// println!("{} of {} users, page {}/{}", page.items.len(), page.total, page.page, page.total_pages());
+    /// ```
+    async fn aggregate_paginated(
+        pipeline: impl Into<Vec<bson::Document>> + Send,
+        page: u32,
+        per_page: u32
+    ) -> Result<Page<Document>, OximodError>
+        where Self: Sized
+    {
+        let facet = page::facet_stage(page, per_page)?;
+        let mut pipeline = pipeline.into();
+        pipeline.push(facet);
+
+        let results = Self::aggregate_as::<Document>(pipeline).await?;
+        page::from_facet(results, page, per_page)
+    }
+    /// Picks up to `n` random documents matching the filter.
+    ///
+    /// Runs `$match` followed by `$sample: { size: n }`, so the server does the sampling instead
+    /// of the whole match being fetched and shuffled. `$sample` may pick the same document more
+    /// than once; repeats are dropped by `_id`, so fewer than `n` models can come back even when
+    /// more documents match.
+    ///
+    /// # Parameters
+    /// - `filter`: A BSON query document used to match documents.
+    /// - `n`: The maximum number of documents to return.
+    ///
+    /// # Returns
+    /// - Up to `n` distinct models in random order; empty when nothing matches or `n` is `0`.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let featured = Product::random(doc! { "in_stock": true }, 1).await?;
+    /// ```
+    async fn random(
+        filter: impl Into<bson::Document> + Send,
+        n: u32
+    ) -> Result<Vec<Self>, OximodError>
+        where Self: Sized + serde::de::DeserializeOwned + Send
+    {
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+
+        let sampled = Self::aggregate_as::<Document>(
+            vec![
+                bson::doc! { "$match": filter.into() },
+                bson::doc! { "$sample": { "size": i64::from(n) } }
+            ]
+        ).await?;
+
+        let mut seen = Vec::with_capacity(sampled.len());
+        let mut models = Vec::with_capacity(sampled.len());
+        for document in sampled {
+            if let Some(id) = document.get("_id") {
+                if seen.contains(id) {
+                    continue;
+                }
+                seen.push(id.clone());
+            }
+            models.push(handle::from_document(document)?);
+        }
+
+        Ok(models)
+    }
+    /// Returns MongoDB's query plan for a `find` with the given filter, without running the query.
+    ///
+    /// Useful for checking that a declared `#[index]` is actually used: look for an `IXSCAN`
+    /// stage under `queryPlanner.winningPlan`.
+    ///
+    /// # Parameters
+    /// - `filter`: The filter the query would use.
+    ///
+    /// # Returns
+    /// - The raw `explain` output at `queryPlanner` verbosity.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let plan = User::explain_find(doc! { "email": "alice@example.com" }).await?;
+    /// println!("{:#?}", plan.get_document("queryPlanner")?.get("winningPlan"));
+    /// ```
+    async fn explain_find(filter: impl Into<bson::Document> + Send) -> Result<Document, OximodError>;
+    /// Opens a change stream on the model's collection.
+    ///
+    /// The returned [`ChangeStream`] implements `Stream`; `full_document` is deserialized into the
+    /// model when present (inserts and replacements). Change streams require a replica set or
+    /// sharded cluster.
+    ///
+    /// # Parameters
+    /// - `pipeline`: Aggregation stages filtering or reshaping events, e.g. a `$match` on `operationType`.
+    ///   Pass an empty `Vec` to receive every event.
+    /// - `resume_after`: A token from [`ChangeStream::resume_token`] to continue after a restart.
+    ///
+    /// # Returns
+    /// - A [`ChangeStream`] of the model's change events.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let mut stream = User::watch(vec![doc! { "$match": { "operationType": "insert" } }], None).await?;
+    /// while let Some(event) = stream.next().await {
+    ///     if let Some(user) = event?.full_document {
+    ///         cache.invalidate(&user);
+    ///     }
+    /// }
+    /// let token = stream.resume_token();
+    /// ```
+    async fn watch(
+        pipeline: impl Into<Vec<bson::Document>> + Send,
+        resume_after: Option<ResumeToken>
+    ) -> Result<ChangeStream<Self>, OximodError>
+        where Self: Sized + serde::de::DeserializeOwned;
+    /// Sends a batch of inserts, updates, and deletes to the model's collection in one round trip.
+    ///
+    /// Uses MongoDB's `bulkWrite` command, which requires MongoDB 8.0 or newer.
+    ///
+    /// # Parameters
+    /// - `ops`: The operations to run, see [`WriteOp`].
+    /// - `ordered`: If `true`, stops at the first failing operation; if `false`, runs every
+    ///   operation and reports failures at the end.
+    ///
+    /// # Returns
+    /// - [`SummaryBulkWriteResult`](https://docs.rs/mongodb/latest/mongodb/results/struct.SummaryBulkWriteResult.html) with aggregate inserted, matched, modified, upserted, and deleted counts.
+    ///
+    /// # Errors
+    /// - Returns [`OximodError::CommandError`] without contacting the server if `ops` is empty.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let result = User::bulk_write(vec![
+    ///     WriteOp::InsertOne(doc! { "name": "Alice", "age": 30 }),
+    ///     WriteOp::DeleteOne(doc! { "name": "Bob" }),
+    /// ], true).await?;
+    /// assert_eq!(result.inserted_count, 1);
+    /// ```
+    async fn bulk_write(
+        ops: Vec<WriteOp>,
+        ordered: bool
+    ) -> Result<SummaryBulkWriteResult, OximodError>;
+    /// Inserts the current model instance as part of the given session's transaction.
+    ///
+    /// Validation runs as in [`Model::save`], but indexes are never created here because
+    /// index builds don't belong in a transaction; call [`Model::sync_indexes`] beforehand.
+    ///
+    /// # Parameters
+    /// - `session`: The session passed to the `with_transaction` callback.
+    ///
+    /// # Returns
+    /// - `ObjectId` of the inserted document.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// with_transaction(|session| Box::pin(async move {
+    ///     user.save_with_session(session).await
+    /// })).await?;
+    /// ```
+    async fn save_with_session(&self, session: &mut ClientSession) -> Result<ObjectId, OximodError>;
+    /// Finds a single document matching the filter within the given session.
+    ///
+    /// # Parameters
+    /// - `filter`: A BSON document specifying which document to match.
+    /// - `session`: The session passed to the `with_transaction` callback.
+    ///
+    /// # Returns
+    /// - `Some(Self)` if a document is found, otherwise `None`.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let account = Account::find_one_with_session(doc! { "owner": "alice" }, session).await?;
+    /// ```
+    async fn find_one_with_session(
+        filter: impl Into<bson::Document> + Send,
+        session: &mut ClientSession
+    ) -> Result<Option<Self>, OximodError>
+        where Self: Sized;
+    /// Finds a document by its `_id` within the given session.
+    ///
+    /// # Parameters
+    /// - `id`: The [`ObjectId`](https://docs.rs/mongodb/latest/mongodb/bson/oid/struct.ObjectId.html) of the document.
+    /// - `session`: The session passed to the `with_transaction` callback.
+    ///
+    /// # Returns
+    /// - `Some(Self)` if a document is found, otherwise `None`.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let account = Account::find_by_id_with_session(id, session).await?;
+    /// ```
+    async fn find_by_id_with_session(
+        id: ObjectId,
+        session: &mut ClientSession
+    ) -> Result<Option<Self>, OximodError>
+        where Self: Sized;
+    /// Updates the first document matching the filter within the given session.
+    ///
+    /// # Parameters
+    /// - `filter`: A BSON document specifying which document to match.
+    /// - `update`: A BSON document with the update operations to apply.
+    /// - `session`: The session passed to the `with_transaction` callback.
+    ///
+    /// # Returns
+    /// - [`UpdateResult`](https://docs.rs/mongodb/latest/mongodb/results/struct.UpdateResult.html) for the matched document.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// Account::update_one_with_session(
+    ///     doc! { "owner": "alice" },
+    ///     doc! { "$inc": { "balance": -100 } },
+    ///     session
+    /// ).await?;
+    /// ```
+    async fn update_one_with_session(
+        filter: impl Into<bson::Document> + Send,
+        update: impl Into<bson::Document> + Send,
+        session: &mut ClientSession
+    ) -> Result<UpdateResult, OximodError>;
+    /// Updates a document by its `_id` within the given session.
+    ///
+    /// # Parameters
+    /// - `id`: The [`ObjectId`](https://docs.rs/mongodb/latest/mongodb/bson/oid/struct.ObjectId.html) of the document to update.
+    /// - `update`: A BSON document with the update operations to apply.
+    /// - `session`: The session passed to the `with_transaction` callback.
+    ///
+    /// # Returns
+    /// - [`UpdateResult`](https://docs.rs/mongodb/latest/mongodb/results/struct.UpdateResult.html) for the matched document.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// Account::update_by_id_with_session(id, doc! { "$inc": { "balance": 100 } }, session).await?;
+    /// ```
+    async fn update_by_id_with_session(
+        id: ObjectId,
+        update: impl Into<bson::Document> + Send,
+        session: &mut ClientSession
+    ) -> Result<UpdateResult, OximodError>;
+    /// Deletes the first document matching the filter within the given session.
+    ///
+    /// # Parameters
+    /// - `filter`: A BSON document specifying which document to delete.
+    /// - `session`: The session passed to the `with_transaction` callback.
+    ///
+    /// # Returns
+    /// - [`DeleteResult`](https://docs.rs/mongodb/latest/mongodb/results/struct.DeleteResult.html) with the number of deleted documents.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// Account::delete_one_with_session(doc! { "balance": 0 }, session).await?;
+    /// ```
+    async fn delete_one_with_session(
+        filter: impl Into<bson::Document> + Send,
+        session: &mut ClientSession
+    ) -> Result<DeleteResult, OximodError>;
+    /// Deletes a document by its `_id` within the given session.
+    ///
+    /// # Parameters
+    /// - `id`: The [`ObjectId`](https://docs.rs/mongodb/latest/mongodb/bson/oid/struct.ObjectId.html) of the document to delete.
+    /// - `session`: The session passed to the `with_transaction` callback.
+    ///
+    /// # Returns
+    /// - [`DeleteResult`](https://docs.rs/mongodb/latest/mongodb/results/struct.DeleteResult.html) with the number of deleted documents.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// Account::delete_by_id_with_session(id, session).await?;
+    /// ```
+    async fn delete_by_id_with_session(
+        id: ObjectId,
+        session: &mut ClientSession
+    ) -> Result<DeleteResult, OximodError>;
 }
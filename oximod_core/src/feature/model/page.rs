@@ -0,0 +1,102 @@
+use mongodb::bson::{ doc, Bson, Document };
+use crate::{ error::oximod_error::OximodError, Printable };
+
+/// One page of results together with the number of results across all pages.
+///
+/// Returned by [`Model::aggregate_paginated`](crate::feature::model::Model::aggregate_paginated).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    /// The results on this page, at most `per_page` of them.
+    pub items: Vec<T>,
+    /// The number of results across all pages.
+    pub total: u64,
+    /// The 1-based number of this page.
+    pub page: u32,
+    /// The page size that was asked for.
+    pub per_page: u32,
+}
+
+impl<T> Page<T> {
+    /// The number of pages, counting a partial last page; `0` when there are no results.
+    pub fn total_pages(&self) -> u64 {
+        self.total.div_ceil(u64::from(self.per_page.max(1)))
+    }
+
+    /// Whether a later page has results.
+    pub fn has_next(&self) -> bool {
+        u64::from(self.page) < self.total_pages()
+    }
+}
+
+/// Builds the `$facet` stage that splits a pipeline's results into one page and their count.
+pub(crate) fn facet_stage(page: u32, per_page: u32) -> Result<Document, OximodError> {
+    if page == 0 || per_page == 0 {
+        return Err(
+            attach_printables!(
+                OximodError::AggregationError(
+                    format!("Invalid page {} of size {}; both must be at least 1", page, per_page)
+                ),
+                "Pages are numbered from 1 and need room for at least one result."
+            )
+        );
+    }
+
+    let skip = i64::from(page - 1) * i64::from(per_page);
+    Ok(
+        doc! {
+            "$facet": {
+                "data": [{ "$skip": skip }, { "$limit": i64::from(per_page) }],
+                "total": [{ "$count": "count" }],
+            }
+        }
+    )
+}
+
+/// Reads the single `{ data: [...], total: [{ count }] }` document produced by [`facet_stage`].
+pub(crate) fn from_facet(
+    mut results: Vec<Document>,
+    page: u32,
+    per_page: u32
+) -> Result<Page<Document>, OximodError> {
+    let malformed = |what: &str| {
+        attach_printables!(
+            OximodError::AggregationError(format!("Unexpected $facet result: {}", what)),
+            "Make sure the pipeline doesn't end with a stage that reshapes every document, such as $out or $merge."
+        )
+    };
+
+    let Some(mut result) = results.pop() else {
+        return Err(malformed("no result document"));
+    };
+
+    let items = match result.remove("data") {
+        Some(Bson::Array(items)) =>
+            items
+                .into_iter()
+                .map(|item| match item {
+                    Bson::Document(document) => Ok(document),
+                    _ => Err(malformed("a result is not a document")),
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        _ => {
+            return Err(malformed("missing `data`"));
+        }
+    };
+
+    let total = match result.get_array("total").ok().and_then(|total| total.first()) {
+        None => 0,
+        Some(Bson::Document(count)) =>
+            match count.get("count") {
+                Some(Bson::Int32(n)) => *n as u64,
+                Some(Bson::Int64(n)) => *n as u64,
+                _ => {
+                    return Err(malformed("`total` has no integer count"));
+                }
+            }
+        Some(_) => {
+            return Err(malformed("`total` is not a document"));
+        }
+    };
+
+    Ok(Page { items, total, page, per_page })
+}
@@ -0,0 +1,145 @@
+use std::marker::PhantomData;
+use mongodb::{ bson::{ doc, Document }, results::{ DeleteResult, UpdateResult } };
+use serde::de::DeserializeOwned;
+use crate::{
+    error::oximod_error::OximodError,
+    feature::model::{ handle::{ collect_cursor, from_document }, Model },
+    Printable,
+};
+
+/// A reusable, chainable query over a model, returned by
+/// [`Model::query`](crate::feature::model::Model::query).
+///
+/// Filters added with [`QueryBuilder::filter`] are combined with `$and`, so a base query
+/// such as "active users" can be cloned and narrowed without repeating it. `sort`, `limit`,
+/// and `skip` apply to [`all`](QueryBuilder::all), [`one`](QueryBuilder::one), and
+/// [`count`](QueryBuilder::count); [`update`](QueryBuilder::update) and
+/// [`delete`](QueryBuilder::delete) act on every matching document.
+pub struct QueryBuilder<M> {
+    filters: Vec<Document>,
+    sort: Option<Document>,
+    limit: Option<i64>,
+    skip: Option<u64>,
+    model: PhantomData<fn() -> M>,
+}
+
+impl<M> Clone for QueryBuilder<M> {
+    fn clone(&self) -> Self {
+        Self {
+            filters: self.filters.clone(),
+            sort: self.sort.clone(),
+            limit: self.limit,
+            skip: self.skip,
+            model: PhantomData,
+        }
+    }
+}
+
+impl<M> Default for QueryBuilder<M> {
+    fn default() -> Self {
+        Self { filters: Vec::new(), sort: None, limit: None, skip: None, model: PhantomData }
+    }
+}
+
+impl<M> QueryBuilder<M> where M: Model + DeserializeOwned + Send + Sync {
+    /// Adds a filter; every filter added must match.
+    pub fn filter(mut self, filter: impl Into<Document>) -> Self {
+        self.filters.push(filter.into());
+        self
+    }
+
+    /// Sets the sort order, e.g. `doc! { "age": -1 }`.
+    pub fn sort(mut self, sort: Document) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Limits the number of documents returned or counted.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Skips this many matching documents first.
+    pub fn skip(mut self, skip: u64) -> Self {
+        self.skip = Some(skip);
+        self
+    }
+
+    /// Returns the combined filter document: `{}`, the single filter, or `{ "$and": [...] }`.
+    pub fn build_filter(&self) -> Document {
+        match self.filters.as_slice() {
+            [] => Document::new(),
+            [filter] => filter.clone(),
+            filters => doc! { "$and": filters.to_vec() },
+        }
+    }
+
+    /// Returns every matching document.
+    pub async fn all(self) -> Result<Vec<M>, OximodError> {
+        let cursor = M::get_collection()?
+            .find(self.build_filter())
+            .with_options(
+                mongodb::options::FindOptions
+                    ::builder()
+                    .sort(self.sort)
+                    .limit(self.limit)
+                    .skip(self.skip)
+                    .build()
+            ).await
+            .map_err(|e|
+                attach_printables!(
+                    OximodError::ConnectionError(e.to_string()),
+                    "Failed to execute find query. Double-check your filter syntax or collection state."
+                )
+            )?;
+
+        collect_cursor(cursor).await
+    }
+
+    /// Returns the first matching document in sort order, if any.
+    pub async fn one(self) -> Result<Option<M>, OximodError> {
+        let result = M::get_collection()?
+            .find_one(self.build_filter())
+            .with_options(
+                mongodb::options::FindOneOptions::builder().sort(self.sort).skip(self.skip).build()
+            ).await
+            .map_err(|e|
+                attach_printables!(
+                    OximodError::ConnectionError(e.to_string()),
+                    "Failed to run find_one query. Ensure your filter is structured properly and the collection is accessible."
+                )
+            )?;
+
+        result.map(from_document).transpose()
+    }
+
+    /// Counts the matching documents, honouring `skip` and `limit`.
+    pub async fn count(self) -> Result<u64, OximodError> {
+        M::get_collection()?
+            .count_documents(self.build_filter())
+            .with_options(
+                mongodb::options::CountOptions
+                    ::builder()
+                    .limit(self.limit.map(|limit| limit.unsigned_abs()))
+                    .skip(self.skip)
+                    .build()
+            ).await
+            .map_err(|e|
+                attach_printables!(
+                    OximodError::ConnectionError(e.to_string()),
+                    "Failed to count documents. Make sure the filter is well-formed and the collection is accessible."
+                )
+            )
+    }
+
+    /// Applies `update` to every matching document through [`Model::update`].
+    pub async fn update(self, update: impl Into<Document> + Send) -> Result<UpdateResult, OximodError> {
+        M::update(self.build_filter(), update).await
+    }
+
+    /// Deletes every matching document through [`Model::delete`], running delete hooks.
+    pub async fn delete(self) -> Result<DeleteResult, OximodError> {
+        M::delete(self.build_filter()).await
+    }
+}
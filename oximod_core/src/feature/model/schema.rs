@@ -0,0 +1,35 @@
+use mongodb::{ bson::{ doc, Document }, error::ErrorKind, Collection };
+use crate::{ error::oximod_error::OximodError, Printable };
+
+/// The server error code for a command on a collection that doesn't exist.
+const NAMESPACE_NOT_FOUND: i32 = 26;
+
+#[doc(hidden)]
+/// Installs `schema` as the `$jsonSchema` validator of `collection`.
+///
+/// Existing collections are updated with `collMod`; a missing one is created with the
+/// validator. MongoDB's defaults apply (`validationLevel: "strict"`,
+/// `validationAction: "error"`), so later inserts and updates that break the schema are
+/// rejected, while documents already stored are not rechecked until they are updated.
+pub async fn apply_json_schema(
+    collection: &Collection<Document>,
+    schema: Document
+) -> Result<(), OximodError> {
+    let name = collection.name();
+    let db = collection.client().database(&collection.namespace().db);
+    let validator = doc! { "$jsonSchema": schema };
+
+    let result = db.run_command(doc! { "collMod": name, "validator": validator.clone() }).await;
+    let result = match result {
+        Err(e) if matches!(e.kind.as_ref(), ErrorKind::Command(c) if c.code == NAMESPACE_NOT_FOUND) =>
+            db.create_collection(name).validator(validator).await,
+        other => other.map(|_| ()),
+    };
+
+    result.map_err(|e| {
+        attach_printables!(
+            OximodError::CommandError(e.to_string()),
+            "Failed to install the $jsonSchema validator. Check that the user may run `collMod` and that every `pattern` is a valid PCRE regex."
+        )
+    })
+}
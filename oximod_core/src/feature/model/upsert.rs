@@ -0,0 +1,50 @@
+use mongodb::{ bson::{ oid::ObjectId, Bson }, results::UpdateResult };
+use crate::{ error::oximod_error::OximodError, Printable };
+
+/// The result of an upsert, returned by
+/// [`Model::upsert_one`](crate::feature::model::Model::upsert_one).
+///
+/// Unlike the driver's [`UpdateResult`], the upserted id is already an [`ObjectId`]. Convert
+/// any other `UpdateResult` with `UpsertOutcome::try_from(result)?`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UpsertOutcome {
+    /// The number of documents that matched the filter.
+    pub matched: u64,
+    /// The number of matched documents that were changed.
+    pub modified: u64,
+    /// The `_id` of the inserted document, if no document matched.
+    pub upserted_id: Option<ObjectId>,
+}
+
+impl UpsertOutcome {
+    /// Whether the upsert inserted a new document.
+    pub fn inserted(&self) -> bool {
+        self.upserted_id.is_some()
+    }
+}
+
+impl TryFrom<UpdateResult> for UpsertOutcome {
+    type Error = OximodError;
+
+    /// Fails with [`OximodError::SerializationError`] if the upserted `_id` is not an `ObjectId`.
+    fn try_from(result: UpdateResult) -> Result<Self, Self::Error> {
+        let upserted_id = match result.upserted_id {
+            None => None,
+            Some(Bson::ObjectId(id)) => Some(id),
+            Some(other) => {
+                return Err(
+                    attach_printables!(
+                        OximodError::SerializationError(format!("upserted_id is not an ObjectId: {}", other)),
+                        "The upsert inserted a non-ObjectId _id. Run it through `get_collection()` to read `upserted_id` as Bson."
+                    )
+                );
+            }
+        };
+
+        Ok(Self {
+            matched: result.matched_count,
+            modified: result.modified_count,
+            upserted_id,
+        })
+    }
+}
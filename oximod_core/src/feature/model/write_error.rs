@@ -0,0 +1,72 @@
+use mongodb::error::{ Error, ErrorKind, IndexedWriteError, InsertManyError, WriteFailure };
+use crate::error::oximod_error::OximodError;
+
+/// The server error code for a unique index violation.
+const DUPLICATE_KEY: i32 = 11000;
+/// The server error code for a write concern that wasn't satisfied in time, e.g. after `wtimeout`.
+const WRITE_CONCERN_FAILED: i32 = 64;
+
+#[doc(hidden)]
+/// Maps a driver error raised by a write to an [`OximodError`].
+///
+/// Unique index violations (code `11000`) become [`OximodError::DuplicateKey`] naming the
+/// violated index, and write concern failures become [`OximodError::WriteConcernError`];
+/// everything else is passed to `fallback`.
+pub fn write_error(e: Error, fallback: fn(String) -> OximodError) -> OximodError {
+    let message = match e.kind.as_ref() {
+        ErrorKind::Write(WriteFailure::WriteError(write_error)) if
+            write_error.code == DUPLICATE_KEY
+        => Some(&write_error.message),
+        ErrorKind::Command(command_error) if command_error.code == DUPLICATE_KEY =>
+            Some(&command_error.message),
+        ErrorKind::InsertMany(InsertManyError { write_errors: Some(write_errors), .. }) =>
+            write_errors
+                .iter()
+                .find(|write_error| write_error.code == DUPLICATE_KEY)
+                .map(|write_error| &write_error.message),
+        ErrorKind::BulkWrite(bulk_write_error) =>
+            bulk_write_error.write_errors
+                .values()
+                .find(|write_error| write_error.code == DUPLICATE_KEY)
+                .map(|write_error| &write_error.message),
+        _ => None,
+    };
+
+    match message {
+        Some(message) => OximodError::DuplicateKey { key: index_name(message).to_string() },
+        None if is_write_concern_error(&e) => OximodError::WriteConcernError(e.to_string()),
+        None => fallback(e.to_string()),
+    }
+}
+
+/// Whether the write failed only because its write concern wasn't satisfied. Errors that also
+/// carry write errors are left to the caller, since some documents weren't written at all.
+fn is_write_concern_error(e: &Error) -> bool {
+    match e.kind.as_ref() {
+        ErrorKind::Write(WriteFailure::WriteConcernError(_)) => true,
+        ErrorKind::InsertMany(InsertManyError { write_errors: None, write_concern_error: Some(_), .. }) =>
+            true,
+        ErrorKind::BulkWrite(bulk_write_error) =>
+            bulk_write_error.write_errors.is_empty() && !bulk_write_error.write_concern_errors.is_empty(),
+        ErrorKind::Command(command_error) => command_error.code == WRITE_CONCERN_FAILED,
+        _ => false,
+    }
+}
+
+/// Maps the error of one document of an unordered `insert_many` like [`write_error`] does.
+pub(crate) fn indexed_write_error(write_error: &IndexedWriteError) -> OximodError {
+    if write_error.code == DUPLICATE_KEY {
+        OximodError::DuplicateKey { key: index_name(&write_error.message).to_string() }
+    } else {
+        OximodError::ConnectionError(write_error.message.clone())
+    }
+}
+
+/// Extracts `email_1` from `E11000 duplicate key error collection: db.users index: email_1 dup key: { ... }`,
+/// falling back to the whole message if the server phrased it differently.
+fn index_name(message: &str) -> &str {
+    message
+        .split_once(" index: ")
+        .and_then(|(_, rest)| rest.split_whitespace().next())
+        .unwrap_or(message)
+}
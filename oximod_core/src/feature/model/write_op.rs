@@ -0,0 +1,73 @@
+use mongodb::{
+    bson::Document,
+    options::{
+        DeleteManyModel,
+        DeleteOneModel,
+        InsertOneModel,
+        UpdateManyModel,
+        UpdateOneModel,
+        WriteModel,
+    },
+    Namespace,
+};
+
+/// A single operation in a [`Model::bulk_write`](crate::feature::model::Model::bulk_write) batch.
+///
+/// Operations hold raw BSON documents, so no model validation runs on inserted documents.
+///
+/// # Example
+/// ```rust, no_run
+/// let ops = vec![
+///     WriteOp::InsertOne(doc! { "name": "Alice", "age": 30 }),
+///     WriteOp::UpdateOne { filter: doc! { "name": "Bob" }, update: doc! { "$set": { "age": 41 } } },
+///     WriteOp::DeleteMany(doc! { "active": false }),
+/// ];
+/// ```
+#[derive(Debug, Clone)]
+pub enum WriteOp {
+    /// Inserts the document.
+    InsertOne(Document),
+    /// Applies `update` to the first document matching `filter`.
+    UpdateOne {
+        filter: Document,
+        update: Document,
+    },
+    /// Applies `update` to every document matching `filter`.
+    UpdateMany {
+        filter: Document,
+        update: Document,
+    },
+    /// Deletes the first document matching the filter.
+    DeleteOne(Document),
+    /// Deletes every document matching the filter.
+    DeleteMany(Document),
+}
+
+impl WriteOp {
+    #[doc(hidden)]
+    /// Converts the operation into the driver's [`WriteModel`] targeting `namespace`.
+    pub fn into_write_model(self, namespace: Namespace) -> WriteModel {
+        match self {
+            WriteOp::InsertOne(document) =>
+                InsertOneModel::builder().namespace(namespace).document(document).build().into(),
+            WriteOp::UpdateOne { filter, update } =>
+                UpdateOneModel::builder()
+                    .namespace(namespace)
+                    .filter(filter)
+                    .update(update)
+                    .build()
+                    .into(),
+            WriteOp::UpdateMany { filter, update } =>
+                UpdateManyModel::builder()
+                    .namespace(namespace)
+                    .filter(filter)
+                    .update(update)
+                    .build()
+                    .into(),
+            WriteOp::DeleteOne(filter) =>
+                DeleteOneModel::builder().namespace(namespace).filter(filter).build().into(),
+            WriteOp::DeleteMany(filter) =>
+                DeleteManyModel::builder().namespace(namespace).filter(filter).build().into(),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! Typed constructors for update documents.
+//!
+//! Each function starts an [`Update`], and the methods of the same names add more operators,
+//! so `push("tags", "rust").inc("edits", 1)` builds
+//! `{ "$push": { "tags": "rust" }, "$inc": { "edits": 1 } }`. An [`Update`] converts into a
+//! `Document`, so it passes straight into `update_one` and friends.
+//!
+//! [`ModelUpdate`] backs the typed `<Model>Update` builders generated by `#[derive(Model)]`.
+
+use mongodb::bson::{ self, Bson, Document };
+use serde::Serialize;
+use crate::{ error::oximod_error::OximodError, Printable };
+
+/// An update document built from operator helpers.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Update(Document);
+
+impl Update {
+    fn with(mut self, op: &str, field: &str, value: Bson) -> Self {
+        match self.0.get_mut(op) {
+            Some(Bson::Document(fields)) => {
+                fields.insert(field, value);
+            }
+            _ => {
+                let mut fields = Document::new();
+                fields.insert(field, value);
+                self.0.insert(op, fields);
+            }
+        }
+        self
+    }
+
+    /// Adds `{ "$set": { field: value } }`.
+    pub fn set(self, field: &str, value: impl Into<Bson>) -> Self {
+        self.with("$set", field, value.into())
+    }
+
+    /// Adds `{ "$unset": { field: "" } }`, removing the field from the document.
+    pub fn unset(self, field: &str) -> Self {
+        self.with("$unset", field, Bson::String(String::new()))
+    }
+
+    /// Adds `{ "$inc": { field: by } }`; use a negative value to decrement.
+    pub fn inc(self, field: &str, by: impl Into<Bson>) -> Self {
+        self.with("$inc", field, by.into())
+    }
+
+    /// Adds `{ "$push": { field: value } }`, appending `value` to an array.
+    pub fn push(self, field: &str, value: impl Into<Bson>) -> Self {
+        self.with("$push", field, value.into())
+    }
+
+    /// Adds `{ "$pull": { field: value } }`, removing every element equal to `value`.
+    pub fn pull(self, field: &str, value: impl Into<Bson>) -> Self {
+        self.with("$pull", field, value.into())
+    }
+
+    /// Adds `{ "$addToSet": { field: value } }`, appending `value` unless already present.
+    pub fn add_to_set(self, field: &str, value: impl Into<Bson>) -> Self {
+        self.with("$addToSet", field, value.into())
+    }
+}
+
+impl From<Update> for Document {
+    fn from(update: Update) -> Self {
+        update.0
+    }
+}
+
+/// Starts an update with `{ "$set": { field: value } }`.
+pub fn set(field: &str, value: impl Into<Bson>) -> Update {
+    Update::default().set(field, value)
+}
+
+/// Starts an update with `{ "$unset": { field: "" } }`.
+pub fn unset(field: &str) -> Update {
+    Update::default().unset(field)
+}
+
+/// Starts an update with `{ "$inc": { field: by } }`.
+pub fn inc(field: &str, by: impl Into<Bson>) -> Update {
+    Update::default().inc(field, by)
+}
+
+/// Starts an update with `{ "$push": { field: value } }`.
+pub fn push(field: &str, value: impl Into<Bson>) -> Update {
+    Update::default().push(field, value)
+}
+
+/// Starts an update with `{ "$pull": { field: value } }`.
+pub fn pull(field: &str, value: impl Into<Bson>) -> Update {
+    Update::default().pull(field, value)
+}
+
+/// Starts an update with `{ "$addToSet": { field: value } }`.
+pub fn add_to_set(field: &str, value: impl Into<Bson>) -> Update {
+    Update::default().add_to_set(field, value)
+}
+
+/// The update accumulated by a generated `<Model>Update` builder, started with `<Model>Update::new()`.
+///
+/// Values are serialized as they are added. A value that can't be represented in BSON, such
+/// as a `u64` above `i64::MAX`, is reported once by [`ModelUpdate::build`] instead of by
+/// every setter.
+#[derive(Debug, Clone, Default)]
+pub struct ModelUpdate {
+    update: Update,
+    error: Option<String>,
+}
+
+impl ModelUpdate {
+    /// Serializes `value` and adds `{ "$set": { field: value } }`.
+    pub fn set(&mut self, field: &str, value: &impl Serialize) {
+        if self.error.is_some() {
+            return;
+        }
+        match bson::to_bson(value) {
+            Ok(value) => {
+                self.update = std::mem::take(&mut self.update).set(field, value);
+            }
+            Err(e) => {
+                self.error = Some(format!("field '{}': {}", field, e));
+            }
+        }
+    }
+
+    /// Adds `{ "$unset": { field: "" } }`.
+    pub fn unset(&mut self, field: &str) {
+        self.update = std::mem::take(&mut self.update).unset(field);
+    }
+
+    /// Adds `{ "$inc": { field: by } }`.
+    pub fn inc(&mut self, field: &str, by: Bson) {
+        self.update = std::mem::take(&mut self.update).inc(field, by);
+    }
+
+    /// Produces the update document, or the first serialization error.
+    pub fn build(self) -> Result<Document, OximodError> {
+        match self.error {
+            Some(message) =>
+                Err(
+                    attach_printables!(
+                        OximodError::SerializationError(message),
+                        "A value passed to the update builder could not be converted to BSON. Check for u64 values above i64::MAX or maps with non-string keys."
+                    )
+                ),
+            None => Ok(self.update.into()),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::error::oximod_error::OximodError;
+
+/// Runs the `#[validate(...)]` rules declared on a type.
+///
+/// Implemented by `#[derive(Validate)]` for plain structs such as request bodies, and by
+/// `#[derive(Model)]` for every model. A field marked `#[validate(nested)]` calls it on the
+/// embedded value. It can also be implemented by hand.
+pub trait Validate {
+    /// Validates the current instance against its `#[validate(...)]` rules.
+    ///
+    /// `Model::save()` calls this automatically before inserting, so you only need to call it
+    /// yourself when you want to check a value without persisting it (e.g. validating
+    /// a request payload).
+    ///
+    /// # Returns
+    /// - `Ok(())` if every rule passes.
+    /// - [`OximodError::ValidationError`](crate::error::oximod_error::OximodError::ValidationError) describing the first rule that failed.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// let user = User::new().name("ab".to_string());
+    /// if let Err(e) = user.validate() {
+    ///     println!("Invalid payload: {}", e);
+    /// }
+    /// ```
+    fn validate(&self) -> Result<(), OximodError>;
+
+    #[doc(hidden)]
+    /// Validates `self` as the embedded value at `path` (e.g. `"address."`), so derived
+    /// messages name the full field path. Hand-written impls ignore the path by default.
+    fn validate_at(&self, path: &str) -> Result<(), OximodError> {
+        let _ = path;
+        self.validate()
+    }
+}
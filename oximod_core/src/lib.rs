@@ -1,5 +1,9 @@
 /// Macro for attaching `Printable` info to an error with an optional suggestion message.
 ///
+/// The backtrace and suggestion are only captured and printed when backtraces are enabled via
+/// `RUST_LIB_BACKTRACE` or `RUST_BACKTRACE`; otherwise the error is returned untouched, so hot
+/// paths such as rejected forms don't pay for them.
+///
 /// # Example
 /// ```ignore
 /// return Err(attach!(MyError::Something, "Check your DB connection"));
@@ -7,13 +11,111 @@
 #[macro_export]
 macro_rules! attach_printables {
     ($err:expr, $msg:expr) => {
-        $err.attach_printables(std::backtrace::Backtrace::capture(), Some($msg))
+        {
+            let err = $err;
+            if $crate::error::printable::printables_enabled() {
+                err.attach_printables(std::backtrace::Backtrace::capture(), Some($msg))
+            } else {
+                err
+            }
+        }
     };
     ($err:expr) => {
-        $err.attach_printables(std::backtrace::Backtrace::capture(), None)
+        {
+            let err = $err;
+            if $crate::error::printable::printables_enabled() {
+                err.attach_printables(std::backtrace::Backtrace::capture(), None)
+            } else {
+                err
+            }
+        }
+    };
+}
+
+/// Runs a generated CRUD method body inside a `tracing` span recording the collection,
+/// operation, and elapsed time, and emits an error event if it fails.
+///
+/// Without the `tracing` feature this expands to the body itself, so it compiles out entirely.
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! instrument {
+    ($collection:expr, $operation:expr, $body:block) => {
+        {
+            use $crate::_tracing::Instrument;
+
+            let span = $crate::_tracing::info_span!(
+                "oximod",
+                collection = $collection,
+                operation = $operation,
+                elapsed_ms = $crate::_tracing::field::Empty
+            );
+            let start = std::time::Instant::now();
+            let result: Result<_, $crate::error::oximod_error::OximodError> = async move $body
+                .instrument(span.clone()).await;
+            span.record("elapsed_ms", start.elapsed().as_millis() as u64);
+            if let Err(e) = &result {
+                $crate::_tracing::error!(parent: &span, error = %e, "oximod operation failed");
+            }
+            result
+        }
+    };
+}
+
+/// Runs a generated CRUD method body. The `tracing` feature wraps it in a span.
+#[cfg(not(feature = "tracing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! instrument {
+    ($collection:expr, $operation:expr, $body:block) => {
+        $body
+    };
+}
+
+/// Emits a `tracing` warning for destructive operations such as `clear()`.
+///
+/// Without the `tracing` feature this expands to nothing, so library code never prints.
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! warning {
+    ($($arg:tt)*) => {
+        $crate::_tracing::warn!($($arg)*)
     };
 }
 
+/// Without the `tracing` feature there is nothing to emit to, so this expands to nothing.
+#[cfg(not(feature = "tracing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! warning {
+    ($($arg:tt)*) => {};
+}
+
+/// Runs the given statements, which must `return`, in place of a generated method's server
+/// call while the in-memory backend of the `mock` feature is on.
+#[cfg(feature = "mock")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! mock {
+    ($($body:tt)*) => {
+        if $crate::feature::conn::mock::is_enabled() {
+            $($body)*
+        }
+    };
+}
+
+/// Without the `mock` feature there is no in-memory backend, so this expands to nothing.
+#[cfg(not(feature = "mock"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! mock {
+    ($($body:tt)*) => {};
+}
+
 pub mod error;
 pub mod feature;
-pub use error::printable::Printable;
\ No newline at end of file
+pub use error::printable::Printable;
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+pub use tracing as _tracing;
@@ -1,23 +1,81 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{ Attribute, GenericArgument, LitStr, PathArguments, Type, Ident };
+use syn::{ parse::ParseStream, Attribute, GenericArgument, LitStr, PathArguments, Token, Type, Ident };
 pub struct DefaultDefinition {
     pub field_ident: syn::Ident,
     pub default_expr: proc_macro2::TokenStream,
 }
 
+/// Parses `#[default(...)]`.
+///
+/// Accepts any expression, e.g. `#[default(Status::Pending)]`, `#[default(42 + 5)]`, or
+/// `#[default(ObjectId::new())]`, and `#[default(fn = "path::to::factory")]`, which calls a
+/// zero-argument function. The expression is pasted into the generated `new()`, so calls
+/// run again on every `new()`/`default()` rather than being evaluated once.
 pub fn parse_default_args(
     attr: &Attribute,
     field_ident: &syn::Ident
 ) -> syn::Result<DefaultDefinition> {
-    // Accept #[default(Status::Pending)] or #[default(42 + 5)]
-    let expr: syn::Expr = attr.parse_args()?;
+    let default_expr = attr.parse_args_with(|input: ParseStream| {
+        if input.peek(Token![fn]) {
+            input.parse::<Token![fn]>()?;
+            input.parse::<Token![=]>()?;
+            let factory: LitStr = input.parse()?;
+            let path: syn::Path = factory.parse()?;
+            Ok(quote! { #path() })
+        } else {
+            let expr: syn::Expr = input.parse()?;
+            Ok(quote! { #expr })
+        }
+    })?;
+
+    Ok(DefaultDefinition {
+        field_ident: field_ident.clone(),
+        default_expr,
+    })
+}
+
+/// Parses `#[default_now]`, which defaults a `DateTime` or `Option<DateTime>` field to the
+/// time `new()` is called.
+pub fn parse_default_now_args(
+    attr: &Attribute,
+    field_ident: &syn::Ident,
+    field_ty: &Type
+) -> syn::Result<DefaultDefinition> {
+    attr.meta.require_path_only()?;
+
+    let default_expr = if is_bson_date_time(field_ty) {
+        quote! { ::oximod::_mongodb::bson::DateTime::now() }
+    } else if option_inner_type(field_ty).is_some_and(is_bson_date_time) {
+        quote! { Some(::oximod::_mongodb::bson::DateTime::now()) }
+    } else {
+        return Err(
+            syn::Error::new_spanned(
+                field_ty,
+                "#[default_now] can only be used on BSON `DateTime` or `Option<DateTime>` fields"
+            )
+        );
+    };
+
     Ok(DefaultDefinition {
         field_ident: field_ident.clone(),
-        default_expr: quote! { #expr },
+        default_expr,
     })
 }
 
+/// Whether `ty` is a `DateTime`, BSON's or chrono's, judged by the last path segment.
+pub fn is_date_time(ty: &Type) -> bool {
+    matches!(ty, Type::Path(type_path) if type_path.path.segments.last().is_some_and(|s| s.ident == "DateTime"))
+}
+
+/// Whether `ty` is BSON's `DateTime`, i.e. a `DateTime` without chrono's `<Tz>` argument.
+fn is_bson_date_time(ty: &Type) -> bool {
+    matches!(
+        ty,
+        Type::Path(type_path) if type_path.path.segments.last().is_some_and(|s| s.ident == "DateTime" && s.arguments.is_empty())
+    )
+}
+
 /// If `ty` is `Option<Inner>`, returns `Some(&Inner)`, otherwise `None`.
 pub fn option_inner_type(ty: &Type) -> Option<&Type> {
     // We only care about a simple `Option<...>` path type
@@ -43,11 +101,11 @@ pub fn option_inner_type(ty: &Type) -> Option<&Type> {
 }
 
 pub fn maybe_push_id_setter(
-    has_id_attr: bool,
+    id_type: Option<&Type>,
     input_attrs: &[Attribute],
     setters: &mut Vec<TokenStream>
 ) {
-    if has_id_attr {
+    if let Some(id_type) = id_type {
         let mut id_setter_name = "id".to_string();
 
         for attr in input_attrs {
@@ -60,18 +118,36 @@ pub fn maybe_push_id_setter(
         }
 
         let id_method_ident = syn::Ident::new(&id_setter_name, proc_macro2::Span::call_site());
-        let id_setter =
-            quote! {
-                /// Set the MongoDB ObjectId
-                pub fn #id_method_ident(mut self, id: ::oximod::_mongodb::bson::oid::ObjectId) -> Self {
+        // `Option<ObjectId>` unless `#[model(custom_id = true)]` allows another type.
+        let id_setter = match option_inner_type(id_type) {
+            Some(inner) =>
+                quote! {
+                /// Set the MongoDB document id
+                pub fn #id_method_ident(mut self, id: #inner) -> Self {
                     self._id = Some(id);
                     self
                 }
-            };
+            },
+            None =>
+                quote! {
+                /// Set the MongoDB document id
+                pub fn #id_method_ident(mut self, id: #id_type) -> Self {
+                    self._id = id;
+                    self
+                }
+            },
+        };
         setters.push(id_setter);
     }
 }
 
+/// Whether `ty` is `Option<ObjectId>`, the `_id` type the generated CRUD methods expect.
+pub fn is_option_object_id(ty: &Type) -> bool {
+    option_inner_type(ty).is_some_and(|inner| {
+        matches!(inner, Type::Path(type_path) if type_path.path.segments.last().is_some_and(|s| s.ident == "ObjectId"))
+    })
+}
+
 pub fn push_field_setters(all_fields: &[(Ident, Type)], setters: &mut Vec<TokenStream>) {
     for (ident, ty) in all_fields.iter().filter(|(ident, _)| ident != "_id") {
         let setter = if let Some(inner) = option_inner_type(ty) {
@@ -0,0 +1,44 @@
+use proc_macro2::TokenStream;
+use quote::{ format_ident, quote };
+use syn::{ Ident, Visibility };
+
+use crate::serde_name::to_snake_case;
+
+/// Generates a `<model>_fields` module with one `pub const` per field holding the
+/// key the field is stored under, honoring `#[serde(rename)]` and `rename_all`.
+///
+/// # Example
+///
+/// ```rust
+/// // #[serde(rename = "emailAddress")] email: String
+/// User::find(doc! { user_fields::EMAIL: "a@b.c" }).await?;
+/// // user_fields::EMAIL == "emailAddress"
+/// ```
+pub fn generate_field_constants_tokens(
+    name: &Ident,
+    vis: &Visibility,
+    field_keys: &[(Ident, String)]
+) -> TokenStream {
+    let module = format_ident!("{}_fields", to_snake_case(&name.to_string()));
+    let module_doc = format!("Stored key names of the fields of [`{}`].", name);
+
+    let constants = field_keys.iter().map(|(ident, key)| {
+        let constant = format_ident!(
+            "{}",
+            ident.to_string().trim_start_matches("r#").to_ascii_uppercase()
+        );
+        let doc = format!("The stored key of `{}`.", ident.to_string().trim_start_matches("r#"));
+        quote! {
+            #[doc = #doc]
+            pub const #constant: &str = #key;
+        }
+    });
+
+    quote! {
+        #[doc = #module_doc]
+        #[allow(dead_code)]
+        #vis mod #module {
+            #(#constants)*
+        }
+    }
+}
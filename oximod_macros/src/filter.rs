@@ -0,0 +1,227 @@
+use proc_macro2::{ Span, TokenStream };
+use quote::{ format_ident, quote };
+use syn::{ Expr, ExprStruct, Ident, Member, PathArguments, Visibility };
+
+use crate::serde_name::to_snake_case;
+
+/// The operators `filter!` accepts as `field: op(value)`, named like the `FilterField` methods.
+const OPERATORS: [(&str, &str); 7] = [
+    ("eq", "$eq"),
+    ("ne", "$ne"),
+    ("gt", "$gt"),
+    ("gte", "$gte"),
+    ("lt", "$lt"),
+    ("lte", "$lte"),
+    ("in_", "$in"),
+];
+
+/// The methods of every `<Model>Filter`, which a field method of the same name would clash with.
+const RESERVED: [&str; 4] = ["new", "and", "or", "build"];
+
+/// One `field: value` of `filter!`: the operator, or `None` for equality, and its value.
+type Condition = (Option<&'static str>, Expr);
+
+/// Generates the typed `<Model>Filter` query builder, started with `<Model>Filter::new()`.
+///
+/// Each field gets a method of the same name returning a `FilterField`, so a misspelled
+/// field is a compile error instead of a filter that silently matches nothing. The
+/// conditions use the key the field is stored under, honoring serde renames. A field named
+/// like one of the builder's own methods (`new`, `and`, `or`, `build`) is a compile error.
+///
+/// # Example
+///
+/// ```rust
+/// let filter = UserFilter::new().name().eq("Alice").age().gte(18).build();
+/// // { "name": { "$eq": "Alice" }, "age": { "$gte": 18 } }
+/// ```
+pub fn generate_filter_tokens(
+    name: &Ident,
+    vis: &Visibility,
+    field_keys: &[(Ident, String)]
+) -> syn::Result<TokenStream> {
+    if let Some((ident, _)) = field_keys.iter().find(|(ident, _)| RESERVED.contains(&ident.to_string().as_str())) {
+        return Err(
+            syn::Error::new_spanned(
+                ident,
+                format!(
+                    "field `{}` clashes with the `{}Filter::{}` method; rename the field and keep its stored key with #[serde(rename = \"{}\")]",
+                    ident,
+                    name,
+                    ident,
+                    ident
+                )
+            )
+        );
+    }
+
+    let filter_name = format_ident!("{}Filter", name);
+    let doc = format!("Typed query builder for [`{}`], created with `{}::new()`.", name, filter_name);
+
+    let field_methods = field_keys.iter().map(|(ident, key)| {
+        quote! {
+            pub fn #ident(self) -> ::oximod::_feature::model::filter::FilterField<Self> {
+                ::oximod::_feature::model::filter::FilterField::new(self, #key)
+            }
+        }
+    });
+
+    Ok(
+        quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Default)]
+        #vis struct #filter_name(::oximod::_feature::model::filter::Filter);
+
+        impl ::oximod::_feature::model::filter::FilterBuilder for #filter_name {
+            fn filter_mut(&mut self) -> &mut ::oximod::_feature::model::filter::Filter {
+                &mut self.0
+            }
+        }
+
+        impl #filter_name {
+            /// Starts an empty filter, which matches every document.
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            #(#field_methods)*
+
+            /// Adds a group that matches only if every one of `filters` matches (`$and`).
+            pub fn and(mut self, filters: impl IntoIterator<Item = Self>) -> Self {
+                self.0.and(filters.into_iter().map(Self::build));
+                self
+            }
+
+            /// Adds a group that matches if any of `filters` matches (`$or`).
+            pub fn or(mut self, filters: impl IntoIterator<Item = Self>) -> Self {
+                self.0.or(filters.into_iter().map(Self::build));
+                self
+            }
+
+            /// Produces the query document to pass to `find`, `update`, `delete`, etc.
+            pub fn build(self) -> ::oximod::_mongodb::bson::Document {
+                self.0.build()
+            }
+        }
+
+        impl From<#filter_name> for ::oximod::_mongodb::bson::Document {
+            fn from(filter: #filter_name) -> Self {
+                filter.build()
+            }
+        }
+    }
+    )
+}
+
+/// Expands `filter!(User { name: "Alice", age: gte(18) })` into a query document.
+///
+/// Each key is looked up in the `<model>_fields` module generated by `#[derive(Model)]`, so a
+/// misspelled or `#[serde(skip)]` field fails to compile, and renamed fields use their stored
+/// key. A plain value matches by equality; `eq`, `ne`, `gt`, `gte`, `lt`, `lte`, and `in_` calls
+/// become operators. A field may be repeated to combine operators.
+///
+/// # Example
+///
+/// ```rust
+/// // filter!(User { name: "Alice", age: gte(18), age: lt(65) })
+/// // { "name": "Alice", "age": { "$gte": 18, "$lt": 65 } }
+/// ```
+pub fn expand_filter_macro(input: ExprStruct) -> syn::Result<TokenStream> {
+    if let Some(dot2) = &input.dot2_token {
+        return Err(syn::Error::new_spanned(dot2, "`..` is not supported in filter!"));
+    }
+
+    let mut module = input.path.clone();
+    let Some(last) = module.segments.last_mut() else {
+        return Err(syn::Error::new_spanned(&input.path, "expected a model name"));
+    };
+    last.ident = Ident::new(&format!("{}_fields", to_snake_case(&last.ident.to_string())), last.ident.span());
+    last.arguments = PathArguments::None;
+
+    // Conditions grouped by field, in the order each field first appears.
+    let mut groups: Vec<(Ident, Vec<Condition>)> = Vec::new();
+    for field in &input.fields {
+        let Member::Named(ident) = &field.member else {
+            return Err(syn::Error::new_spanned(&field.member, "filter! expects named fields"));
+        };
+        let condition = operator_call(&field.expr).unwrap_or((None, field.expr.clone()));
+        match groups.iter_mut().find(|(existing, _)| existing == ident) {
+            Some((_, conditions)) => conditions.push(condition),
+            None => groups.push((ident.clone(), vec![condition])),
+        }
+    }
+
+    // Mixed-site names can't shadow variables used in the values.
+    let filter = Ident::new("filter", Span::mixed_site());
+    let ops = Ident::new("ops", Span::mixed_site());
+    let mut inserts = Vec::with_capacity(groups.len());
+    for (ident, conditions) in &groups {
+        let name = ident.to_string();
+        let constant = Ident::new(&name.trim_start_matches("r#").to_ascii_uppercase(), ident.span());
+        let key = quote! { #module::#constant };
+
+        if let [(None, value)] = conditions.as_slice() {
+            inserts.push(quote! {
+                #filter.insert(#key, ::oximod::_mongodb::bson::Bson::from(#value));
+            });
+            continue;
+        }
+
+        if conditions.iter().any(|(op, _)| op.is_none()) {
+            return Err(
+                syn::Error::new_spanned(
+                    ident,
+                    format!(
+                        "`{}` appears more than once; repeat a field only with operators, e.g. `age: gte(18), age: lt(65)`",
+                        name
+                    )
+                )
+            );
+        }
+
+        let op_inserts = conditions.iter().map(|(op, value)| {
+            let op = op.unwrap_or_default();
+            if op == "$in" {
+                quote! {
+                    #ops.insert(#op, ::oximod::_mongodb::bson::Bson::Array(
+                        ::std::iter::IntoIterator::into_iter(#value).map(::std::convert::Into::into).collect()
+                    ));
+                }
+            } else {
+                quote! { #ops.insert(#op, ::oximod::_mongodb::bson::Bson::from(#value)); }
+            }
+        });
+        inserts.push(quote! {
+            #filter.insert(#key, {
+                let mut #ops = ::oximod::_mongodb::bson::Document::new();
+                #(#op_inserts)*
+                #ops
+            });
+        });
+    }
+
+    Ok(
+        quote! {
+        {
+            let mut #filter = ::oximod::_mongodb::bson::Document::new();
+            #(#inserts)*
+            #filter
+        }
+    }
+    )
+}
+
+/// Splits `gt(value)` into its operator and argument, if `expr` is a call to one of [`OPERATORS`].
+fn operator_call(expr: &Expr) -> Option<Condition> {
+    let Expr::Call(call) = expr else {
+        return None;
+    };
+    let Expr::Path(func) = call.func.as_ref() else {
+        return None;
+    };
+    let name = func.path.get_ident()?.to_string();
+    let (_, op) = OPERATORS.iter().find(|(method, _)| *method == name)?;
+    if call.args.len() != 1 {
+        return None;
+    }
+    Some((Some(*op), call.args[0].clone()))
+}
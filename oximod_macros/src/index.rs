@@ -1,6 +1,6 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{ Attribute, Lit };
+use syn::{ meta::ParseNestedMeta, Attribute, Lit, LitInt, LitStr, Token };
 
 #[derive(Default, Debug)]
 /// Arguments for creating an index on a field in a MongoDB collection.
@@ -32,8 +32,35 @@ use syn::{ Attribute, Lit };
 ///
 /// - `expire_after_secs`: (Optional) The time-to-live (TTL) for the index.
 ///   - If set, documents will be automatically deleted after the specified number of seconds.
+///   - `0` expires each document at the date stored in the field itself.
+///   - At most `2147483647` (about 68 years), the largest value MongoDB accepts. Larger values
+///     are a compile error on the literal.
+///   - MongoDB's TTL monitor runs about once a minute, so documents can outlive their
+///     expiry by up to that long.
 ///   - If not provided, documents will not automatically expire.
 ///
+/// - `text`: (Optional) Whether the field gets a full-text index (`{ field: "text" }`).
+///   - If `true`, `order` is ignored and the field can be queried with `$text`.
+///   - Default: `false`
+///
+/// - `default_language`: (Optional) The default language of a text index.
+///   - Controls stemming and stop words; only meaningful for text indexes.
+///   - If not provided, MongoDB uses `"english"`.
+///
+/// - `hidden`: (Optional) Whether the index is hidden from the query planner.
+///   - If `true`, the index is still maintained but not used by queries, which lets you
+///     measure the impact of dropping it without actually dropping it.
+///   - Default: `false`
+///
+/// - `collation_locale`: (Optional) The ICU locale of the index collation, e.g. `"en"`.
+///   - Required whenever `collation_strength` is set.
+///   - If not provided, the index uses simple binary comparison.
+///
+/// - `collation_strength`: (Optional) The collation strength, from `1` (primary) to `5` (identical).
+///   - Any other value is a compile error on the literal.
+///   - `2` ignores case, which makes `unique` indexes case-insensitive.
+///   - Queries only use the index when they specify the same collation.
+///
 /// # Example
 ///
 /// ```rust
@@ -51,117 +78,378 @@ pub struct IndexArgs {
     pub sparse: Option<bool>,
     pub name: Option<String>,
     pub background: Option<bool>,
+    pub hidden: Option<bool>,
     pub order: Option<i32>,
-    pub expire_after_secs: Option<i32>,
+    pub expire_after_secs: Option<u64>,
+    pub text: Option<bool>,
+    pub default_language: Option<String>,
+    /// Per-field weights, only set by `#[text_index(fields(...))]`.
+    pub weights: Option<Vec<(String, i32)>>,
+    pub collation_locale: Option<String>,
+    pub collation_strength: Option<u8>,
 }
 
 #[derive(Debug)]
 pub struct IndexDefinition {
+    /// The key the field is stored under, after `#[serde(rename)]`/`rename_all`.
     pub field_name: String,
     pub args: IndexArgs,
 }
 
+/// A multi-field index declared on the struct with `#[compound_index(...)]`.
+///
+/// Keys keep the order they were written in, since MongoDB uses that order
+/// when planning queries against the index.
+///
+/// # Example
+///
+/// ```rust
+/// #[compound_index(keys("last_name" = 1, "first_name" = -1), unique, name = "full_name_idx")]
+/// pub struct User { /* ... */ }
+/// ```
+#[derive(Debug)]
+pub struct CompoundIndexDefinition {
+    pub keys: Vec<(String, i32)>,
+    pub args: IndexArgs,
+}
+
+/// A weighted, multi-field text index declared on the struct with `#[text_index(...)]`.
+///
+/// MongoDB allows a single text index per collection, so declare at most one.
+///
+/// # Example
+///
+/// ```rust
+/// #[text_index(fields("title" = 10, "body" = 1), default_language = "english", name = "search_idx")]
+/// pub struct Article { /* ... */ }
+/// ```
+#[derive(Debug)]
+pub struct TextIndexDefinition {
+    pub args: IndexArgs,
+}
+
 pub fn parse_index_args(attr: &Attribute, field_name: String) -> syn::Result<IndexDefinition> {
     let mut args = IndexArgs::default();
 
     if attr.path().is_ident("index") {
-        attr.parse_nested_meta(|meta| {
-            if meta.path.is_ident("unique") {
-                args.unique = Some(true);
-            } else if meta.path.is_ident("sparse") {
-                args.sparse = Some(true);
-            } else if meta.path.is_ident("background") {
-                args.background = Some(true);
-            } else if meta.path.is_ident("name") {
-                let lit: Lit = meta.value()?.parse()?;
-                if let Lit::Str(lit_str) = lit {
-                    args.name = Some(lit_str.value());
-                }
-            } else if meta.path.is_ident("order") {
-                let lit: Lit = meta.value()?.parse()?;
-                let order_val = match lit {
-                    Lit::Int(lit_int) => lit_int.base10_parse::<i32>()?,
-                    Lit::Str(lit_str) =>
-                        lit_str
-                            .value()
-                            .parse::<i32>()
-                            .map_err(|e|
-                                syn::Error::new(
-                                    lit_str.span(),
-                                    format!("could not parse order: {}", e)
-                                )
-                            )?,
-                    other => {
-                        return Err(
-                            syn::Error::new(
-                                other.span(),
-                                "expected integer literal or string literal for `order`"
-                            )
-                        );
-                    }
-                };
-                args.order = Some(order_val);
-            } else if meta.path.is_ident("expire_after_secs") {
-                let lit: Lit = meta.value()?.parse()?;
-                if let Lit::Int(lit_int) = lit {
-                    args.expire_after_secs = Some(lit_int.base10_parse::<i32>()?);
-                } else {
-                    return Err(
+        attr.parse_nested_meta(|meta| parse_index_option(&meta, &mut args))?;
+        check_collation(attr, &args)?;
+    }
+
+    Ok(IndexDefinition { field_name, args })
+}
+
+pub fn parse_compound_index_args(attr: &Attribute) -> syn::Result<CompoundIndexDefinition> {
+    let mut keys = Vec::new();
+    let mut args = IndexArgs::default();
+
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("keys") {
+            keys = parse_key_list(&meta)?;
+            Ok(())
+        } else if meta.path.is_ident("order") {
+            Err(meta.error("set the order of each key inside `keys(...)` instead"))
+        } else {
+            parse_index_option(&meta, &mut args)
+        }
+    })?;
+
+    check_collation(attr, &args)?;
+
+    if keys.is_empty() {
+        return Err(
+            syn::Error::new_spanned(
+                attr,
+                "Expected #[compound_index(keys(\"field_a\" = 1, \"field_b\" = -1), ...)]"
+            )
+        );
+    }
+
+    Ok(CompoundIndexDefinition { keys, args })
+}
+
+pub fn parse_text_index_args(attr: &Attribute) -> syn::Result<TextIndexDefinition> {
+    let mut args = IndexArgs::default();
+
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("fields") {
+            args.weights = Some(parse_key_list(&meta)?);
+            Ok(())
+        } else if meta.path.is_ident("order") {
+            Err(meta.error("text indexes have no order; use `fields(\"field\" = weight)`"))
+        } else {
+            parse_index_option(&meta, &mut args)
+        }
+    })?;
+
+    if args.weights.as_ref().is_none_or(|weights| weights.is_empty()) {
+        return Err(
+            syn::Error::new_spanned(
+                attr,
+                "Expected #[text_index(fields(\"field_a\" = 10, \"field_b\" = 1), ...)]"
+            )
+        );
+    }
+
+    check_collation(attr, &args)?;
+
+    args.text = Some(true);
+    Ok(TextIndexDefinition { args })
+}
+
+fn check_collation(attr: &Attribute, args: &IndexArgs) -> syn::Result<()> {
+    if args.collation_strength.is_some() && args.collation_locale.is_none() {
+        return Err(
+            syn::Error::new_spanned(
+                attr,
+                "`collation_strength` requires `collation_locale`, e.g. collation_locale = \"en\""
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Parses a parenthesized list of `"field" = N` pairs, keeping their order.
+fn parse_key_list(meta: &ParseNestedMeta) -> syn::Result<Vec<(String, i32)>> {
+    let mut keys = Vec::new();
+    let content;
+    syn::parenthesized!(content in meta.input);
+    while !content.is_empty() {
+        let key: LitStr = content.parse()?;
+        content.parse::<Token![=]>()?;
+        let negative = content.parse::<Option<Token![-]>>()?.is_some();
+        let value = content.parse::<LitInt>()?.base10_parse::<i32>()?;
+        keys.push((key.value(), if negative { -value } else { value }));
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+    }
+    Ok(keys)
+}
+
+fn parse_index_option(meta: &ParseNestedMeta, args: &mut IndexArgs) -> syn::Result<()> {
+    if meta.path.is_ident("unique") {
+        args.unique = Some(true);
+    } else if meta.path.is_ident("sparse") {
+        args.sparse = Some(true);
+    } else if meta.path.is_ident("background") {
+        args.background = Some(true);
+    } else if meta.path.is_ident("hidden") {
+        args.hidden = Some(true);
+    } else if meta.path.is_ident("text") {
+        args.text = Some(true);
+    } else if meta.path.is_ident("collation_locale") {
+        let lit: Lit = meta.value()?.parse()?;
+        if let Lit::Str(lit_str) = lit {
+            args.collation_locale = Some(lit_str.value());
+        } else {
+            return Err(
+                syn::Error::new(lit.span(), "expected string literal for `collation_locale`")
+            );
+        }
+    } else if meta.path.is_ident("collation_strength") {
+        let lit: Lit = meta.value()?.parse()?;
+        if let Lit::Int(lit_int) = lit {
+            let strength = lit_int.base10_parse::<u8>()?;
+            if !(1..=5).contains(&strength) {
+                return Err(
+                    syn::Error::new(lit_int.span(), "`collation_strength` must be between 1 and 5")
+                );
+            }
+            args.collation_strength = Some(strength);
+        } else {
+            return Err(
+                syn::Error::new(lit.span(), "expected integer literal for `collation_strength`")
+            );
+        }
+    } else if meta.path.is_ident("default_language") {
+        let lit: Lit = meta.value()?.parse()?;
+        if let Lit::Str(lit_str) = lit {
+            args.default_language = Some(lit_str.value());
+        } else {
+            return Err(
+                syn::Error::new(lit.span(), "expected string literal for `default_language`")
+            );
+        }
+    } else if meta.path.is_ident("name") {
+        let lit: Lit = meta.value()?.parse()?;
+        if let Lit::Str(lit_str) = lit {
+            args.name = Some(lit_str.value());
+        }
+    } else if meta.path.is_ident("order") {
+        let lit: Lit = meta.value()?.parse()?;
+        let order_val = match lit {
+            Lit::Int(lit_int) => lit_int.base10_parse::<i32>()?,
+            Lit::Str(lit_str) =>
+                lit_str
+                    .value()
+                    .parse::<i32>()
+                    .map_err(|e|
                         syn::Error::new(
-                            lit.span(),
-                            "expected integer literal for `expire_after_secs`"
+                            lit_str.span(),
+                            format!("could not parse order: {}", e)
                         )
-                    );
-                }
+                    )?,
+            other => {
+                return Err(
+                    syn::Error::new(
+                        other.span(),
+                        "expected integer literal or string literal for `order`"
+                    )
+                );
             }
-            Ok(())
-        })?;
+        };
+        args.order = Some(order_val);
+    } else if meta.path.is_ident("expire_after_secs") {
+        let lit: Lit = meta.value()?.parse()?;
+        if let Lit::Int(lit_int) = lit {
+            let secs = lit_int.base10_parse::<u64>()?;
+            if secs > (i32::MAX as u64) {
+                return Err(
+                    syn::Error::new(
+                        lit_int.span(),
+                        "`expire_after_secs` must be at most 2147483647, the largest TTL MongoDB accepts"
+                    )
+                );
+            }
+            args.expire_after_secs = Some(secs);
+        } else {
+            return Err(
+                syn::Error::new(
+                    lit.span(),
+                    "expected integer literal for `expire_after_secs`"
+                )
+            );
+        }
     }
-
-    Ok(IndexDefinition { field_name, args })
+    Ok(())
 }
 
 pub fn generate_index_model_tokens(index_def: &IndexDefinition) -> TokenStream {
     let field = &index_def.field_name;
-    let order = index_def.args.order.unwrap_or(1);
+    let key = match index_def.args.text {
+        Some(true) => quote! { "text" },
+        _ => {
+            let order = index_def.args.order.unwrap_or(1);
+            quote! { #order }
+        }
+    };
+    let options = generate_index_options_tokens(&index_def.args);
+
+    quote! {
+        ::oximod::_mongodb::IndexModel::builder()
+            .keys(::oximod::_mongodb::bson::doc! { #field: #key })
+            .options(#options)
+            .build()
+    }
+}
 
-    let unique = match index_def.args.unique {
+pub fn generate_compound_index_model_tokens(index_def: &CompoundIndexDefinition) -> TokenStream {
+    let fields = index_def.keys.iter().map(|(field, _)| field);
+    let orders = index_def.keys.iter().map(|(_, order)| order);
+    let options = generate_index_options_tokens(&index_def.args);
+
+    quote! {
+        ::oximod::_mongodb::IndexModel::builder()
+            .keys(::oximod::_mongodb::bson::doc! { #(#fields: #orders),* })
+            .options(#options)
+            .build()
+    }
+}
+
+pub fn generate_text_index_model_tokens(index_def: &TextIndexDefinition) -> TokenStream {
+    let fields = index_def.args.weights
+        .iter()
+        .flatten()
+        .map(|(field, _)| field);
+    let options = generate_index_options_tokens(&index_def.args);
+
+    quote! {
+        ::oximod::_mongodb::IndexModel::builder()
+            .keys(::oximod::_mongodb::bson::doc! { #(#fields: "text"),* })
+            .options(#options)
+            .build()
+    }
+}
+
+fn generate_index_options_tokens(args: &IndexArgs) -> TokenStream {
+    let unique = match args.unique {
+        Some(val) => quote! { Some(#val) },
+        None => quote! { None },
+    };
+
+    let sparse = match args.sparse {
         Some(val) => quote! { Some(#val) },
         None => quote! { None },
     };
 
-    let sparse = match index_def.args.sparse {
+    let background = match args.background {
         Some(val) => quote! { Some(#val) },
         None => quote! { None },
     };
 
-    let background = match index_def.args.background {
+    let hidden = match args.hidden {
         Some(val) => quote! { Some(#val) },
         None => quote! { None },
     };
 
-    let name = match &index_def.args.name {
+    let name = match &args.name {
         Some(val) => quote! { Some(#val.to_string()) },
         None => quote! { None },
     };
 
-    let expire_after_secs = match index_def.args.expire_after_secs {
-        Some(secs) => quote! { Some(::std::time::Duration::from_secs(#secs as u64)) },
+    let expire_after_secs = match args.expire_after_secs {
+        Some(secs) => quote! { Some(::std::time::Duration::from_secs(#secs)) },
+        None => quote! { None },
+    };
+
+    let default_language = match &args.default_language {
+        Some(val) => quote! { Some(#val.to_string()) },
+        None => quote! { None },
+    };
+
+    let weights = match &args.weights {
+        Some(weights) => {
+            let fields = weights.iter().map(|(field, _)| field);
+            let values = weights.iter().map(|(_, weight)| weight);
+            quote! { Some(::oximod::_mongodb::bson::doc! { #(#fields: #values),* }) }
+        }
+        None => quote! { None },
+    };
+
+    let collation = match &args.collation_locale {
+        Some(locale) => {
+            let strength = match args.collation_strength {
+                Some(1) => quote! { Some(::oximod::_mongodb::options::CollationStrength::Primary) },
+                Some(2) => quote! { Some(::oximod::_mongodb::options::CollationStrength::Secondary) },
+                Some(3) => quote! { Some(::oximod::_mongodb::options::CollationStrength::Tertiary) },
+                Some(4) => quote! { Some(::oximod::_mongodb::options::CollationStrength::Quaternary) },
+                Some(_) => quote! { Some(::oximod::_mongodb::options::CollationStrength::Identical) },
+                None => quote! { None },
+            };
+            quote! {
+                Some(
+                    ::oximod::_mongodb::options::Collation::builder()
+                        .locale(#locale)
+                        .strength(#strength)
+                        .build()
+                )
+            }
+        }
         None => quote! { None },
     };
 
     quote! {
-        ::oximod::_mongodb::IndexModel::builder()
-            .keys(::oximod::_mongodb::bson::doc! { #field: #order })
-            .options(
-                ::oximod::_mongodb::options::IndexOptions::builder()
-                    .unique(#unique)
-                    .sparse(#sparse)
-                    .background(#background)
-                    .name(#name)
-                    .expire_after(#expire_after_secs)
-                    .build()
-            )
+        ::oximod::_mongodb::options::IndexOptions::builder()
+            .unique(#unique)
+            .sparse(#sparse)
+            .background(#background)
+            .hidden(#hidden)
+            .name(#name)
+            .expire_after(#expire_after_secs)
+            .default_language(#default_language)
+            .weights(#weights)
+            .collation(#collation)
             .build()
     }
 }
@@ -1,18 +1,67 @@
 mod index;
 mod validate;
 mod default;
+mod model;
+mod filter;
+mod fields;
+mod serde_name;
+mod reference;
+mod normalize;
+mod schema;
+mod sensitive;
+mod update;
+mod patch;
 use std::collections::HashSet;
 
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{ quote, quote_spanned };
+use syn::spanned::Spanned;
 use syn::{ parse_macro_input, DeriveInput, LitStr };
-use index::{ parse_index_args, generate_index_model_tokens };
-use validate::{ parse_validate_args, generate_validate_model_tokens };
-use default::{ parse_default_args, maybe_push_id_setter, push_field_setters };
+use index::{
+    parse_index_args,
+    parse_compound_index_args,
+    parse_text_index_args,
+    generate_index_model_tokens,
+    generate_compound_index_model_tokens,
+    generate_text_index_model_tokens,
+};
+use validate::{ generate_validate_impl_tokens, generate_validate_unique_tokens, parse_unique_fields };
+use default::{
+    is_option_object_id,
+    parse_default_args,
+    parse_default_now_args,
+    maybe_push_id_setter,
+    push_field_setters,
+};
+use model::{ parse_collection_args, parse_db_args, parse_model_args, CappedArgs, ModelArgs };
+use filter::{ expand_filter_macro, generate_filter_tokens };
+use update::generate_update_tokens;
+use patch::generate_patch_tokens;
+use fields::generate_field_constants_tokens;
+use serde_name::{ has_serde_default, is_skipped, parse_rename_all, serde_codec_attrs, serialized_name };
+use reference::{ parse_reference_args, generate_populate_tokens };
+use normalize::{ parse_normalize_args, generate_normalize_tokens };
+use schema::generate_json_schema_tokens;
+use sensitive::generate_debug_tokens;
 
 #[proc_macro_derive(
     Model,
-    attributes(db, collection, index, validate, default, document_id_setter_ident)
+    attributes(
+        db,
+        collection,
+        model,
+        index,
+        compound_index,
+        text_index,
+        validate,
+        default,
+        default_now,
+        reference,
+        sensitive,
+        skip_if_default,
+        normalize,
+        document_id_setter_ident
+    )
 )]
 /// Procedural macro to derive the `Model` trait for mongodb schema support.
 ///
@@ -22,7 +71,42 @@ use default::{ parse_default_args, maybe_push_id_setter, push_field_setters };
 /// # Required Attributes
 ///
 /// - `#[db("your_database_name")]`: Specifies the database name.
-/// - `#[collection("your_collection_name")]`: Specifies the collection name.
+/// - `#[collection("your_collection_name")]`: Specifies the collection name. Add
+///   `capped(size = N, max = M)` to create it as a capped collection before the first insert.
+///
+/// # Optional Struct Attributes
+///
+/// - `#[model(auto_create_indexes = false)]`: Stops `save()` from creating indexes on every insert.
+///   Call `Model::sync_indexes()` once at startup instead.
+/// - `#[model(read_preference = "...", write_concern = "...")]`: Sets collection-wide defaults.
+/// - `#[model(allow_clear = false)]`: Makes calling `clear()` or `drop_collection()` on the model a
+///   compile error.
+/// - `#[model(json_schema = true)]`: Makes `sync_indexes()` install a `$jsonSchema` validator
+///   generated from the field types and `#[validate]` rules.
+/// - `#[model(custom_id = true)]`: Allows an `_id` type other than `Option<ObjectId>`, which is
+///   otherwise a compile error. `save()` then fails with `SerializationError` for non-`ObjectId`
+///   ids, so insert such models through `get_collection()`.
+/// - `#[compound_index(keys("field_a" = 1, "field_b" = -1), unique, name = "...")]`: Declares a
+///   multi-field index. Repeat the attribute to declare several.
+/// - `#[text_index(fields("title" = 10, "body" = 1), default_language = "english")]`: Declares a
+///   weighted full-text index over several fields.
+/// - `#[validate(with = "path::to::fn")]`: Runs a custom validation function on the whole model.
+///
+/// Field-level `#[sensitive]` prints the field as `"***"` in a generated `Debug` impl, so
+/// logging a model doesn't leak secrets. Don't also derive `Debug` on a model with sensitive
+/// fields; the two impls conflict.
+///
+/// Field-level `#[skip_if_default]` leaves the field out of stored documents while it equals
+/// `Default::default()`. The field type must be `PartialEq + Default`, and the field needs
+/// `#[serde(default)]` so documents without the key still deserialize.
+///
+/// Field-level `#[normalize(trim, lowercase, uppercase)]` rewrites a `String` or
+/// `Option<String>` field in the document written by `save()` and friends, applying the
+/// transforms in the order written. The struct itself is not modified. Field rules in
+/// `validate()` check the normalized value, so they agree with what is stored.
+///
+/// Field-level `#[validate(nested)]` also runs the rules of an embedded type that implements
+/// `Validate`, which every derived model does.
 ///
 /// # Example
 ///
@@ -46,32 +130,62 @@ pub fn derive_model(input: TokenStream) -> TokenStream {
     let name = &input.ident;
 
     let mut db: Option<LitStr> = None;
+    let mut client_name: Option<LitStr> = None;
     let mut collection: Option<LitStr> = None;
+    let mut capped = None;
+    let mut model_args = ModelArgs::default();
     let mut index_definitions = Vec::new();
-    let mut validate_definitions = Vec::new();
+    let mut compound_index_definitions = Vec::new();
+    let mut text_index_definitions = Vec::new();
     let mut default_definitions = Vec::new();
+    let mut reference_definitions = Vec::new();
     let mut all_fields: Vec<(syn::Ident, syn::Type)> = Vec::new();
-    let mut has_id_attr = false;
+    let mut field_keys: Vec<(syn::Ident, String)> = Vec::new();
+    let mut serialize_codecs = Vec::new();
+    let rename_all = parse_rename_all(&input.attrs);
+    let mut id_type = None;
+    let mut skip_if_default_fields = Vec::new();
+    let mut normalize_definitions = Vec::new();
     let mut setters = Vec::new();
 
     for attr in &input.attrs {
         if attr.path().is_ident("db") {
-            if let Ok(val) = attr.parse_args::<LitStr>() {
-                db = Some(val);
-            } else {
-                return syn::Error
-                    ::new_spanned(attr, "Expected #[db(\"db_name\"]")
-                    .to_compile_error()
-                    .into();
+            match parse_db_args(attr) {
+                Ok((val, client)) => {
+                    db = Some(val);
+                    client_name = client;
+                }
+                Err(e) => {
+                    return e.to_compile_error().into();
+                }
             }
         } else if attr.path().is_ident("collection") {
-            if let Ok(val) = attr.parse_args::<LitStr>() {
-                collection = Some(val);
-            } else {
-                return syn::Error
-                    ::new_spanned(attr, "Expected #[collection(\"collection_name\"]")
-                    .to_compile_error()
-                    .into();
+            match parse_collection_args(attr) {
+                Ok((val, capped_args)) => {
+                    collection = Some(val);
+                    capped = capped_args;
+                }
+                Err(e) => {
+                    return e.to_compile_error().into();
+                }
+            }
+        } else if attr.path().is_ident("model") {
+            if let Err(e) = parse_model_args(attr, &mut model_args) {
+                return e.to_compile_error().into();
+            }
+        } else if attr.path().is_ident("compound_index") {
+            match parse_compound_index_args(attr) {
+                Ok(compound_index) => compound_index_definitions.push(compound_index),
+                Err(e) => {
+                    return e.to_compile_error().into();
+                }
+            }
+        } else if attr.path().is_ident("text_index") {
+            match parse_text_index_args(attr) {
+                Ok(text_index) => text_index_definitions.push(text_index),
+                Err(e) => {
+                    return e.to_compile_error().into();
+                }
             }
         }
     }
@@ -86,6 +200,11 @@ pub fn derive_model(input: TokenStream) -> TokenStream {
         }
     };
 
+    let get_client = match &client_name {
+        Some(name) => quote! { ::oximod::_feature::conn::client::get_client(#name)? },
+        None => quote! { ::oximod::_feature::conn::client::get_global_client()? },
+    };
+
     let collection = match collection {
         Some(val) => val,
         None => {
@@ -96,44 +215,273 @@ pub fn derive_model(input: TokenStream) -> TokenStream {
         }
     };
 
+    let collection_with_options = if
+        model_args.read_preference.is_none() &&
+        model_args.write_concern.is_none()
+    {
+        quote! { Ok(db.collection::<::oximod::_mongodb::bson::Document>(name)) }
+    } else {
+        let read_preference = model_args.read_preference
+            .iter()
+            .map(|rp| quote! { options.selection_criteria = Some(#rp); });
+        let write_concern = model_args.write_concern
+            .iter()
+            .map(|wc| quote! { options.write_concern = Some(#wc); });
+        quote! {
+            let mut options = ::oximod::_mongodb::options::CollectionOptions::default();
+            #(#read_preference)*
+            #(#write_concern)*
+            Ok(db.collection_with_options::<::oximod::_mongodb::bson::Document>(name, options))
+        }
+    };
+
     if let syn::Data::Struct(data_struct) = &input.data {
         for field in data_struct.fields.iter() {
             if let Some(ident) = &field.ident {
                 all_fields.push((ident.clone(), field.ty.clone()));
+                let key = serialized_name(field, rename_all.as_deref());
+                // A `#[serde(skip)]` field is never stored, so it gets no filter, update, or key constant.
+                let skipped = is_skipped(&field.attrs);
+                if key == "_id" && model_args.custom_id != Some(true) && !is_option_object_id(&field.ty) {
+                    return syn::Error
+                        ::new_spanned(
+                            &field.ty,
+                            "`_id` must be `Option<ObjectId>`: `None` lets MongoDB assign the id on insert, and `save()` returns it as an `ObjectId`. To store your own id type, add #[model(custom_id = true)]"
+                        )
+                        .to_compile_error()
+                        .into();
+                }
+                if !skipped {
+                    field_keys.push((ident.clone(), key.clone()));
+                    serialize_codecs.push((ident.clone(), serde_codec_attrs(&field.attrs, true)));
+                }
                 for attr in &field.attrs {
                     let field_name = ident.to_string();
-                    if field_name == "_id".to_string() {
-                        has_id_attr = true;
+                    if field_name == "_id" {
+                        id_type = Some(&field.ty);
+                    }
+                    if
+                        skipped &&
+                        ["index", "default", "default_now", "normalize", "skip_if_default", "reference"]
+                            .iter()
+                            .any(|name| attr.path().is_ident(name))
+                    {
+                        return syn::Error
+                            ::new_spanned(
+                                attr,
+                                "this attribute has no effect on a #[serde(skip)] field, which is never stored and is reset to `Default::default()` when loaded"
+                            )
+                            .to_compile_error()
+                            .into();
                     }
                     if attr.path().is_ident("index") {
-                        let index_args = parse_index_args(attr, field_name.clone()).expect(
-                            "could not parse index args"
-                        );
-                        index_definitions.push(index_args); // <-- COLLECT
-                    } else if attr.path().is_ident("validate") {
-                        let validate_definition = parse_validate_args(
-                            attr,
-                            field_name.clone()
-                        ).expect("could not parse validate args");
-                        validate_definitions.push(validate_definition);
+                        match parse_index_args(attr, key.clone()) {
+                            Ok(index_args) => index_definitions.push(index_args), // <-- COLLECT
+                            Err(e) => {
+                                return e.to_compile_error().into();
+                            }
+                        }
                     } else if attr.path().is_ident("default") {
-                        let def = parse_default_args(attr, ident).expect(
-                            "could not parse default args"
-                        );
-                        default_definitions.push(def);
+                        match parse_default_args(attr, ident) {
+                            Ok(def) => default_definitions.push(def),
+                            Err(e) => {
+                                return e.to_compile_error().into();
+                            }
+                        }
+                    } else if attr.path().is_ident("default_now") {
+                        match parse_default_now_args(attr, ident, &field.ty) {
+                            Ok(def) => default_definitions.push(def),
+                            Err(e) => {
+                                return e.to_compile_error().into();
+                            }
+                        }
+                    } else if attr.path().is_ident("skip_if_default") {
+                        if let Err(e) = attr.meta.require_path_only() {
+                            return e.to_compile_error().into();
+                        }
+                        if !has_serde_default(&field.attrs) && !has_serde_default(&input.attrs) {
+                            return syn::Error
+                                ::new_spanned(
+                                    attr,
+                                    "#[skip_if_default] requires #[serde(default)] on the field, so documents without the key still deserialize"
+                                )
+                                .to_compile_error()
+                                .into();
+                        }
+                        skip_if_default_fields.push((ident.clone(), field.ty.clone(), key.clone()));
+                    } else if attr.path().is_ident("normalize") {
+                        match parse_normalize_args(attr, &field.ty, key.clone()) {
+                            Ok(normalize) => normalize_definitions.push(normalize),
+                            Err(e) => {
+                                return e.to_compile_error().into();
+                            }
+                        }
+                    } else if attr.path().is_ident("reference") {
+                        match parse_reference_args(attr, ident, &field.ty) {
+                            Ok(reference) => reference_definitions.push(reference),
+                            Err(e) => {
+                                return e.to_compile_error().into();
+                            }
+                        }
                     }
                 }
             }
         }
     }
 
+    let validate_impl = match generate_validate_impl_tokens(&input) {
+        Ok(tokens) => tokens,
+        Err(e) => {
+            return e.to_compile_error().into();
+        }
+    };
+
+    let validate_unique_body = match parse_unique_fields(&input) {
+        Ok(unique_fields) => generate_validate_unique_tokens(&unique_fields),
+        Err(e) => {
+            return e.to_compile_error().into();
+        }
+    };
+
+    let debug_impl = match generate_debug_tokens(&input) {
+        Ok(tokens) => tokens,
+        Err(e) => {
+            return e.to_compile_error().into();
+        }
+    };
+
     let index_models = index_definitions
         .iter()
-        .map(|index_def| generate_index_model_tokens(index_def));
+        .map(generate_index_model_tokens)
+        .chain(compound_index_definitions.iter().map(generate_compound_index_model_tokens))
+        .chain(text_index_definitions.iter().map(generate_text_index_model_tokens));
+
+    let (clear_guard, clear_body, drop_body) = if model_args.allow_clear.unwrap_or(true) {
+        (
+            quote! {},
+            quote! {
+                let collection = Self::get_collection()?;
+                use ::oximod::_error::printable::Printable;
 
-    let validations = validate_definitions
-        .iter()
-        .flat_map(|validate_def| generate_validate_model_tokens(validate_def));
+                let result = collection
+                    .delete_many(::oximod::_mongodb::bson::doc! {})
+                    .await
+                    .map_err(|e| {
+                        ::oximod::_attach_printables!(
+                            ::oximod::_error::oximod_error::OximodError::ConnectionError(e.to_string()),
+                            "Failed to clear the collection. Ensure the mongodb connection is valid and the collection is writable."
+                        )
+                    })?;
+
+                ::oximod::_warning!(
+                    collection = %collection.namespace(),
+                    deleted = result.deleted_count,
+                    "clear() deleted every document"
+                );
+
+                Ok(result)
+            },
+            quote! {
+                let collection = Self::get_collection()?;
+                use ::oximod::_error::printable::Printable;
+
+                collection
+                    .drop()
+                    .await
+                    .map_err(|e| {
+                        ::oximod::_attach_printables!(
+                            ::oximod::_error::oximod_error::OximodError::ConnectionError(e.to_string()),
+                            "Failed to drop the collection. Ensure the mongodb connection is valid and the user may run `drop`."
+                        )
+                    })?;
+
+                ::oximod::_warning!(
+                    collection = %collection.namespace(),
+                    "drop_collection() dropped the collection"
+                );
+
+                Ok(())
+            },
+        )
+    } else {
+        (
+            quote! {
+                /// Disabled by `#[model(allow_clear = false)]`; calling it is a compile error.
+                #[allow(dead_code)]
+                pub async fn clear() -> Result<::oximod::_mongodb::results::DeleteResult, ::oximod::_error::oximod_error::OximodError>
+                where
+                    for<'a> ::oximod::_feature::model::clear_guard::ClearDisabled<'a>: ::oximod::_feature::model::clear_guard::ClearAllowed,
+                {
+                    unreachable!()
+                }
+
+                /// Disabled by `#[model(allow_clear = false)]`; calling it is a compile error.
+                #[allow(dead_code)]
+                pub async fn drop_collection() -> Result<(), ::oximod::_error::oximod_error::OximodError>
+                where
+                    for<'a> ::oximod::_feature::model::clear_guard::DropDisabled<'a>: ::oximod::_feature::model::clear_guard::DropAllowed,
+                {
+                    unreachable!()
+                }
+            },
+            quote! {
+                use ::oximod::_error::printable::Printable;
+
+                Err(::oximod::_attach_printables!(
+                    ::oximod::_error::oximod_error::OximodError::CommandError(
+                        "clear() is disabled for this model".to_string()
+                    ),
+                    "Remove `allow_clear = false` from #[model(...)] or delete with an explicit filter."
+                ))
+            },
+            quote! {
+                use ::oximod::_error::printable::Printable;
+
+                Err(::oximod::_attach_printables!(
+                    ::oximod::_error::oximod_error::OximodError::CommandError(
+                        "drop_collection() is disabled for this model".to_string()
+                    ),
+                    "Remove `allow_clear = false` from #[model(...)] or drop it through `get_collection()`."
+                ))
+            },
+        )
+    };
+
+    let json_schema = if model_args.json_schema.unwrap_or(false) {
+        match generate_json_schema_tokens(&input) {
+            Ok(schema) => quote! { Some(#schema) },
+            Err(e) => {
+                return e.to_compile_error().into();
+            }
+        }
+    } else {
+        quote! { None }
+    };
+
+    let ensure_capped = match &capped {
+        Some(CappedArgs { size, max }) => {
+            let max = match max {
+                Some(max) => quote! { Some(#max) },
+                None => quote! { None },
+            };
+            quote! { ::oximod::_feature::model::capped::ensure_capped(collection, #size, #max).await?; }
+        }
+        None => quote! {},
+    };
+
+    let create_indexes_on_save = if model_args.auto_create_indexes.unwrap_or(true) {
+        quote! { Self::_create_indexes(&collection).await?; }
+    } else if capped.is_some() {
+        // The collection must be capped before the first insert creates it implicitly.
+        quote! {
+            {
+                let collection = &collection;
+                #ensure_capped
+            }
+        }
+    } else {
+        quote! {}
+    };
 
     let default_inits = default_definitions.iter().map(|def| {
         let ident = &def.field_ident;
@@ -153,27 +501,123 @@ pub fn derive_model(input: TokenStream) -> TokenStream {
             quote! { #ident: Default::default(), }
         });
 
-    maybe_push_id_setter(has_id_attr, &input.attrs, &mut setters);
+    maybe_push_id_setter(id_type, &input.attrs, &mut setters);
     push_field_setters(&all_fields, &mut setters);
 
+    let populate_helpers = reference_definitions.iter().map(generate_populate_tokens);
+
+    let normalizers = normalize_definitions.iter().map(generate_normalize_tokens);
+
+    let skip_defaults = skip_if_default_fields.iter().map(|(ident, ty, key)| {
+        quote_spanned! {ty.span()=>
+            {
+                fn skip_if_default_requires_partial_eq_and_default<T: ::std::cmp::PartialEq + ::std::default::Default>(
+                    value: &T
+                ) -> bool {
+                    *value == T::default()
+                }
+                if skip_if_default_requires_partial_eq_and_default(&model.#ident) {
+                    document.remove(#key);
+                }
+            }
+        }
+    });
+
+    let filter_builder = match generate_filter_tokens(name, &input.vis, &field_keys) {
+        Ok(tokens) => tokens,
+        Err(e) => {
+            return e.to_compile_error().into();
+        }
+    };
+    let update_builder = match generate_update_tokens(
+        name,
+        &input.vis,
+        &all_fields,
+        &field_keys,
+        &normalize_definitions,
+        &serialize_codecs
+    ) {
+        Ok(tokens) => tokens,
+        Err(e) => {
+            return e.to_compile_error().into();
+        }
+    };
+    let patch_type = generate_patch_tokens(&input, &normalize_definitions);
+    let field_constants = generate_field_constants_tokens(name, &input.vis, &field_keys);
+
     let expanded =
         quote! {
+        #filter_builder
+        #update_builder
+        #patch_type
+        #field_constants
+
+        #validate_impl
+        #debug_impl
 
         impl #name {
-            fn validate(&self) -> Result<(), ::oximod::_error::oximod_error::OximodError> {
+            fn _hooks() -> &'static ::oximod::_feature::model::hooks::Hooks {
+                static HOOKS: ::std::sync::OnceLock<::oximod::_feature::model::hooks::Hooks> = ::std::sync::OnceLock::new();
+                HOOKS.get_or_init(Default::default)
+            }
+
+            async fn _find_one_sorted(
+                filter: ::oximod::_mongodb::bson::Document,
+                sort: ::oximod::_mongodb::bson::Document,
+            ) -> Result<Option<Self>, ::oximod::_error::oximod_error::OximodError> {
+                let collection = Self::get_collection()?;
                 use ::oximod::_error::printable::Printable;
-                #(#validations)*
-                Ok(())
+
+                let result = collection
+                    .find_one(filter)
+                    .sort(sort)
+                    .await
+                    .map_err(|e| {
+                        ::oximod::_attach_printables!(
+                            ::oximod::_error::oximod_error::OximodError::ConnectionError(e.to_string()),
+                            "Failed to run sorted find_one query. Ensure your filter is structured properly and the collection is accessible."
+                        )
+                    })?;
+
+                result
+                    .map(|doc| {
+                        ::oximod::_feature::model::deserialize::from_document(doc).map_err(|e| {
+                            ::oximod::_attach_printables!(
+                                e,
+                                "Could not deserialize document into model. Check for type mismatches or missing #[serde] attributes."
+                            )
+                        })
+                    })
+                    .transpose()
             }
-            
+
+            #(#populate_helpers)*
+
+            #clear_guard
+
+            fn _collection_in(db: &str, name: &str) -> Result<
+                ::oximod::_mongodb::Collection<::oximod::_mongodb::bson::Document>,
+                ::oximod::_error::oximod_error::OximodError
+            > {
+                let client = #get_client;
+                let db = client.database(db);
+                #collection_with_options
+            }
+
+            fn _declared_indexes() -> Vec<::oximod::_mongodb::IndexModel> {
+                vec![
+                    #(#index_models),*
+                ]
+            }
+
             async fn _create_indexes(
                 collection: &::oximod::_mongodb::Collection<::oximod::_mongodb::bson::Document>
             ) -> Result<(), ::oximod::_error::oximod_error::OximodError> {
                 use ::oximod::_error::printable::Printable;
+
+                #ensure_capped
     
-                let indexes = vec![
-                    #(#index_models),*
-                ];
+                let indexes = Self::_declared_indexes();
     
                 if !indexes.is_empty() {
                     collection.create_indexes(indexes).await.map_err(|e| {
@@ -201,170 +645,565 @@ pub fn derive_model(input: TokenStream) -> TokenStream {
             fn default() -> Self { Self::new() }
         }
 
-        #[::oximod::_async_trait::async_trait]
-        impl ::oximod::_feature::model::Model for #name {
+        impl ::std::convert::TryFrom<::oximod::_mongodb::bson::Document> for #name {
+            type Error = ::oximod::_error::oximod_error::OximodError;
 
-            fn get_collection() -> Result<
-                ::oximod::_mongodb::Collection<::oximod::_mongodb::bson::Document>, 
-                ::oximod::_error::oximod_error::OximodError
-            > {
-                let client = ::oximod::_feature::conn::client::get_global_client()?;
-                let db = client.database(#db);
-                Ok(db.collection::<::oximod::_mongodb::bson::Document>(#collection))
+            /// Deserializes a raw document, e.g. an aggregation result, into the model.
+            fn try_from(document: ::oximod::_mongodb::bson::Document) -> Result<Self, Self::Error> {
+                use ::oximod::_error::printable::Printable;
+
+                ::oximod::_feature::model::deserialize::from_document(document).map_err(|e| {
+                    ::oximod::_attach_printables!(
+                        e,
+                        "Could not deserialize document into model. Check for type mismatches or missing #[serde] attributes."
+                    )
+                })
             }
-            
-            async fn save(&self) -> Result<::oximod::_mongodb::bson::oid::ObjectId, ::oximod::_error::oximod_error::OximodError> {
-                self.validate()?; 
-                let collection = Self::get_collection()?;
-                Self::_create_indexes(&collection).await?; 
+        }
+
+        impl ::std::convert::TryFrom<&#name> for ::oximod::_mongodb::bson::Document {
+            type Error = ::oximod::_error::oximod_error::OximodError;
+
+            /// Serializes the model into the document that `save()` would insert.
+            fn try_from(model: &#name) -> Result<Self, Self::Error> {
                 use ::oximod::_error::printable::Printable;
 
-                let document = ::oximod::_mongodb::bson::to_document(&self).map_err(|e| {
+                #[allow(unused_mut)]
+                let mut document = ::oximod::_mongodb::bson::to_document(model).map_err(|e| {
                     ::oximod::_attach_printables!(
                         ::oximod::_error::oximod_error::OximodError::SerializationError(e.to_string()),
                         "Failed to serialize model. Are all field types supported by bson::to_document()?"
                     )
                 })?;
+                #(#normalizers)*
+                #(#skip_defaults)*
 
-                let result = collection.insert_one(document).await.map_err(|e| {
-                    ::oximod::_attach_printables!(
-                        ::oximod::_error::oximod_error::OximodError::ConnectionError(e.to_string()),
-                        "Failed to insert document. Check if the mongodb server is reachable and the collection exists."
-                    )
-                })?;
+                Ok(document)
+            }
+        }
 
-                match result.inserted_id.as_object_id() {
-                    Some(id) => Ok(id),
-                    None => Err( ::oximod::_attach_printables!(
-                        ::oximod::_error::oximod_error::OximodError::SerializationError("inserted_id is not an ObjectId".to_string()),
-                        "Expected inserted_id to be an ObjectId but received something else. This may happen if you're using a custom _id."
-                    ))
-                }
+        #[::oximod::_async_trait::async_trait]
+        impl ::oximod::_feature::model::Model for #name {
+
+            fn get_collection() -> Result<
+                ::oximod::_mongodb::Collection<::oximod::_mongodb::bson::Document>, 
+                ::oximod::_error::oximod_error::OximodError
+            > {
+                Self::get_collection_named(#collection)
             }
 
-            async fn update(
-                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
-                update: impl Into<::oximod::_mongodb::bson::Document> + Send
-            ) -> Result<::oximod::_mongodb::results::UpdateResult, ::oximod::_error::oximod_error::OximodError> {
-                let collection = Self::get_collection()?;
-                use ::oximod::_error::printable::Printable;
+            fn get_database() -> Result<::oximod::_mongodb::Database, ::oximod::_error::oximod_error::OximodError> {
+                let client = #get_client;
+                Ok(client.database(#db))
+            }
 
+            fn collection_name() -> &'static str {
+                #collection
+            }
 
-                let result = collection
-                    .update_many(filter.into(), update.into())
-                    .await
-                    .map_err(|e| {
-                        ::oximod::_attach_printables!(
-                            ::oximod::_error::oximod_error::OximodError::ConnectionError(e.to_string()),
-                            "Failed to update documents. Check your update operators and filter structure."
-                        )
-                    })?;
+            fn get_collection_named(name: &str) -> Result<
+                ::oximod::_mongodb::Collection<::oximod::_mongodb::bson::Document>,
+                ::oximod::_error::oximod_error::OximodError
+            > {
+                #name::_collection_in(#db, name)
+            }
 
-                Ok(result)
+            fn get_collection_in_db(db: &str) -> Result<
+                ::oximod::_mongodb::Collection<::oximod::_mongodb::bson::Document>,
+                ::oximod::_error::oximod_error::OximodError
+            > {
+                #name::_collection_in(db, #collection)
             }
 
-            async fn update_one(
-                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
-                update: impl Into<::oximod::_mongodb::bson::Document> + Send,
-            ) -> Result<::oximod::_mongodb::results::UpdateResult, ::oximod::_error::oximod_error::OximodError> {
-                let collection = Self::get_collection()?;
-                use ::oximod::_error::printable::Printable;
+            fn _hooks() -> &'static ::oximod::_feature::model::hooks::Hooks {
+                #name::_hooks()
+            }
 
+            fn _to_document(&self) -> Result<::oximod::_mongodb::bson::Document, ::oximod::_error::oximod_error::OximodError> {
+                ::oximod::_mongodb::bson::Document::try_from(self)
+            }
 
-                let result = collection
-                    .update_one(filter.into(), update.into())
-                    .await
-                    .map_err(|e| {
-                        ::oximod::_attach_printables!(
-                            ::oximod::_error::oximod_error::OximodError::ConnectionError(e.to_string()),
-                            "Failed to update a document. Make sure your update syntax is valid and the filter matches at least one document."
-                        )
-                    })?;
+            async fn _create_indexes(
+                collection: &::oximod::_mongodb::Collection<::oximod::_mongodb::bson::Document>
+            ) -> Result<(), ::oximod::_error::oximod_error::OximodError> {
+                #name::_create_indexes(collection).await
+            }
 
-                Ok(result)
+            fn declared_indexes() -> Vec<::oximod::_mongodb::IndexModel> {
+                #name::_declared_indexes()
             }
 
-            async fn delete(
-                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
-            ) -> Result<::oximod::_mongodb::results::DeleteResult, ::oximod::_error::oximod_error::OximodError> {
+            async fn sync_indexes() -> Result<(), ::oximod::_error::oximod_error::OximodError> {
+                let collection = Self::get_collection()?;
+                Self::_create_indexes(&collection).await?;
+                if let Some(schema) = Self::json_schema() {
+                    ::oximod::_feature::model::schema::apply_json_schema(&collection, schema).await?;
+                }
+                Ok(())
+            }
+
+            fn json_schema() -> Option<::oximod::_mongodb::bson::Document> {
+                #json_schema
+            }
+
+            async fn list_indexes() -> Result<Vec<::oximod::_mongodb::IndexModel>, ::oximod::_error::oximod_error::OximodError> {
                 let collection = Self::get_collection()?;
                 use ::oximod::_error::printable::Printable;
 
+                let mut cursor = collection.list_indexes().await.map_err(|e| {
+                    ::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::IndexError(e.to_string()),
+                        "Failed to list indexes. Ensure the collection exists and the connection is healthy."
+                    )
+                })?;
+
+                let mut indexes = vec![];
 
-                let result = collection
-                    .delete_many(filter.into())
-                    .await
-                    .map_err(|e| {
+                while let Some(index) = ::oximod::_futures_util::stream::StreamExt::next(&mut cursor).await {
+                    let index = index.map_err(|e| {
                         ::oximod::_attach_printables!(
-                            ::oximod::_error::oximod_error::OximodError::ConnectionError(e.to_string()),
-                            "Failed to delete documents. Ensure your filter is valid and matches the correct documents."
+                            ::oximod::_error::oximod_error::OximodError::IndexError(e.to_string()),
+                            "Cursor failed to retrieve an index description."
                         )
                     })?;
+                    indexes.push(index);
+                }
 
-                Ok(result)
+                Ok(indexes)
             }
 
-            async fn delete_one(
-                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
-            ) -> Result<::oximod::_mongodb::results::DeleteResult, ::oximod::_error::oximod_error::OximodError> {
+            async fn drop_index(name: &str) -> Result<(), ::oximod::_error::oximod_error::OximodError> {
                 let collection = Self::get_collection()?;
                 use ::oximod::_error::printable::Printable;
 
+                collection.drop_index(name).await.map_err(|e| {
+                    ::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::IndexError(e.to_string()),
+                        "Failed to drop index. Check the index name with `list_indexes()`."
+                    )
+                })?;
 
-                let result = collection
-                    .delete_one(filter.into())
-                    .await
-                    .map_err(|e| {
-                        ::oximod::_attach_printables!(
-                            ::oximod::_error::oximod_error::OximodError::ConnectionError(e.to_string()),
-                            "Failed to delete a single document. Ensure your filter is valid and matches the correct document."
-                        )
-                    })?;
-
-                Ok(result)
+                Ok(())
             }
 
-            async fn find(
-                filter: impl Into<::oximod::_mongodb::bson::Document> + Send
-            ) -> Result<Vec<Self>, ::oximod::_error::oximod_error::OximodError>
-            where
-                Self: Sized,
-            {
+            async fn drop_all_indexes() -> Result<(), ::oximod::_error::oximod_error::OximodError> {
                 let collection = Self::get_collection()?;
                 use ::oximod::_error::printable::Printable;
 
-
-                let mut cursor = collection
-                    .find(filter.into())
-                    .await
-                    .map_err(|e| {
+                collection.drop_indexes().await.map_err(|e| {
+                    ::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::IndexError(e.to_string()),
+                        "Failed to drop indexes. Ensure the collection exists and is writable."
+                    )
+                })?;
+
+                Ok(())
+            }
+            
+            fn pre_save<F>(hook: F)
+            where
+                F: Fn(&mut ::oximod::_mongodb::bson::Document) -> ::oximod::_feature::model::hooks::HookFuture + Send + Sync + 'static,
+            {
+                Self::_hooks().add_pre_save(::std::sync::Arc::new(hook));
+            }
+
+            fn post_save<F>(hook: F)
+            where
+                F: Fn(&mut ::oximod::_mongodb::bson::Document) -> ::oximod::_feature::model::hooks::HookFuture + Send + Sync + 'static,
+            {
+                Self::_hooks().add_post_save(::std::sync::Arc::new(hook));
+            }
+
+            fn pre_delete<F>(hook: F)
+            where
+                F: Fn(&::oximod::_mongodb::bson::Document) -> ::oximod::_feature::model::hooks::HookFuture + Send + Sync + 'static,
+            {
+                Self::_hooks().add_pre_delete(::std::sync::Arc::new(hook));
+            }
+
+            fn post_delete<F>(hook: F)
+            where
+                F: Fn(&::oximod::_mongodb::bson::Document) -> ::oximod::_feature::model::hooks::HookFuture + Send + Sync + 'static,
+            {
+                Self::_hooks().add_post_delete(::std::sync::Arc::new(hook));
+            }
+
+            async fn save(&self) -> Result<::oximod::_mongodb::bson::oid::ObjectId, ::oximod::_error::oximod_error::OximodError> {
+                ::oximod::_instrument!(#collection, "save", {
+                    ::oximod::_mock! {
+                        self.validate()?;
+                        let mut document = self._to_document()?;
+                        Self::_hooks().run_pre_save(&mut document).await?;
+                        let id = ::oximod::_feature::conn::mock::insert(#db, #collection, &mut document)?;
+                        Self::_hooks().run_post_save(&mut document).await?;
+                        return Ok(id);
+                    }
+                    self.validate()?; 
+                    let collection = Self::get_collection()?;
+                    #create_indexes_on_save
+                    use ::oximod::_error::printable::Printable;
+
+                    let mut document = self._to_document()?;
+
+                    Self::_hooks().run_pre_save(&mut document).await?;
+
+                    let result = collection.insert_one(&document).await.map_err(|e| {
                         ::oximod::_attach_printables!(
-                            ::oximod::_error::oximod_error::OximodError::ConnectionError(e.to_string()),
-                            "Failed to execute find query. Double-check your filter syntax or collection state."
+                            ::oximod::_feature::model::write_error::write_error(e, ::oximod::_error::oximod_error::OximodError::ConnectionError),
+                            "Failed to insert document. Check if the mongodb server is reachable and the collection exists."
                         )
                     })?;
 
-                let mut results = vec![];
+                    let id = match result.inserted_id.as_object_id() {
+                        Some(id) => id,
+                        None => return Err( ::oximod::_attach_printables!(
+                            ::oximod::_error::oximod_error::OximodError::SerializationError("inserted_id is not an ObjectId".to_string()),
+                            "Expected inserted_id to be an ObjectId but received something else. This may happen if you're using a custom _id."
+                        ))
+                    };
 
-                while let Some(doc) = ::oximod::_futures_util::stream::StreamExt::next(&mut cursor).await {
-                    let doc = doc.map_err(|e| {
+                    document.insert("_id", id);
+                    Self::_hooks().run_post_save(&mut document).await?;
+
+                    Ok(id)
+                })
+            }
+
+            async fn save_many_lenient(
+                models: Vec<Self>
+            ) -> Result<::oximod::_feature::model::batch::BatchResult, ::oximod::_error::oximod_error::OximodError> {
+                ::oximod::_instrument!(#collection, "save_many_lenient", {
+                    let collection = Self::get_collection()?;
+                    #create_indexes_on_save
+                    Ok(::oximod::_feature::model::batch::insert_lenient(&collection, models).await)
+                })
+            }
+
+            async fn save_if_not_exists(
+                &self,
+                filter: impl Into<::oximod::_mongodb::bson::Document> + Send
+            ) -> Result<bool, ::oximod::_error::oximod_error::OximodError> {
+                ::oximod::_instrument!(#collection, "save_if_not_exists", {
+                    self.validate()?;
+                    let collection = Self::get_collection()?;
+                    #create_indexes_on_save
+                    use ::oximod::_error::printable::Printable;
+
+                    let mut document = self._to_document()?;
+
+                    Self::_hooks().run_pre_save(&mut document).await?;
+
+                    let result = collection
+                        .update_one(
+                            filter.into(),
+                            ::oximod::_mongodb::bson::doc! { "$setOnInsert": document.clone() }
+                        )
+                        .upsert(true)
+                        .await
+                        .map_err(|e| {
+                            ::oximod::_attach_printables!(
+                                ::oximod::_feature::model::write_error::write_error(e, ::oximod::_error::oximod_error::OximodError::ConnectionError),
+                                "Failed to upsert document. Make sure the filter and model fields don't set the same keys to different values."
+                            )
+                        })?;
+
+                    match result.upserted_id {
+                        Some(id) => {
+                            document.insert("_id", id);
+                            Self::_hooks().run_post_save(&mut document).await?;
+                            Ok(true)
+                        }
+                        None => Ok(false),
+                    }
+                })
+            }
+
+            async fn find_one_or_create(
+                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
+                default: Self
+            ) -> Result<Self, ::oximod::_error::oximod_error::OximodError> {
+                ::oximod::_instrument!(#collection, "find_one_or_create", {
+                    default.validate()?;
+                    let collection = Self::get_collection()?;
+                    #create_indexes_on_save
+                    use ::oximod::_error::printable::Printable;
+
+                    let mut document = default._to_document()?;
+
+                    Self::_hooks().run_pre_save(&mut document).await?;
+
+                    // A fresh `_id` tells an inserted document apart from an existing one.
+                    if !document.contains_key("_id") {
+                        document.insert("_id", ::oximod::_mongodb::bson::oid::ObjectId::new());
+                    }
+
+                    let result = collection
+                        .find_one_and_update(
+                            filter.into(),
+                            ::oximod::_mongodb::bson::doc! { "$setOnInsert": document.clone() }
+                        )
+                        .upsert(true)
+                        .return_document(::oximod::_mongodb::options::ReturnDocument::After)
+                        .await
+                        .map_err(|e| {
+                            ::oximod::_attach_printables!(
+                                ::oximod::_feature::model::write_error::write_error(e, ::oximod::_error::oximod_error::OximodError::ConnectionError),
+                                "Failed to find or create document. Make sure the filter and model fields don't set the same keys to different values."
+                            )
+                        })?;
+
+                    let stored = result.ok_or_else(|| {
                         ::oximod::_attach_printables!(
-                            ::oximod::_error::oximod_error::OximodError::ConnectionError(e.to_string()),
-                            "Cursor failed to retrieve a document. This may indicate a deserialization or network error mid-stream."
+                            ::oximod::_error::oximod_error::OximodError::ConnectionError(
+                                "findOneAndUpdate with upsert returned no document".to_string()
+                            ),
+                            "The server should always return the upserted document. Check that the deployment supports findAndModify upserts."
                         )
                     })?;
 
-                    let parsed = ::oximod::_mongodb::bson::from_document(doc).map_err(|e| {
+                    if stored.get("_id") == document.get("_id") {
+                        Self::_hooks().run_post_save(&mut document).await?;
+                    }
+
+                    ::oximod::_feature::model::deserialize::from_document(stored).map_err(|e| {
                         ::oximod::_attach_printables!(
-                            ::oximod::_error::oximod_error::OximodError::SerializationError(e.to_string()),
-                            "Failed to deserialize document into model. Check field types and optionality."
+                            e,
+                            "Could not deserialize document into model. Check for type mismatches or missing #[serde] attributes."
                         )
-                    })?;
+                    })
+                })
+            }
 
-                    results.push(parsed);
-                }
+            async fn update(
+                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
+                update: impl Into<::oximod::_mongodb::bson::Document> + Send
+            ) -> Result<::oximod::_mongodb::results::UpdateResult, ::oximod::_error::oximod_error::OximodError> {
+                ::oximod::_instrument!(#collection, "update", {
+                    let collection = Self::get_collection()?;
+                    use ::oximod::_error::printable::Printable;
 
-                Ok(results)
+
+                    let result = collection
+                        .update_many(filter.into(), update.into())
+                        .await
+                        .map_err(|e| {
+                            ::oximod::_attach_printables!(
+                                ::oximod::_feature::model::write_error::write_error(e, ::oximod::_error::oximod_error::OximodError::ConnectionError),
+                                "Failed to update documents. Check your update operators and filter structure."
+                            )
+                        })?;
+
+                    Ok(result)
+                })
+            }
+
+            async fn update_one(
+                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
+                update: impl Into<::oximod::_mongodb::bson::Document> + Send,
+            ) -> Result<::oximod::_mongodb::results::UpdateResult, ::oximod::_error::oximod_error::OximodError> {
+                ::oximod::_instrument!(#collection, "update_one", {
+                    let collection = Self::get_collection()?;
+                    use ::oximod::_error::printable::Printable;
+
+
+                    let result = collection
+                        .update_one(filter.into(), update.into())
+                        .await
+                        .map_err(|e| {
+                            ::oximod::_attach_printables!(
+                                ::oximod::_feature::model::write_error::write_error(e, ::oximod::_error::oximod_error::OximodError::ConnectionError),
+                                "Failed to update a document. Make sure your update syntax is valid and the filter matches at least one document."
+                            )
+                        })?;
+
+                    Ok(result)
+                })
+            }
+
+            async fn upsert_one(
+                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
+                update: impl Into<::oximod::_mongodb::bson::Document> + Send,
+            ) -> Result<::oximod::_feature::model::upsert::UpsertOutcome, ::oximod::_error::oximod_error::OximodError> {
+                ::oximod::_instrument!(#collection, "upsert_one", {
+                    let collection = Self::get_collection()?;
+                    use ::oximod::_error::printable::Printable;
+
+                    let result = collection
+                        .update_one(filter.into(), update.into())
+                        .upsert(true)
+                        .await
+                        .map_err(|e| {
+                            ::oximod::_attach_printables!(
+                                ::oximod::_feature::model::write_error::write_error(e, ::oximod::_error::oximod_error::OximodError::ConnectionError),
+                                "Failed to upsert a document. Make sure your update syntax is valid and the filter doesn't conflict with the update."
+                            )
+                        })?;
+
+                    ::oximod::_feature::model::upsert::UpsertOutcome::try_from(result)
+                })
+            }
+
+            async fn update_one_returning(
+                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
+                update: impl Into<::oximod::_mongodb::bson::Document> + Send,
+            ) -> Result<Option<Self>, ::oximod::_error::oximod_error::OximodError> {
+                ::oximod::_instrument!(#collection, "update_one_returning", {
+                    let collection = Self::get_collection()?;
+                    use ::oximod::_error::printable::Printable;
+
+                    let result = collection
+                        .find_one_and_update(filter.into(), update.into())
+                        .return_document(::oximod::_mongodb::options::ReturnDocument::After)
+                        .await
+                        .map_err(|e| {
+                            ::oximod::_attach_printables!(
+                                ::oximod::_feature::model::write_error::write_error(e, ::oximod::_error::oximod_error::OximodError::ConnectionError),
+                                "Failed to update a document. Make sure your update syntax is valid and the filter is well-formed."
+                            )
+                        })?;
+
+                    result
+                        .map(|doc| {
+                            ::oximod::_feature::model::deserialize::from_document(doc).map_err(|e| {
+                                ::oximod::_attach_printables!(
+                                    e,
+                                    "Could not deserialize document into model. Check for type mismatches or missing #[serde] attributes."
+                                )
+                            })
+                        })
+                        .transpose()
+                })
+            }
+
+            async fn replace_one(
+                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
+                replacement: &Self,
+            ) -> Result<::oximod::_mongodb::results::UpdateResult, ::oximod::_error::oximod_error::OximodError> {
+                ::oximod::_instrument!(#collection, "replace_one", {
+                    replacement.validate()?;
+                    let collection = Self::get_collection()?;
+                    use ::oximod::_error::printable::Printable;
+
+                    let mut document = replacement._to_document()?;
+                    document.remove("_id");
+
+                    let result = collection
+                        .replace_one(filter.into(), document)
+                        .await
+                        .map_err(|e| {
+                            ::oximod::_attach_printables!(
+                                ::oximod::_feature::model::write_error::write_error(e, ::oximod::_error::oximod_error::OximodError::ConnectionError),
+                                "Failed to replace a document. Make sure the filter is valid and the collection is writable."
+                            )
+                        })?;
+
+                    Ok(result)
+                })
+            }
+
+            async fn delete(
+                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
+            ) -> Result<::oximod::_mongodb::results::DeleteResult, ::oximod::_error::oximod_error::OximodError> {
+                ::oximod::_instrument!(#collection, "delete", {
+                    let collection = Self::get_collection()?;
+                    use ::oximod::_error::printable::Printable;
+
+
+                    let filter = filter.into();
+                    Self::_hooks().run_pre_delete(&filter).await?;
+
+                    let result = collection
+                        .delete_many(filter.clone())
+                        .await
+                        .map_err(|e| {
+                            ::oximod::_attach_printables!(
+                                ::oximod::_feature::model::write_error::write_error(e, ::oximod::_error::oximod_error::OximodError::ConnectionError),
+                                "Failed to delete documents. Ensure your filter is valid and matches the correct documents."
+                            )
+                        })?;
+
+                    Self::_hooks().run_post_delete(&filter).await?;
+
+                    Ok(result)
+                })
+            }
+
+            async fn delete_one(
+                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
+            ) -> Result<::oximod::_mongodb::results::DeleteResult, ::oximod::_error::oximod_error::OximodError> {
+                ::oximod::_instrument!(#collection, "delete_one", {
+                    let collection = Self::get_collection()?;
+                    use ::oximod::_error::printable::Printable;
+
+
+                    let filter = filter.into();
+                    Self::_hooks().run_pre_delete(&filter).await?;
+
+                    let result = collection
+                        .delete_one(filter.clone())
+                        .await
+                        .map_err(|e| {
+                            ::oximod::_attach_printables!(
+                                ::oximod::_feature::model::write_error::write_error(e, ::oximod::_error::oximod_error::OximodError::ConnectionError),
+                                "Failed to delete a single document. Ensure your filter is valid and matches the correct document."
+                            )
+                        })?;
+
+                    Self::_hooks().run_post_delete(&filter).await?;
+
+                    Ok(result)
+                })
+            }
+
+            async fn find(
+                filter: impl Into<::oximod::_mongodb::bson::Document> + Send
+            ) -> Result<Vec<Self>, ::oximod::_error::oximod_error::OximodError>
+            where
+                Self: Sized,
+            {
+                ::oximod::_instrument!(#collection, "find", {
+                    ::oximod::_mock! {
+                        return ::oximod::_feature::conn::mock::find(#db, #collection, &filter.into())?
+                            .into_iter()
+                            .map(Self::try_from)
+                            .collect();
+                    }
+                    let collection = Self::get_collection()?;
+                    use ::oximod::_error::printable::Printable;
+
+
+                    let mut cursor = collection
+                        .find(filter.into())
+                        .await
+                        .map_err(|e| {
+                            ::oximod::_attach_printables!(
+                                ::oximod::_error::oximod_error::OximodError::ConnectionError(e.to_string()),
+                                "Failed to execute find query. Double-check your filter syntax or collection state."
+                            )
+                        })?;
+
+                    let mut results = vec![];
+
+                    while let Some(doc) = ::oximod::_futures_util::stream::StreamExt::next(&mut cursor).await {
+                        let doc = doc.map_err(|e| {
+                            ::oximod::_attach_printables!(
+                                ::oximod::_error::oximod_error::OximodError::ConnectionError(e.to_string()),
+                                "Cursor failed to retrieve a document. This may indicate a deserialization or network error mid-stream."
+                            )
+                        })?;
+
+                        let parsed = ::oximod::_feature::model::deserialize::from_document(doc).map_err(|e| {
+                            ::oximod::_attach_printables!(
+                                e,
+                                "Failed to deserialize document into model. Check field types and optionality."
+                            )
+                        })?;
+
+                        results.push(parsed);
+                    }
+
+                    Ok(results)
+                })
             }
 
             async fn find_one(
@@ -373,32 +1212,53 @@ pub fn derive_model(input: TokenStream) -> TokenStream {
             where
                 Self: Sized,
             {
-                let collection = Self::get_collection()?;
-                use ::oximod::_error::printable::Printable;
-
+                ::oximod::_instrument!(#collection, "find_one", {
+                    ::oximod::_mock! {
+                        return ::oximod::_feature::conn::mock::find(#db, #collection, &filter.into())?
+                            .into_iter()
+                            .next()
+                            .map(Self::try_from)
+                            .transpose();
+                    }
+                    let collection = Self::get_collection()?;
+                    use ::oximod::_error::printable::Printable;
 
-                let result = collection
-                    .find_one(filter.into())
-                    .await
-                    .map_err(|e| {
-                        ::oximod::_attach_printables!(
-                            ::oximod::_error::oximod_error::OximodError::ConnectionError(e.to_string()),
-                            "Failed to run find_one query. Ensure your filter is structured properly and the collection is accessible."
-                        )
-                    })?;
 
-                match result {
-                    Some(doc) => {
-                        let parsed = ::oximod::_mongodb::bson::from_document(doc).map_err(|e| {
+                    let result = collection
+                        .find_one(filter.into())
+                        .await
+                        .map_err(|e| {
                             ::oximod::_attach_printables!(
-                                ::oximod::_error::oximod_error::OximodError::SerializationError(e.to_string()),
-                                "Could not deserialize document into model. Check for type mismatches or missing #[serde] attributes."
+                                ::oximod::_error::oximod_error::OximodError::ConnectionError(e.to_string()),
+                                "Failed to run find_one query. Ensure your filter is structured properly and the collection is accessible."
                             )
                         })?;
-                        Ok(Some(parsed))
+
+                    match result {
+                        Some(doc) => {
+                            let parsed = ::oximod::_feature::model::deserialize::from_document(doc).map_err(|e| {
+                                ::oximod::_attach_printables!(
+                                    e,
+                                    "Could not deserialize document into model. Check for type mismatches or missing #[serde] attributes."
+                                )
+                            })?;
+                            Ok(Some(parsed))
+                        }
+                        None => Ok(None),
                     }
-                    None => Ok(None),
-                }
+                })
+            }
+
+            async fn first(
+                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
+            ) -> Result<Option<Self>, ::oximod::_error::oximod_error::OximodError> {
+                Self::_find_one_sorted(filter.into(), ::oximod::_mongodb::bson::doc! { "_id": 1 }).await
+            }
+
+            async fn last(
+                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
+            ) -> Result<Option<Self>, ::oximod::_error::oximod_error::OximodError> {
+                Self::_find_one_sorted(filter.into(), ::oximod::_mongodb::bson::doc! { "_id": -1 }).await
             }
 
             async fn find_by_id(
@@ -444,14 +1304,368 @@ pub fn derive_model(input: TokenStream) -> TokenStream {
                 })
             }
 
+            async fn find_by_id_str(
+                id: &str,
+            ) -> Result<Option<Self>, ::oximod::_error::oximod_error::OximodError> {
+                let id = ::oximod::_feature::id::parse_object_id(id)?;
+
+                Self::find_by_id(id).await
+            }
+
+            async fn update_by_id_str(
+                id: &str,
+                update: impl Into<::oximod::_mongodb::bson::Document> + Send,
+            ) -> Result<::oximod::_mongodb::results::UpdateResult, ::oximod::_error::oximod_error::OximodError> {
+                let id = ::oximod::_feature::id::parse_object_id(id)?;
+
+                Self::update_by_id(id, update).await
+            }
+
+            async fn delete_by_id_str(
+                id: &str,
+            ) -> Result<::oximod::_mongodb::results::DeleteResult, ::oximod::_error::oximod_error::OximodError> {
+                let id = ::oximod::_feature::id::parse_object_id(id)?;
+
+                Self::delete_by_id(id).await
+            }
+
+            async fn update_by_ids(
+                ids: Vec<::oximod::_mongodb::bson::oid::ObjectId>,
+                update: impl Into<::oximod::_mongodb::bson::Document> + Send,
+            ) -> Result<::oximod::_mongodb::results::UpdateResult, ::oximod::_error::oximod_error::OximodError> {
+                use ::oximod::_error::printable::Printable;
+
+                Self::update(::oximod::_mongodb::bson::doc! { "_id": { "$in": ids } }, update).await.map_err(|e| {
+                    ::oximod::_attach_printables!(
+                        e,
+                        "Failed to update documents by _id. Check that your update operators are valid."
+                    )
+                })
+            }
+
+            async fn delete_by_ids(
+                ids: Vec<::oximod::_mongodb::bson::oid::ObjectId>,
+            ) -> Result<::oximod::_mongodb::results::DeleteResult, ::oximod::_error::oximod_error::OximodError> {
+                use ::oximod::_error::printable::Printable;
+
+                Self::delete(::oximod::_mongodb::bson::doc! { "_id": { "$in": ids } }).await.map_err(|e| {
+                    ::oximod::_attach_printables!(
+                        e,
+                        "Failed to delete documents by _id. Ensure the connection is healthy and the collection is writable."
+                    )
+                })
+            }
+
+            async fn count_grouped(
+                group_by: &str,
+                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
+            ) -> Result<Vec<(::oximod::_mongodb::bson::Bson, u64)>, ::oximod::_error::oximod_error::OximodError> {
+                use ::oximod::_error::printable::Printable;
+
+                let group_by = ::oximod::_feature::model::aggregate::field_path(group_by)?;
+                let pipeline = vec![
+                    ::oximod::_mongodb::bson::doc! { "$match": filter.into() },
+                    ::oximod::_mongodb::bson::doc! {
+                        "$group": { "_id": group_by, "count": { "$sum": 1_i64 } }
+                    },
+                    ::oximod::_mongodb::bson::doc! { "$sort": { "count": -1, "_id": 1 } },
+                ];
+
+                let groups = Self::aggregate_as::<::oximod::_mongodb::bson::Document>(pipeline).await?;
+
+                groups
+                    .into_iter()
+                    .map(|mut group| {
+                        let count = group.get_i64("count").map_err(|e| {
+                            ::oximod::_attach_printables!(
+                                ::oximod::_error::oximod_error::OximodError::AggregationError(e.to_string()),
+                                "Unexpected `$group` output while counting."
+                            )
+                        })?;
+                        let key = group.remove("_id").unwrap_or(::oximod::_mongodb::bson::Bson::Null);
+                        Ok((key, count as u64))
+                    })
+                    .collect()
+            }
+
+            async fn aggregate_as<T>(
+                pipeline: impl Into<Vec<::oximod::_mongodb::bson::Document>> + Send
+            ) -> Result<Vec<T>, ::oximod::_error::oximod_error::OximodError>
+            where
+                T: ::oximod::_serde::de::DeserializeOwned + Send,
+            {
+                use ::oximod::_error::printable::Printable;
+
+                let mut cursor = Self::aggregate(pipeline).await?;
+                let mut results = vec![];
+
+                while let Some(doc) = ::oximod::_futures_util::stream::StreamExt::next(&mut cursor).await {
+                    let doc = doc.map_err(|e| {
+                        ::oximod::_attach_printables!(
+                            ::oximod::_error::oximod_error::OximodError::AggregationError(e.to_string()),
+                            "Cursor failed to retrieve an aggregation result. This may indicate a network error mid-stream."
+                        )
+                    })?;
+
+                    let parsed = ::oximod::_feature::model::deserialize::from_document(doc).map_err(|e| {
+                        ::oximod::_attach_printables!(
+                            e,
+                            "Failed to deserialize aggregation result. Check that `T` matches the shape of the last pipeline stage."
+                        )
+                    })?;
+
+                    results.push(parsed);
+                }
+
+                Ok(results)
+            }
+
+            async fn explain_find(
+                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
+            ) -> Result<::oximod::_mongodb::bson::Document, ::oximod::_error::oximod_error::OximodError> {
+                let collection = Self::get_collection()?;
+                use ::oximod::_error::printable::Printable;
+
+                let namespace = collection.namespace();
+                let command = ::oximod::_mongodb::bson::doc! {
+                    "explain": { "find": &namespace.coll, "filter": filter.into() },
+                    "verbosity": "queryPlanner",
+                };
+
+                let plan = collection
+                    .client()
+                    .database(&namespace.db)
+                    .run_command(command)
+                    .await
+                    .map_err(|e| {
+                        ::oximod::_attach_printables!(
+                            ::oximod::_error::oximod_error::OximodError::CommandError(e.to_string()),
+                            "Failed to explain the query. Check the filter syntax and that the user may run `explain`."
+                        )
+                    })?;
+
+                Ok(plan)
+            }
+
+            async fn watch(
+                pipeline: impl Into<Vec<::oximod::_mongodb::bson::Document>> + Send,
+                resume_after: Option<::oximod::_mongodb::change_stream::event::ResumeToken>,
+            ) -> Result<::oximod::_feature::model::change_stream::ChangeStream<Self>, ::oximod::_error::oximod_error::OximodError>
+            where
+                Self: Sized + ::oximod::_serde::de::DeserializeOwned,
+            {
+                let collection = Self::get_collection()?;
+                use ::oximod::_error::printable::Printable;
+
+                let stream = collection
+                    .clone_with_type::<Self>()
+                    .watch()
+                    .pipeline(pipeline.into())
+                    .resume_after(resume_after)
+                    .await
+                    .map_err(|e| {
+                        ::oximod::_attach_printables!(
+                            ::oximod::_error::oximod_error::OximodError::ConnectionError(e.to_string()),
+                            "Failed to open change stream. Change streams require a replica set or sharded cluster."
+                        )
+                    })?;
+
+                Ok(::oximod::_feature::model::change_stream::ChangeStream::new(stream))
+            }
+
+            async fn bulk_write(
+                ops: Vec<::oximod::_feature::model::write_op::WriteOp>,
+                ordered: bool,
+            ) -> Result<::oximod::_mongodb::results::SummaryBulkWriteResult, ::oximod::_error::oximod_error::OximodError> {
+                use ::oximod::_error::printable::Printable;
+
+                if ops.is_empty() {
+                    return Err(::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::CommandError("bulk_write requires at least one operation".to_string()),
+                        "Skip the call when there is nothing to write."
+                    ));
+                }
+
+                let collection = Self::get_collection()?;
+
+                let namespace = collection.namespace();
+                let models = ops
+                    .into_iter()
+                    .map(|op| op.into_write_model(namespace.clone()))
+                    .collect::<Vec<_>>();
+
+                let mut action = collection.client().bulk_write(models).ordered(ordered);
+                if let Some(write_concern) = collection.write_concern() {
+                    action = action.write_concern(write_concern.clone());
+                }
+
+                let result = action.await.map_err(|e| {
+                    ::oximod::_attach_printables!(
+                        ::oximod::_feature::model::write_error::write_error(e, ::oximod::_error::oximod_error::OximodError::ConnectionError),
+                        "Bulk write failed. Bulk writes require MongoDB 8.0+; check each operation's filter and update syntax."
+                    )
+                })?;
+
+                Ok(result)
+            }
+
+            async fn save_with_session(
+                &self,
+                session: &mut ::oximod::_mongodb::ClientSession,
+            ) -> Result<::oximod::_mongodb::bson::oid::ObjectId, ::oximod::_error::oximod_error::OximodError> {
+                self.validate()?;
+                let collection = Self::get_collection()?;
+                use ::oximod::_error::printable::Printable;
+
+                let mut document = self._to_document()?;
+
+                Self::_hooks().run_pre_save(&mut document).await?;
+
+                let result = collection.insert_one(&document).session(session).await.map_err(|e| {
+                    ::oximod::_attach_printables!(
+                        ::oximod::_feature::conn::transaction::session_error(e, ::oximod::_error::oximod_error::OximodError::ConnectionError),
+                        "Failed to insert document in session. Check that the session's transaction is still active."
+                    )
+                })?;
+
+                let id = match result.inserted_id.as_object_id() {
+                    Some(id) => id,
+                    None => return Err( ::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::SerializationError("inserted_id is not an ObjectId".to_string()),
+                        "Expected inserted_id to be an ObjectId but received something else. This may happen if you're using a custom _id."
+                    ))
+                };
+
+                document.insert("_id", id);
+                Self::_hooks().run_post_save(&mut document).await?;
+
+                Ok(id)
+            }
+
+            async fn find_one_with_session(
+                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
+                session: &mut ::oximod::_mongodb::ClientSession,
+            ) -> Result<Option<Self>, ::oximod::_error::oximod_error::OximodError>
+            where
+                Self: Sized,
+            {
+                let collection = Self::get_collection()?;
+                use ::oximod::_error::printable::Printable;
+
+                let result = collection
+                    .find_one(filter.into())
+                    .session(session)
+                    .await
+                    .map_err(|e| {
+                        ::oximod::_attach_printables!(
+                            ::oximod::_feature::conn::transaction::session_error(e, ::oximod::_error::oximod_error::OximodError::ConnectionError),
+                            "Failed to run find_one query in session. Ensure your filter is structured properly."
+                        )
+                    })?;
+
+                match result {
+                    Some(doc) => {
+                        let parsed = ::oximod::_feature::model::deserialize::from_document(doc).map_err(|e| {
+                            ::oximod::_attach_printables!(
+                                e,
+                                "Could not deserialize document into model. Check for type mismatches or missing #[serde] attributes."
+                            )
+                        })?;
+                        Ok(Some(parsed))
+                    }
+                    None => Ok(None),
+                }
+            }
+
+            async fn find_by_id_with_session(
+                id: ::oximod::_mongodb::bson::oid::ObjectId,
+                session: &mut ::oximod::_mongodb::ClientSession,
+            ) -> Result<Option<Self>, ::oximod::_error::oximod_error::OximodError>
+            where
+                Self: Sized,
+            {
+                Self::find_one_with_session(::oximod::_mongodb::bson::doc! { "_id": id }, session).await
+            }
+
+            async fn update_one_with_session(
+                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
+                update: impl Into<::oximod::_mongodb::bson::Document> + Send,
+                session: &mut ::oximod::_mongodb::ClientSession,
+            ) -> Result<::oximod::_mongodb::results::UpdateResult, ::oximod::_error::oximod_error::OximodError> {
+                let collection = Self::get_collection()?;
+                use ::oximod::_error::printable::Printable;
+
+                let result = collection
+                    .update_one(filter.into(), update.into())
+                    .session(session)
+                    .await
+                    .map_err(|e| {
+                        ::oximod::_attach_printables!(
+                            ::oximod::_feature::conn::transaction::session_error(e, ::oximod::_error::oximod_error::OximodError::ConnectionError),
+                            "Failed to update a document in session. Make sure your update syntax is valid."
+                        )
+                    })?;
+
+                Ok(result)
+            }
+
+            async fn update_by_id_with_session(
+                id: ::oximod::_mongodb::bson::oid::ObjectId,
+                update: impl Into<::oximod::_mongodb::bson::Document> + Send,
+                session: &mut ::oximod::_mongodb::ClientSession,
+            ) -> Result<::oximod::_mongodb::results::UpdateResult, ::oximod::_error::oximod_error::OximodError> {
+                Self::update_one_with_session(::oximod::_mongodb::bson::doc! { "_id": id }, update, session).await
+            }
+
+            async fn delete_one_with_session(
+                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
+                session: &mut ::oximod::_mongodb::ClientSession,
+            ) -> Result<::oximod::_mongodb::results::DeleteResult, ::oximod::_error::oximod_error::OximodError> {
+                let collection = Self::get_collection()?;
+                use ::oximod::_error::printable::Printable;
+
+                let filter = filter.into();
+                Self::_hooks().run_pre_delete(&filter).await?;
+
+                let result = collection
+                    .delete_one(filter.clone())
+                    .session(session)
+                    .await
+                    .map_err(|e| {
+                        ::oximod::_attach_printables!(
+                            ::oximod::_feature::conn::transaction::session_error(e, ::oximod::_error::oximod_error::OximodError::ConnectionError),
+                            "Failed to delete a document in session. Ensure your filter is valid."
+                        )
+                    })?;
+
+                Self::_hooks().run_post_delete(&filter).await?;
+
+                Ok(result)
+            }
+
+            async fn delete_by_id_with_session(
+                id: ::oximod::_mongodb::bson::oid::ObjectId,
+                session: &mut ::oximod::_mongodb::ClientSession,
+            ) -> Result<::oximod::_mongodb::results::DeleteResult, ::oximod::_error::oximod_error::OximodError> {
+                Self::delete_one_with_session(::oximod::_mongodb::bson::doc! { "_id": id }, session).await
+            }
+
             async fn count(
                 filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
+            ) -> Result<u64, ::oximod::_error::oximod_error::OximodError> {
+                Self::count_with_options(filter, ::oximod::_mongodb::options::CountOptions::default()).await
+            }
+
+            async fn count_with_options(
+                filter: impl Into<::oximod::_mongodb::bson::Document> + Send,
+                options: ::oximod::_mongodb::options::CountOptions,
             ) -> Result<u64, ::oximod::_error::oximod_error::OximodError> {
                 let collection = Self::get_collection()?;
                 use ::oximod::_error::printable::Printable;
 
                 let count = collection
                     .count_documents(filter.into())
+                    .with_options(options)
                     .await
                     .map_err(|e| {
                         ::oximod::_attach_printables!(
@@ -468,31 +1682,32 @@ pub fn derive_model(input: TokenStream) -> TokenStream {
             ) -> Result<bool, ::oximod::_error::oximod_error::OximodError> {
                 use ::oximod::_error::printable::Printable;
 
-                Self::find_one(filter).await
-                    .map(|opt| opt.is_some())
-                    .map_err(|e| {
-                        ::oximod::_attach_printables!(
-                            e,
-                            "Failed to check document existence. Make sure your filter is valid and your connection is healthy."
-                        )
-                    })
-            }
-
-            async fn clear() -> Result<::oximod::_mongodb::results::DeleteResult, ::oximod::_error::oximod_error::OximodError> {
                 let collection = Self::get_collection()?;
-                use ::oximod::_error::printable::Printable;
 
-                let result = collection
-                    .delete_many(::oximod::_mongodb::bson::doc! {})
+                let count = collection
+                    .count_documents(filter.into())
+                    .limit(1)
                     .await
                     .map_err(|e| {
                         ::oximod::_attach_printables!(
                             ::oximod::_error::oximod_error::OximodError::ConnectionError(e.to_string()),
-                            "Failed to clear the collection. Ensure the mongodb connection is valid and the collection is writable."
+                            "Failed to check document existence. Make sure your filter is valid and your connection is healthy."
                         )
                     })?;
 
-                Ok(result)
+                Ok(count > 0)
+            }
+
+            async fn clear() -> Result<::oximod::_mongodb::results::DeleteResult, ::oximod::_error::oximod_error::OximodError> {
+                #clear_body
+            }
+
+            async fn drop_collection() -> Result<(), ::oximod::_error::oximod_error::OximodError> {
+                #drop_body
+            }
+
+            async fn validate_unique(&self) -> Result<(), ::oximod::_error::oximod_error::OximodError> {
+                #validate_unique_body
             }
 
             async fn aggregate(
@@ -515,3 +1730,73 @@ pub fn derive_model(input: TokenStream) -> TokenStream {
 
     expanded.into()
 }
+
+#[proc_macro]
+/// Builds a query document whose keys are checked against a model's fields at compile time.
+///
+/// Takes the model name and struct-literal fields. A plain value matches by equality, and
+/// `eq`, `ne`, `gt`, `gte`, `lt`, `lte`, and `in_` calls become the matching operators. Repeat
+/// a field to combine operators on it. Keys come from the `<model>_fields` constants, so a
+/// typo is a compile error and renamed fields use their stored key. Use `doc!` for dotted
+/// paths or operators not listed here.
+///
+/// # Example
+///
+/// ```ignore
+/// let adults = User::find(filter!(User { active: true, age: gte(18), age: lt(65) })).await?;
+/// // { "active": true, "age": { "$gte": 18, "$lt": 65 } }
+/// ```
+pub fn filter(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::ExprStruct);
+
+    match expand_filter_macro(input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(Validate, attributes(validate))]
+/// Procedural macro to derive the `Validate` trait for structs that are not database models.
+///
+/// Accepts the same `#[validate(...)]` field and struct attributes as `#[derive(Model)]`, except
+/// `unique`, but needs no `#[db]` or `#[collection]`, so request bodies and embedded types can be
+/// validated on their own. Do not combine it with `#[derive(Model)]`, which already implements `Validate`.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(Validate, Deserialize)]
+/// pub struct SignupRequest {
+///     #[validate(min_length = 3)]
+///     username: String,
+///     #[validate(email)]
+///     email: Option<String>,
+/// }
+///
+/// payload.validate()?;
+/// ```
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match parse_unique_fields(&input) {
+        Ok(unique_fields) => {
+            if let Some(field) = unique_fields.first() {
+                return syn::Error
+                    ::new(
+                        field.span,
+                        "`unique` needs a collection to check against; use #[derive(Model)] or #[index(unique)]"
+                    )
+                    .to_compile_error()
+                    .into();
+            }
+        }
+        Err(e) => {
+            return e.to_compile_error().into();
+        }
+    }
+
+    match generate_validate_impl_tokens(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
@@ -0,0 +1,249 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ parenthesized, parse::ParseStream, Attribute, Ident, Lit, LitInt, LitStr, Token };
+
+#[derive(Default, Debug)]
+/// Model-wide options set with the struct-level `#[model(...)]` attribute.
+///
+/// # Fields
+///
+/// - `auto_create_indexes`: (Optional) Whether `save()` creates the declared indexes before every insert.
+///   - If `false`, indexes are only created when `Model::sync_indexes()` is called,
+///     typically once at application startup.
+///   - Default: `true`
+/// - `allow_clear`: (Optional) Whether `clear()` may be called on the model.
+///   - If `false`, calling `Model::clear()` is a compile error, protecting production data
+///     from test helpers.
+///   - Default: `true`
+/// - `json_schema`: (Optional) Whether `Model::sync_indexes()` also installs a server-side
+///   `$jsonSchema` validator generated from the field types and `#[validate]` rules.
+///   - Default: `false`, so existing collections are left untouched.
+/// - `custom_id`: (Optional) Whether the `_id` field may have a type other than `Option<ObjectId>`,
+///   such as `String` or `Option<Uuid>`.
+///   - Without it, any other `_id` type is a compile error, since `None` is what lets MongoDB
+///     assign the id and `save()` reports it as an `ObjectId`.
+///   - Default: `false`
+/// - `read_preference`: (Optional) Default read preference for the model's collection,
+///   one of `"primary"`, `"primaryPreferred"`, `"secondary"`, `"secondaryPreferred"`, or `"nearest"`.
+/// - `write_concern`: (Optional) Default write concern for the model's collection,
+///   either `"majority"` or the number of nodes that must acknowledge a write.
+///
+/// Both default to whatever the client is configured with.
+///
+/// `server_selection_timeout_ms` is not supported. The driver only sets that timeout per
+/// client, so the option is a compile error that points to a named client registered with
+/// `set_client_with` and bound with `#[db("db_name", client = "name")]`.
+///
+/// # Example
+///
+/// ```rust
+/// #[model(auto_create_indexes = false, read_preference = "secondaryPreferred", write_concern = "majority")]
+/// pub struct User { /* ... */ }
+/// ```
+pub struct ModelArgs {
+    pub auto_create_indexes: Option<bool>,
+    pub allow_clear: Option<bool>,
+    pub read_preference: Option<TokenStream>,
+    pub write_concern: Option<TokenStream>,
+    pub json_schema: Option<bool>,
+    pub custom_id: Option<bool>,
+}
+
+pub fn parse_model_args(attr: &Attribute, args: &mut ModelArgs) -> syn::Result<()> {
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("auto_create_indexes") {
+            let lit: Lit = meta.value()?.parse()?;
+            if let Lit::Bool(lit_bool) = lit {
+                args.auto_create_indexes = Some(lit_bool.value);
+            } else {
+                return Err(
+                    syn::Error::new(lit.span(), "expected boolean literal for `auto_create_indexes`")
+                );
+            }
+        } else if meta.path.is_ident("allow_clear") {
+            let lit: Lit = meta.value()?.parse()?;
+            if let Lit::Bool(lit_bool) = lit {
+                args.allow_clear = Some(lit_bool.value);
+            } else {
+                return Err(syn::Error::new(lit.span(), "expected boolean literal for `allow_clear`"));
+            }
+        } else if meta.path.is_ident("json_schema") {
+            let lit: Lit = meta.value()?.parse()?;
+            if let Lit::Bool(lit_bool) = lit {
+                args.json_schema = Some(lit_bool.value);
+            } else {
+                return Err(syn::Error::new(lit.span(), "expected boolean literal for `json_schema`"));
+            }
+        } else if meta.path.is_ident("custom_id") {
+            let lit: Lit = meta.value()?.parse()?;
+            if let Lit::Bool(lit_bool) = lit {
+                args.custom_id = Some(lit_bool.value);
+            } else {
+                return Err(syn::Error::new(lit.span(), "expected boolean literal for `custom_id`"));
+            }
+        } else if meta.path.is_ident("read_preference") {
+            let lit: LitStr = meta.value()?.parse()?;
+            let variant = match lit.value().as_str() {
+                "primary" => quote! { Primary },
+                "primaryPreferred" => quote! { PrimaryPreferred { options: None } },
+                "secondary" => quote! { Secondary { options: None } },
+                "secondaryPreferred" => quote! { SecondaryPreferred { options: None } },
+                "nearest" => quote! { Nearest { options: None } },
+                _ => {
+                    return Err(
+                        syn::Error::new(
+                            lit.span(),
+                            "expected one of \"primary\", \"primaryPreferred\", \"secondary\", \"secondaryPreferred\", \"nearest\""
+                        )
+                    );
+                }
+            };
+            args.read_preference = Some(
+                quote! {
+                    ::oximod::_mongodb::options::SelectionCriteria::ReadPreference(
+                        ::oximod::_mongodb::options::ReadPreference::#variant
+                    )
+                }
+            );
+        } else if meta.path.is_ident("write_concern") {
+            let lit: Lit = meta.value()?.parse()?;
+            let write_concern = match &lit {
+                Lit::Str(lit_str) if lit_str.value() == "majority" => {
+                    quote! { ::oximod::_mongodb::options::WriteConcern::majority() }
+                }
+                Lit::Int(lit_int) => {
+                    let nodes: u32 = lit_int.base10_parse()?;
+                    if nodes == 0 {
+                        return Err(
+                            syn::Error::new(lit.span(), "unacknowledged writes (`write_concern = 0`) are not supported")
+                        );
+                    }
+                    quote! { ::oximod::_mongodb::options::WriteConcern::nodes(#nodes) }
+                }
+                _ => {
+                    return Err(
+                        syn::Error::new(lit.span(), "expected \"majority\" or a number of nodes for `write_concern`")
+                    );
+                }
+            };
+            args.write_concern = Some(write_concern);
+        } else if meta.path.is_ident("server_selection_timeout_ms") {
+            return Err(
+                meta.error(
+                    "the server selection timeout is a client setting and can't be set per model; register a client with `set_client_with` (or `serverSelectionTimeoutMS` in the URI passed to `set_client`) and bind the model with #[db(\"db_name\", client = \"name\")]"
+                )
+            );
+        } else {
+            return Err(meta.error("unknown model option"));
+        }
+
+        Ok(())
+    })
+}
+
+/// Parses `#[db("db_name")]` or `#[db("db_name", client = "name")]`.
+///
+/// Returns the database name and, if given, the name of the client registered
+/// with `set_client` that the model should use instead of the global client.
+pub fn parse_db_args(attr: &Attribute) -> syn::Result<(LitStr, Option<LitStr>)> {
+    attr.parse_args_with(|input: ParseStream| {
+        let db: LitStr = input.parse().map_err(|e| {
+            syn::Error::new(e.span(), "Expected #[db(\"db_name\"]")
+        })?;
+
+        let mut client = None;
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key != "client" {
+                return Err(syn::Error::new(key.span(), "unknown db option, expected `client`"));
+            }
+            input.parse::<Token![=]>()?;
+            client = Some(input.parse::<LitStr>()?);
+            input.parse::<Option<Token![,]>>()?;
+        }
+
+        Ok((db, client))
+    })
+}
+
+/// Capped-collection settings from `#[collection(..., capped(size = N, max = M))]`.
+#[derive(Debug)]
+pub struct CappedArgs {
+    /// The maximum size of the collection in bytes.
+    pub size: u64,
+    /// The maximum number of documents, if limited.
+    pub max: Option<u64>,
+}
+
+/// Parses `#[collection("name")]`, `#[collection(name = "name")]`, or either form followed by
+/// `capped(size = N, max = M)`.
+///
+/// Returns the collection name and, if given, the capped-collection settings. `size` is
+/// required inside `capped(...)`; `max` is optional.
+pub fn parse_collection_args(attr: &Attribute) -> syn::Result<(LitStr, Option<CappedArgs>)> {
+    attr.parse_args_with(|input: ParseStream| {
+        let mut name = None;
+        let mut capped = None;
+
+        if input.peek(LitStr) {
+            name = Some(input.parse::<LitStr>()?);
+            input.parse::<Option<Token![,]>>()?;
+        }
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key == "name" {
+                input.parse::<Token![=]>()?;
+                name = Some(input.parse::<LitStr>()?);
+            } else if key == "capped" {
+                let content;
+                parenthesized!(content in input);
+                capped = Some(parse_capped_args(&content, &key)?);
+            } else {
+                return Err(
+                    syn::Error::new(key.span(), "unknown collection option, expected `name` or `capped`")
+                );
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        let name = name.ok_or_else(|| {
+            syn::Error::new_spanned(attr, "Expected #[collection(\"collection_name\"]")
+        })?;
+        Ok((name, capped))
+    })
+}
+
+fn parse_capped_args(input: ParseStream, capped: &Ident) -> syn::Result<CappedArgs> {
+    let mut size = None;
+    let mut max = None;
+
+    while !input.is_empty() {
+        let key: Ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let value: LitInt = input.parse()?;
+        let parsed = value.base10_parse::<u64>()?;
+        if parsed == 0 || parsed > (i64::MAX as u64) {
+            return Err(
+                syn::Error::new(value.span(), format!("`{}` must be between 1 and {}", key, i64::MAX))
+            );
+        }
+        if key == "size" {
+            size = Some(parsed);
+        } else if key == "max" {
+            max = Some(parsed);
+        } else {
+            return Err(syn::Error::new(key.span(), "unknown capped option, expected `size` or `max`"));
+        }
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+
+    let size = size.ok_or_else(|| {
+        syn::Error::new(capped.span(), "capped collections need a size, e.g. `capped(size = 1048576)`")
+    })?;
+    Ok(CappedArgs { size, max })
+}
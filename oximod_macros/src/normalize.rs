@@ -0,0 +1,114 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ Attribute, Type };
+
+use crate::default::option_inner_type;
+
+/// A single `#[normalize(...)]` transform.
+pub enum NormalizeStep {
+    Trim,
+    Lowercase,
+    Uppercase,
+}
+
+/// Parsed `#[normalize(...)]` options for one field.
+pub struct NormalizeDefinition {
+    /// The key the field is stored under, which the transform edits in the serialized document.
+    pub key: String,
+    /// Transforms in the order they were written.
+    pub steps: Vec<NormalizeStep>,
+}
+
+/// Parses `#[normalize(trim, lowercase)]` and friends on a `String` or `Option<String>` field.
+pub fn parse_normalize_args(attr: &Attribute, ty: &Type, key: String) -> syn::Result<NormalizeDefinition> {
+    let mut definition = NormalizeDefinition { key, steps: Vec::new() };
+
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("trim") {
+            definition.steps.push(NormalizeStep::Trim);
+        } else if meta.path.is_ident("lowercase") {
+            definition.steps.push(NormalizeStep::Lowercase);
+        } else if meta.path.is_ident("uppercase") {
+            definition.steps.push(NormalizeStep::Uppercase);
+        } else {
+            return Err(meta.error("unsupported normalize option; expected `trim`, `lowercase`, or `uppercase`"));
+        }
+        Ok(())
+    })?;
+
+    let inner = option_inner_type(ty).unwrap_or(ty);
+    let is_string = matches!(
+        inner,
+        Type::Path(type_path) if type_path.path.segments.last().is_some_and(|s| s.ident == "String")
+    );
+    if !is_string {
+        return Err(
+            syn::Error::new_spanned(ty, "#[normalize(...)] only applies to String and Option<String> fields")
+        );
+    }
+
+    Ok(definition)
+}
+
+/// Generates the statements that normalize the field inside the serialized `document`.
+///
+/// Runs when the model is converted into a document. Validation checks the same normalized
+/// value, computed by [`generate_normalized_value_tokens`], so the two always agree.
+pub fn generate_normalize_tokens(definition: &NormalizeDefinition) -> TokenStream {
+    let key = &definition.key;
+
+    if definition.steps.is_empty() {
+        return quote! {};
+    }
+
+    let steps = step_tokens(definition);
+
+    quote! {
+        if let Some(::oximod::_mongodb::bson::Bson::String(value)) = document.get_mut(#key) {
+            let mut normalized = ::std::mem::take(value);
+            #steps
+            *value = normalized;
+        }
+    }
+}
+
+/// Generates an expression evaluating to a normalized copy of `field`, a `String` or, if
+/// `is_option`, an `Option<String>`, for `validate()` to check without modifying the struct.
+pub fn generate_normalized_value_tokens(
+    definition: &NormalizeDefinition,
+    field: &TokenStream,
+    is_option: bool
+) -> TokenStream {
+    let steps = step_tokens(definition);
+
+    if is_option {
+        quote! {
+            #field.as_ref().map(|value| {
+                let mut normalized = value.clone();
+                #steps
+                normalized
+            })
+        }
+    } else {
+        quote! {
+            {
+                let mut normalized = #field.clone();
+                #steps
+                normalized
+            }
+        }
+    }
+}
+
+/// The transforms of `definition`, applied in order to a `normalized: String` binding.
+fn step_tokens(definition: &NormalizeDefinition) -> TokenStream {
+    let steps = definition.steps.iter().map(|step| {
+        match step {
+            NormalizeStep::Trim => quote! { normalized = normalized.trim().to_string(); },
+            NormalizeStep::Lowercase => quote! { normalized = normalized.to_lowercase(); },
+            NormalizeStep::Uppercase => quote! { normalized = normalized.to_uppercase(); },
+        }
+    });
+
+    quote! { #(#steps)* }
+}
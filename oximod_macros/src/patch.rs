@@ -0,0 +1,138 @@
+use proc_macro2::TokenStream;
+use quote::{ format_ident, quote };
+use syn::{ ext::IdentExt, DeriveInput };
+
+use crate::default::option_inner_type;
+use crate::normalize::{ generate_normalized_value_tokens, NormalizeDefinition };
+use crate::serde_name::{ is_skipped, parse_rename_all, serde_codec_attrs, serialized_name };
+
+/// Generates the `<Model>Patch` type for partial updates such as HTTP `PATCH` bodies.
+///
+/// Every stored field except `_id` becomes an `Option` under the same name and stored key, so a
+/// patch deserializes from the same JSON as the model. `Option<T>` fields stay `Option<T>`, so
+/// `None` always means "leave unchanged"; clearing a field is left to `<Model>Update::new().unset(..)`.
+/// `to_update_doc()` `$set`s the provided fields after the field's `#[normalize]` steps. A field's
+/// `#[serde(with)]`, `deserialize_with`, and `serialize_with` apply to the patch too, so it reads
+/// and writes the same shapes as the model; such values are cloned when building the update.
+///
+/// # Example
+///
+/// ```rust
+/// // { "name": "Alice" } deserialized into a `UserPatch`
+/// let update = patch.to_update_doc()?;
+/// // { "$set": { "name": "Alice" } }
+/// ```
+pub fn generate_patch_tokens(input: &DeriveInput, normalizers: &[NormalizeDefinition]) -> TokenStream {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let patch_name = format_ident!("{}Patch", name);
+    let doc = format!(
+        "Partial update for [`{}`]: every field is optional and only the provided ones are `$set`.",
+        name
+    );
+    let rename_all = parse_rename_all(&input.attrs);
+
+    let mut fields = Vec::new();
+    let mut idents = Vec::new();
+    let mut sets = Vec::new();
+    let mut deserializers = Vec::new();
+    if let syn::Data::Struct(data_struct) = &input.data {
+        for field in data_struct.fields.iter() {
+            let Some(ident) = &field.ident else {
+                continue;
+            };
+            let key = serialized_name(field, rename_all.as_deref());
+            if key == "_id" || is_skipped(&field.attrs) {
+                continue;
+            }
+
+            let field_vis = &field.vis;
+            let field_ty = &field.ty;
+            let is_option = option_inner_type(field_ty).is_some();
+            let ty = option_inner_type(field_ty).unwrap_or(field_ty);
+
+            let de_codecs = serde_codec_attrs(&field.attrs, false);
+            let deserialize_with = if de_codecs.is_empty() {
+                quote! {}
+            } else {
+                let helper = format_ident!("__deserialize_{}", ident.unraw());
+                let path = format!("{}::{}", patch_name, helper);
+                let value = if is_option { quote! { value } } else { quote! { Some(value) } };
+                deserializers.push(quote! {
+                    fn #helper<'de, D>(deserializer: D) -> Result<Option<#ty>, D::Error>
+                    where
+                        D: ::oximod::_serde::Deserializer<'de>,
+                    {
+                        #[derive(::oximod::_serde::Deserialize)]
+                        #[serde(crate = "::oximod::_serde")]
+                        struct Value(#[serde(#(#de_codecs),*)] #field_ty);
+                        <Value as ::oximod::_serde::Deserialize>::deserialize(deserializer).map(|Value(value)| #value)
+                    }
+                });
+                quote! { , deserialize_with = #path }
+            };
+            fields.push(quote! {
+                #[serde(default, rename = #key #deserialize_with)]
+                #field_vis #ident: Option<#ty>,
+            });
+
+            let normalize = normalizers
+                .iter()
+                .find(|definition| definition.key == key)
+                .map(|definition| {
+                    let normalized = generate_normalized_value_tokens(definition, &quote! { value }, false);
+                    quote! { let value = &#normalized; }
+                });
+            let ser_codecs = serde_codec_attrs(&field.attrs, true);
+            let set = if ser_codecs.is_empty() {
+                quote! { update.set(#key, value); }
+            } else {
+                let value = if is_option { quote! { Some(value.clone()) } } else { quote! { value.clone() } };
+                quote! {
+                    #[derive(::oximod::_serde::Serialize)]
+                    #[serde(crate = "::oximod::_serde")]
+                    struct Value(#[serde(#(#ser_codecs),*)] #field_ty);
+                    update.set(#key, &Value(#value));
+                }
+            };
+            sets.push(quote! {
+                if let Some(value) = &self.#ident {
+                    #normalize
+                    #set
+                }
+            });
+            idents.push(ident);
+        }
+    }
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Default, ::oximod::_serde::Deserialize)]
+        #[serde(crate = "::oximod::_serde")]
+        #vis struct #patch_name {
+            #(#fields)*
+        }
+
+        impl #patch_name {
+            /// Whether no field was provided.
+            pub fn is_empty(&self) -> bool {
+                true #(&& self.#idents.is_none())*
+            }
+
+            /// Produces `{ "$set": { ... } }` with the provided fields, normalized, under their stored keys.
+            ///
+            /// An empty patch produces an empty document, which MongoDB rejects as an update;
+            /// check [`is_empty`](Self::is_empty) first. Fails with
+            /// `OximodError::SerializationError` if a value can't be represented in BSON.
+            pub fn to_update_doc(
+                &self
+            ) -> Result<::oximod::_mongodb::bson::Document, ::oximod::_error::oximod_error::OximodError> {
+                let mut update = ::oximod::_feature::update::ModelUpdate::default();
+                #(#sets)*
+                update.build()
+            }
+
+            #(#deserializers)*
+        }
+    }
+}
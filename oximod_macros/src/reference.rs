@@ -0,0 +1,118 @@
+use proc_macro2::TokenStream;
+use quote::{ format_ident, quote };
+use syn::{ Attribute, GenericArgument, Ident, Path, PathArguments, Type };
+
+use crate::default::option_inner_type;
+
+/// A field declared with `#[reference(Model)]`, holding the `_id` of a document of another model.
+pub struct ReferenceDefinition {
+    pub field_ident: Ident,
+    pub target: Path,
+    pub kind: ReferenceKind,
+}
+
+/// How the referenced `_id`s are stored, which decides the populate helper's return type.
+pub enum ReferenceKind {
+    /// `ObjectId`, populated as `Option<Target>`.
+    One,
+    /// `Option<ObjectId>`, populated as `Option<Target>`.
+    Optional,
+    /// `Vec<ObjectId>`, populated as `Vec<Target>` with a single `$in` query.
+    Many,
+}
+
+pub fn parse_reference_args(
+    attr: &Attribute,
+    field_ident: &Ident,
+    field_ty: &Type
+) -> syn::Result<ReferenceDefinition> {
+    let target: Path = attr.parse_args()?;
+
+    let kind = if is_object_id(field_ty) {
+        ReferenceKind::One
+    } else if option_inner_type(field_ty).is_some_and(is_object_id) {
+        ReferenceKind::Optional
+    } else if vec_inner_type(field_ty).is_some_and(is_object_id) {
+        ReferenceKind::Many
+    } else {
+        return Err(
+            syn::Error::new_spanned(
+                field_ty,
+                "#[reference] expects an `ObjectId`, `Option<ObjectId>`, or `Vec<ObjectId>` field"
+            )
+        );
+    };
+
+    Ok(ReferenceDefinition { field_ident: field_ident.clone(), target, kind })
+}
+
+fn is_object_id(ty: &Type) -> bool {
+    matches!(ty, Type::Path(type_path) if type_path.path.segments.last().is_some_and(|s| s.ident == "ObjectId"))
+}
+
+pub fn vec_inner_type(ty: &Type) -> Option<&Type> {
+    if let Type::Path(type_path) = ty {
+        let segment = type_path.path.segments.last()?;
+        if segment.ident == "Vec" {
+            if let PathArguments::AngleBracketed(params) = &segment.arguments {
+                if let Some(GenericArgument::Type(inner_ty)) = params.args.first() {
+                    return Some(inner_ty);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Generates `populate_<name>()`, where `<name>` is the field name without its `_id`/`_ids`
+/// suffix (`user_id` becomes `populate_user`, `tag_ids` becomes `populate_tags`).
+///
+/// A dangling reference is not an error: it yields `None`, or is left out of the `Vec`.
+pub fn generate_populate_tokens(reference: &ReferenceDefinition) -> TokenStream {
+    let field_ident = &reference.field_ident;
+    let target = &reference.target;
+
+    let field_name = field_ident.to_string();
+    let field_name = field_name.trim_start_matches("r#");
+    let populated = if let Some(stem) = field_name.strip_suffix("_ids") {
+        format!("{}s", stem)
+    } else if let Some(stem) = field_name.strip_suffix("_id") {
+        stem.to_string()
+    } else {
+        field_name.to_string()
+    };
+    let method = format_ident!("populate_{}", populated);
+    let doc = format!("Loads the `{}` referenced by `{}`.", quote!(#target), field_name);
+
+    match reference.kind {
+        ReferenceKind::One =>
+            quote! {
+                #[doc = #doc]
+                pub async fn #method(&self) -> Result<Option<#target>, ::oximod::_error::oximod_error::OximodError> {
+                    <#target as ::oximod::_feature::model::Model>::find_by_id(self.#field_ident).await
+                }
+            },
+        ReferenceKind::Optional =>
+            quote! {
+                #[doc = #doc]
+                pub async fn #method(&self) -> Result<Option<#target>, ::oximod::_error::oximod_error::OximodError> {
+                    match self.#field_ident {
+                        Some(id) => <#target as ::oximod::_feature::model::Model>::find_by_id(id).await,
+                        None => Ok(None),
+                    }
+                }
+            },
+        ReferenceKind::Many =>
+            quote! {
+                #[doc = #doc]
+                pub async fn #method(&self) -> Result<Vec<#target>, ::oximod::_error::oximod_error::OximodError> {
+                    if self.#field_ident.is_empty() {
+                        return Ok(Vec::new());
+                    }
+                    <#target as ::oximod::_feature::model::Model>::find(
+                        ::oximod::_mongodb::bson::doc! { "_id": { "$in": &self.#field_ident } }
+                    ).await
+                }
+            },
+    }
+}
@@ -0,0 +1,313 @@
+use proc_macro2::{ Literal, TokenStream };
+use quote::quote;
+use syn::{ DeriveInput, Type };
+use crate::default::option_inner_type;
+use crate::reference::vec_inner_type;
+use crate::serde_name::{ is_skipped, parse_rename_all, serialized_name };
+use crate::validate::{ parse_validate_args, NumericBound, ValidateArgs };
+
+/// Generates a `$jsonSchema` document from the struct's field types and `#[validate]` rules,
+/// for `#[model(json_schema = true)]`.
+///
+/// Field types map to `bsonType`: strings to `"string"`, integers to `["int", "long"]`,
+/// floats to `"double"`, `bool`, `ObjectId`, and BSON `DateTime` to their BSON types, and
+/// `Vec<T>` to `"array"` with `items`. `Option<T>` also allows `"null"` unless the field is
+/// `required`. Types without an obvious BSON type, such as embedded structs, are left
+/// unconstrained.
+///
+/// Rules with a JSON Schema equivalent are translated: `required`, `min_length`,
+/// `max_length`, `len`, `non_empty`, `min_items`, `max_items`, `one_of`, `pattern`, `min`,
+/// `max`, `exclusive_min`, `exclusive_max`, `positive`, `negative`, `non_negative`, and
+/// `multiple_of`. The rest, such as `email` or `with`, only run in `validate()`. When several
+/// rules bound the same side, only the tightest one is emitted, and `one_of` on a nullable
+/// field also allows `null`.
+///
+/// # Example
+///
+/// ```rust
+/// // #[validate(min_length = 3)] name: String, age: Option<i32>
+/// // { "bsonType": "object", "properties": {
+/// //     "name": { "bsonType": "string", "minLength": 3 },
+/// //     "age": { "bsonType": ["int", "long", "null"] } } }
+/// ```
+pub fn generate_json_schema_tokens(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let rename_all = parse_rename_all(&input.attrs);
+    let mut required = Vec::new();
+    let mut properties = Vec::new();
+
+    if let syn::Data::Struct(data_struct) = &input.data {
+        for field in data_struct.fields.iter() {
+            let Some(ident) = &field.ident else {
+                continue;
+            };
+            let key = serialized_name(field, rename_all.as_deref());
+            if key == "_id" || is_skipped(&field.attrs) {
+                continue;
+            }
+
+            let mut rules = Vec::new();
+            for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("validate")) {
+                rules.push(parse_validate_args(attr, ident.to_string(), key.clone(), &field.ty)?.args);
+            }
+            let is_required = rules.iter().any(|args| args.required == Some(true));
+
+            let (nullable, ty) = match option_inner_type(&field.ty) {
+                Some(inner) => (!is_required, inner),
+                None => (false, &field.ty),
+            };
+            let mut entries = type_entries(ty, nullable);
+            let is_string = bson_types(ty) == ["string"];
+            let mut constraints = Constraints::default();
+            for args in &rules {
+                constraints.add(args, is_string);
+            }
+            constraints.push_entries(nullable, &mut entries);
+
+            if is_required {
+                required.push(key.clone());
+            }
+            properties.push(schema_doc(&key, &entries));
+        }
+    }
+
+    let required = if required.is_empty() {
+        quote! {}
+    } else {
+        quote! { "required": [#(#required),*], }
+    };
+
+    Ok(
+        quote! {
+        ::oximod::_mongodb::bson::doc! {
+            "bsonType": "object",
+            #required
+            "properties": { #(#properties),* },
+        }
+    }
+    )
+}
+
+/// Returns the `bsonType` (and `items`, for arrays) entries of a field of type `ty`.
+fn type_entries(ty: &Type, nullable: bool) -> Vec<(&'static str, TokenStream)> {
+    let mut types = bson_types(ty);
+    if types.is_empty() {
+        return vec![];
+    }
+    if nullable {
+        types.push("null");
+    }
+
+    let mut entries = vec![(
+        "bsonType",
+        if types.len() == 1 {
+            let ty = types[0];
+            quote! { #ty }
+        } else {
+            quote! { [#(#types),*] }
+        },
+    )];
+
+    if let Some(inner) = vec_inner_type(ty) {
+        let items = type_entries(inner, false);
+        if !items.is_empty() {
+            let keys = items.iter().map(|(key, _)| key);
+            let values = items.iter().map(|(_, value)| value);
+            entries.push(("items", quote! { { #(#keys: #values),* } }));
+        }
+    }
+
+    entries
+}
+
+/// Maps a Rust type to the BSON types it serializes to, or nothing if it isn't obvious.
+fn bson_types(ty: &Type) -> Vec<&'static str> {
+    let Type::Path(type_path) = ty else {
+        return vec![];
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return vec![];
+    };
+
+    match segment.ident.to_string().as_str() {
+        "String" => vec!["string"],
+        "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "isize" | "usize" =>
+            vec!["int", "long"],
+        "f32" | "f64" => vec!["double"],
+        "bool" => vec!["bool"],
+        "ObjectId" => vec!["objectId"],
+        // `chrono::DateTime<Tz>` serializes as a string; only the BSON one is a date.
+        "DateTime" if segment.arguments.is_empty() => vec!["date"],
+        "Vec" => vec!["array"],
+        _ => vec![],
+    }
+}
+
+/// The constraints of a field, merged across all of its `#[validate]` attributes so each
+/// keyword appears once with the tightest bound.
+#[derive(Default)]
+struct Constraints {
+    min_length: Option<u32>,
+    max_length: Option<u32>,
+    min_items: Option<u32>,
+    max_items: Option<u32>,
+    one_of: Option<Vec<String>>,
+    pattern: Option<String>,
+    /// The lower bound and whether it is exclusive.
+    minimum: Option<(NumericBound, bool)>,
+    /// The upper bound and whether it is exclusive.
+    maximum: Option<(NumericBound, bool)>,
+    multiple_of: Option<i64>,
+}
+
+impl Constraints {
+    fn add(&mut self, args: &ValidateArgs, is_string: bool) {
+        let zero = NumericBound::Int(0);
+
+        if let Some(min) = args.min_length {
+            raise(&mut self.min_length, min);
+        }
+        if let Some(max) = args.max_length {
+            lower(&mut self.max_length, max);
+        }
+        if let Some(len) = args.len {
+            raise(&mut self.min_length, len);
+            lower(&mut self.max_length, len);
+        }
+        if args.non_empty == Some(true) && is_string {
+            raise(&mut self.min_length, 1);
+        }
+        if let Some(min) = args.min_items {
+            raise(&mut self.min_items, min);
+        }
+        if let Some(max) = args.max_items {
+            lower(&mut self.max_items, max);
+        }
+        if let Some(values) = &args.one_of {
+            self.one_of = Some(values.clone());
+        }
+        if let Some(pattern) = &args.pattern {
+            self.pattern = Some(pattern.clone());
+        }
+        if let Some(multiple_of) = args.multiple_of {
+            self.multiple_of = Some(multiple_of);
+        }
+
+        if let Some(min) = args.min {
+            self.add_minimum(min, false);
+        }
+        if args.non_negative == Some(true) {
+            self.add_minimum(zero, false);
+        }
+        if let Some(min) = args.exclusive_min {
+            self.add_minimum(min, true);
+        }
+        if args.positive == Some(true) {
+            self.add_minimum(zero, true);
+        }
+        if let Some(max) = args.max {
+            self.add_maximum(max, false);
+        }
+        if let Some(max) = args.exclusive_max {
+            self.add_maximum(max, true);
+        }
+        if args.negative == Some(true) {
+            self.add_maximum(zero, true);
+        }
+    }
+
+    /// Keeps the higher lower bound; at equal values the exclusive one is tighter.
+    fn add_minimum(&mut self, bound: NumericBound, exclusive: bool) {
+        let tighter = match self.minimum {
+            None => true,
+            Some((current, current_exclusive)) => {
+                let (new, old) = (as_f64(bound), as_f64(current));
+                new > old || (new == old && exclusive && !current_exclusive)
+            }
+        };
+        if tighter {
+            self.minimum = Some((bound, exclusive));
+        }
+    }
+
+    /// Keeps the lower upper bound; at equal values the exclusive one is tighter.
+    fn add_maximum(&mut self, bound: NumericBound, exclusive: bool) {
+        let tighter = match self.maximum {
+            None => true,
+            Some((current, current_exclusive)) => {
+                let (new, old) = (as_f64(bound), as_f64(current));
+                new < old || (new == old && exclusive && !current_exclusive)
+            }
+        };
+        if tighter {
+            self.maximum = Some((bound, exclusive));
+        }
+    }
+
+    /// Appends the merged constraints. `nullable` lets `enum` accept the `null` an unset
+    /// `Option<T>` is stored as, since `enum` applies to every value, not only strings.
+    fn push_entries(&self, nullable: bool, entries: &mut Vec<(&'static str, TokenStream)>) {
+        let mut push = |key: &'static str, value: TokenStream| entries.push((key, value));
+
+        if let Some(min) = self.min_length {
+            push("minLength", count(min));
+        }
+        if let Some(max) = self.max_length {
+            push("maxLength", count(max));
+        }
+        if let Some(min) = self.min_items {
+            push("minItems", count(min));
+        }
+        if let Some(max) = self.max_items {
+            push("maxItems", count(max));
+        }
+        if let Some(values) = &self.one_of {
+            let null = if nullable { quote! { , null } } else { quote! {} };
+            push("enum", quote! { [#(#values),* #null] });
+        }
+        if let Some(pattern) = &self.pattern {
+            push("pattern", quote! { #pattern });
+        }
+        if let Some((min, exclusive)) = &self.minimum {
+            push("minimum", quote! { #min });
+            if *exclusive {
+                push("exclusiveMinimum", quote! { true });
+            }
+        }
+        if let Some((max, exclusive)) = &self.maximum {
+            push("maximum", quote! { #max });
+            if *exclusive {
+                push("exclusiveMaximum", quote! { true });
+            }
+        }
+        if let Some(multiple_of) = self.multiple_of {
+            push("multipleOf", quote! { #multiple_of });
+        }
+    }
+}
+
+fn raise(slot: &mut Option<u32>, value: u32) {
+    *slot = Some(slot.map_or(value, |current| current.max(value)));
+}
+
+fn lower(slot: &mut Option<u32>, value: u32) {
+    *slot = Some(slot.map_or(value, |current| current.min(value)));
+}
+
+fn as_f64(bound: NumericBound) -> f64 {
+    match bound {
+        NumericBound::Int(value) => value as f64,
+        NumericBound::Float(value) => value,
+    }
+}
+
+fn count(value: u32) -> TokenStream {
+    let value = Literal::u32_unsuffixed(value);
+    quote! { #value }
+}
+
+/// Renders `key: { entries }`.
+fn schema_doc(key: &str, entries: &[(&'static str, TokenStream)]) -> TokenStream {
+    let keys = entries.iter().map(|(key, _)| key);
+    let values = entries.iter().map(|(_, value)| value);
+    quote! { #key: { #(#keys: #values),* } }
+}
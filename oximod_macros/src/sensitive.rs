@@ -0,0 +1,80 @@
+use proc_macro2::TokenStream;
+use quote::{ quote, quote_spanned };
+use syn::{ spanned::Spanned, DeriveInput };
+
+/// Generates a `Debug` impl that prints `#[sensitive]` fields as `"***"`.
+///
+/// Returns no tokens when no field is marked, leaving `#[derive(Debug)]` to the user.
+/// A marked model must not also derive `Debug`. A `#[derive(Debug)]` written as its own
+/// attribute after `#[derive(Model)]` is reported here. One in the same list is invisible to
+/// the macro, so the compiler reports the conflicting impl instead, pointing at `#[sensitive]`.
+///
+/// # Example
+///
+/// ```rust
+/// // #[sensitive] password_hash: String
+/// format!("{:?}", user); // User { name: "alice", password_hash: "***" }
+/// ```
+pub fn generate_debug_tokens(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+
+    let syn::Data::Struct(data_struct) = &input.data else {
+        return Ok(quote! {});
+    };
+
+    let mut sensitive_span = None;
+    let mut fields = Vec::new();
+    for field in data_struct.fields.iter() {
+        let Some(ident) = &field.ident else {
+            continue;
+        };
+        let label = ident.to_string().trim_start_matches("r#").to_string();
+
+        let mut sensitive = false;
+        for attr in &field.attrs {
+            if attr.path().is_ident("sensitive") {
+                attr.meta.require_path_only()?;
+                sensitive = true;
+                sensitive_span.get_or_insert(attr.span());
+            }
+        }
+
+        fields.push(if sensitive {
+            quote! { .field(#label, &"***") }
+        } else {
+            quote! { .field(#label, &self.#ident) }
+        });
+    }
+
+    let Some(span) = sensitive_span else {
+        return Ok(quote! {});
+    };
+
+    for attr in &input.attrs {
+        if attr.path().is_ident("derive") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("Debug") {
+                    return Err(
+                        meta.error(
+                            "#[sensitive] generates a redacting Debug impl; remove Debug from #[derive(...)]"
+                        )
+                    );
+                }
+                Ok(())
+            })?;
+        }
+    }
+
+    let label = name.to_string();
+    Ok(
+        quote_spanned! {span=>
+            impl ::std::fmt::Debug for #name {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.debug_struct(#label)
+                        #(#fields)*
+                        .finish()
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,190 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ parenthesized, Attribute, Expr, Field, LitStr, Token };
+
+/// Reads the container-level `#[serde(rename_all = "...")]` rule, if any.
+///
+/// Only the serialized form matters for the keys stored in MongoDB, so
+/// `rename_all(serialize = "...")` is honored as well. Malformed serde attributes
+/// are ignored here and left for serde's own derive to report.
+pub fn parse_rename_all(attrs: &[Attribute]) -> Option<String> {
+    serde_key(attrs, "rename_all")
+}
+
+/// Returns the key a field is stored under: its `#[serde(rename = "...")]`, otherwise
+/// its Rust name transformed by the container's `rename_all` rule.
+pub fn serialized_name(field: &Field, rename_all: Option<&str>) -> String {
+    if let Some(rename) = serde_key(&field.attrs, "rename") {
+        return rename;
+    }
+
+    let name = field.ident
+        .as_ref()
+        .map(|ident| ident.to_string())
+        .unwrap_or_default();
+    let name = name.trim_start_matches("r#");
+
+    match rename_all {
+        Some(rule) => apply_rename_rule(name, rule),
+        None => name.to_string(),
+    }
+}
+
+/// Whether `#[serde(default)]` or `#[serde(default = "...")]` is present.
+pub fn has_serde_default(attrs: &[Attribute]) -> bool {
+    let mut found = false;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("default") {
+                found = true;
+            }
+            if meta.input.peek(Token![=]) {
+                let _: Expr = meta.value()?.parse()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                let _content;
+                parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        });
+    }
+
+    found
+}
+
+/// Whether `#[serde(skip)]` or `#[serde(skip_serializing)]` keeps the field out of stored
+/// documents. `skip_serializing_if` only skips some values, so it doesn't count.
+pub fn is_skipped(attrs: &[Attribute]) -> bool {
+    let mut found = false;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") || meta.path.is_ident("skip_serializing") {
+                found = true;
+            }
+            if meta.input.peek(Token![=]) {
+                let _: Expr = meta.value()?.parse()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                let _content;
+                parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        });
+    }
+
+    found
+}
+
+/// Returns the field's `#[serde(with = "...")]` and either `serialize_with` or
+/// `deserialize_with`, as `name = "path"` items to copy onto a wrapper of the field's type.
+///
+/// Generated types that carry a field's value (typed updates, patches) use these so the value
+/// has the same BSON shape as in the model.
+pub fn serde_codec_attrs(attrs: &[Attribute], serialize: bool) -> Vec<TokenStream> {
+    let directional = if serialize { "serialize_with" } else { "deserialize_with" };
+    let mut codecs = Vec::new();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("with") || meta.path.is_ident(directional) {
+                let path = &meta.path;
+                let lit: LitStr = meta.value()?.parse()?;
+                codecs.push(quote! { #path = #lit });
+            } else if meta.input.peek(Token![=]) {
+                let _: Expr = meta.value()?.parse()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                let _content;
+                parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        });
+    }
+
+    codecs
+}
+
+fn serde_key(attrs: &[Attribute], key: &str) -> Option<String> {
+    let mut value = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(key) {
+                if meta.input.peek(Token![=]) {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    value = Some(lit.value());
+                } else {
+                    meta.parse_nested_meta(|inner| {
+                        let lit: LitStr = inner.value()?.parse()?;
+                        if inner.path.is_ident("serialize") {
+                            value = Some(lit.value());
+                        }
+                        Ok(())
+                    })?;
+                }
+            } else if meta.input.peek(Token![=]) {
+                let _: Expr = meta.value()?.parse()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                let _content;
+                parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        });
+    }
+
+    value
+}
+
+/// Applies a serde `rename_all` rule to a `snake_case` field name, mirroring serde's own rules.
+fn apply_rename_rule(name: &str, rule: &str) -> String {
+    match rule {
+        "lowercase" => name.to_ascii_lowercase(),
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => name.to_ascii_uppercase(),
+        "PascalCase" => to_pascal_case(name),
+        "camelCase" => {
+            let pascal = to_pascal_case(name);
+            let mut chars = pascal.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                None => pascal,
+            }
+        }
+        "kebab-case" => name.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => name.to_ascii_uppercase().replace('_', "-"),
+        _ => name.to_string(),
+    }
+}
+
+fn to_pascal_case(name: &str) -> String {
+    let mut pascal = String::new();
+    let mut capitalize = true;
+    for ch in name.chars() {
+        if ch == '_' {
+            capitalize = true;
+        } else if capitalize {
+            pascal.push(ch.to_ascii_uppercase());
+            capitalize = false;
+        } else {
+            pascal.push(ch);
+        }
+    }
+    pascal
+}
+
+/// Converts a `PascalCase` type name to `snake_case`, e.g. `UserProfile` to `user_profile`.
+pub fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+    for (i, ch) in chars.iter().enumerate() {
+        if ch.is_ascii_uppercase() {
+            let prev_lower = i > 0 && (chars[i - 1].is_ascii_lowercase() || chars[i - 1].is_ascii_digit());
+            let next_lower = chars.get(i + 1).is_some_and(|c| c.is_ascii_lowercase());
+            if i > 0 && (prev_lower || (next_lower && chars[i - 1].is_ascii_uppercase())) {
+                snake.push('_');
+            }
+            snake.push(ch.to_ascii_lowercase());
+        } else {
+            snake.push(*ch);
+        }
+    }
+    snake
+}
@@ -0,0 +1,167 @@
+use proc_macro2::TokenStream;
+use quote::{ format_ident, quote };
+use syn::{ Ident, Type, Visibility };
+
+use crate::default::option_inner_type;
+use crate::normalize::{ generate_normalized_value_tokens, NormalizeDefinition };
+use crate::serde_name::to_snake_case;
+
+/// The methods of every `<Model>Update`, which a setter of the same name would clash with.
+const RESERVED: [&str; 4] = ["new", "unset", "inc", "build_update"];
+
+/// Generates the typed `<Model>Update` builder, started with `<Model>Update::new()`.
+///
+/// Each stored field except `_id` gets a setter of the same name that serializes the value into
+/// `$set` under the field's stored key, so a misspelled field is a compile error instead of an
+/// update that silently adds a stray key. Setters take the same types as the model's builder
+/// setters. Values go through the field's `#[normalize]` steps and its `#[serde(with)]` or
+/// `serialize_with`, so they are stored exactly as `save()` would store them. `unset` and
+/// `inc` take a key, best written with the `<model>_fields` constants.
+/// A field named like one of the builder's own methods (`new`, `unset`, `inc`,
+/// `build_update`) is a compile error.
+///
+/// # Example
+///
+/// ```rust
+/// let update = UserUpdate::new().name("Alice".to_string()).inc(user_fields::VISITS, 1).build_update()?;
+/// // { "$set": { "name": "Alice" }, "$inc": { "visits": 1 } }
+/// ```
+pub fn generate_update_tokens(
+    name: &Ident,
+    vis: &Visibility,
+    fields: &[(Ident, Type)],
+    field_keys: &[(Ident, String)],
+    normalizers: &[NormalizeDefinition],
+    serialize_codecs: &[(Ident, Vec<TokenStream>)]
+) -> syn::Result<TokenStream> {
+    if
+        let Some((ident, _)) = field_keys
+            .iter()
+            .filter(|(_, key)| key != "_id")
+            .find(|(ident, _)| RESERVED.contains(&ident.to_string().as_str()))
+    {
+        return Err(
+            syn::Error::new_spanned(
+                ident,
+                format!(
+                    "field `{}` clashes with the `{}Update::{}` method; rename the field and keep its stored key with #[serde(rename = \"{}\")]",
+                    ident,
+                    name,
+                    ident,
+                    ident
+                )
+            )
+        );
+    }
+
+    let update_name = format_ident!("{}Update", name);
+    let fields_module = format_ident!("{}_fields", to_snake_case(&name.to_string()));
+    let doc = format!("Typed `$set` update builder for [`{}`], created with `{}::new()`.", name, update_name);
+    let unset_doc = format!(
+        "Removes `field` from the document (`$unset`), e.g. `{}::NAME`.",
+        fields_module
+    );
+    let inc_doc = format!(
+        "Increments `field` by `by` (`$inc`); use a negative value to decrement, e.g. `{}::COUNT`.",
+        fields_module
+    );
+
+    let field_methods = field_keys
+        .iter()
+        .filter(|(_, key)| key != "_id")
+        .filter_map(|(ident, key)| {
+            fields.iter().find(|(field, _)| field == ident).map(|(_, ty)| (ident, ty, key))
+        })
+        .map(|(ident, ty, key)| {
+            let inner = option_inner_type(ty);
+            let normalize = normalizers
+                .iter()
+                .find(|definition| &definition.key == key)
+                .map(|definition| {
+                    let normalized = generate_normalized_value_tokens(definition, &quote! { val }, false);
+                    quote! { let val = #normalized; }
+                });
+            let codecs = serialize_codecs
+                .iter()
+                .find(|(field, _)| field == ident)
+                .map(|(_, codecs)| codecs)
+                .filter(|codecs| !codecs.is_empty());
+            let set = match codecs {
+                Some(codecs) => {
+                    let value = if inner.is_some() { quote! { Some(val) } } else { quote! { val } };
+                    quote! {
+                        #[derive(::oximod::_serde::Serialize)]
+                        #[serde(crate = "::oximod::_serde")]
+                        struct Value(#[serde(#(#codecs),*)] #ty);
+                        self.0.set(#key, &Value(#value));
+                    }
+                }
+                None => quote! { self.0.set(#key, &val); },
+            };
+
+            if let Some(inner) = inner {
+                quote! {
+                    pub fn #ident<T: Into<#inner>>(mut self, val: T) -> Self {
+                        let val: #inner = val.into();
+                        #normalize
+                        #set
+                        self
+                    }
+                }
+            } else {
+                quote! {
+                    pub fn #ident(mut self, val: #ty) -> Self {
+                        #normalize
+                        #set
+                        self
+                    }
+                }
+            }
+        });
+
+    Ok(
+        quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Default)]
+        #vis struct #update_name(::oximod::_feature::update::ModelUpdate);
+
+        impl #update_name {
+            /// Starts an empty update.
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            #(#field_methods)*
+
+            #[doc = #unset_doc]
+            pub fn unset(mut self, field: &str) -> Self {
+                self.0.unset(field);
+                self
+            }
+
+            #[doc = #inc_doc]
+            pub fn inc(mut self, field: &str, by: impl Into<::oximod::_mongodb::bson::Bson>) -> Self {
+                self.0.inc(field, by.into());
+                self
+            }
+
+            /// Produces the update document to pass to `update`, `update_one`, etc.
+            ///
+            /// Fails with `OximodError::SerializationError` if a value can't be represented in BSON.
+            pub fn build_update(
+                self
+            ) -> Result<::oximod::_mongodb::bson::Document, ::oximod::_error::oximod_error::OximodError> {
+                self.0.build()
+            }
+        }
+
+        impl TryFrom<#update_name> for ::oximod::_mongodb::bson::Document {
+            type Error = ::oximod::_error::oximod_error::OximodError;
+
+            fn try_from(update: #update_name) -> Result<Self, Self::Error> {
+                update.build_update()
+            }
+        }
+    }
+    )
+}
@@ -1,6 +1,10 @@
-use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{ Attribute, Lit };
+use proc_macro2::{ Literal, TokenStream };
+use quote::{ quote, ToTokens };
+use syn::{ spanned::Spanned, Attribute, DeriveInput, Lit, LitStr, Token, Type };
+use crate::default::{ is_date_time, option_inner_type };
+use crate::normalize::{ generate_normalized_value_tokens, parse_normalize_args };
+use crate::reference::vec_inner_type;
+use crate::serde_name::{ is_skipped, parse_rename_all, serialized_name };
 
 #[derive(Default, Debug)]
 /// Arguments for field validation in OxiMod using the `#[validate(...)]` attribute.
@@ -11,13 +15,28 @@ use syn::{ Attribute, Lit };
 /// # Fields
 ///
 /// - `min_length`: (Optional) Minimum allowed length for strings.
-///   - The field’s length must be >= this value.
+///   - The field’s length in `char`s must be >= this value; `Option<String>` is checked when `Some`.
 ///   - Default: no minimum‐length constraint.
 ///
 /// - `max_length`: (Optional) Maximum allowed length for strings.
-///   - The field’s length must be <= this value.
+///   - The field’s length in `char`s must be <= this value; `Option<String>` is checked when `Some`.
 ///   - Default: no maximum‐length constraint.
 ///
+/// Lengths count Unicode scalar values, so `"héllo"` is 5 long even though it is 6 bytes.
+/// A character built from combining marks (e.g. `e` + U+0301) still counts as two.
+///
+/// - `len`: (Optional) Exact length for strings, e.g. `len = 2` for country codes.
+///   - Counts `char`s rather than bytes, so `"Ü"` has length 1; `Option<String>` is checked when `Some`.
+///   - Default: no exact-length constraint.
+///
+/// - `min_items`: (Optional) Minimum number of items for `Vec` fields.
+///   - The field’s `len()` must be >= this value; `None` counts as zero items.
+///   - Default: no minimum‐items constraint.
+///
+/// - `max_items`: (Optional) Maximum number of items for `Vec` fields.
+///   - The field’s `len()` must be <= this value; `None` counts as zero items.
+///   - Default: no maximum‐items constraint.
+///
 /// - `required`: (Optional) Whether the field is required (i.e., must be present and non-`None`).
 ///   - If `true`, an error is returned when the field is missing or `None`.
 ///   - Default: `false` (field may be omitted).
@@ -26,16 +45,38 @@ use syn::{ Attribute, Lit };
 ///   - If `true`, the field’s string value is matched against a basic email regex.
 ///   - Default: `false` (no email format check).
 ///
+/// - `one_of`: (Optional) The allowed values for a string field, e.g. `one_of("admin", "user")`.
+///   - Compared exactly (case-sensitive); `Option<String>` is checked when `Some`.
+///   - Default: any value is allowed.
+///
+/// - `enum`: (Optional) Path to an enum the field’s value must be a variant of, e.g. `enum = "Role"`.
+///   - The value is serialized, deserialized into the enum, and serialized again, and must come
+///     back unchanged, so the allowed set follows the enum and its `#[serde]` attributes
+///     (`rename_all`, tagging) instead of a hardcoded list. Aliases are rejected.
+///   - Use a `String` field for unit variants and a `Document` field for internally tagged enums;
+///     `Option<T>` is checked when `Some`.
+///   - Only runs in `validate()`; `json_schema` can't see the variants.
+///   - Default: no enum constraint.
+///
 /// - `pattern`: (Optional) A custom regular expression that the field’s string value must match.
 ///   - If provided, the field’s string must match this regex exactly.
 ///   - Default: no custom pattern enforced.
 ///
+/// - `regex_path`: (Optional) Path to a shared, already compiled regex the field’s string value
+///   must match, e.g. `regex_path = "crate::patterns::SLUG"`.
+///   - Point it at a `LazyLock<Regex>` (or `once_cell::sync::Lazy<Regex>`) static so the regex
+///     is compiled once per process instead of on every `validate()`.
+///   - Only runs in `validate()`; `json_schema` can't see the pattern.
+///   - Default: no shared regex enforced.
+///
 /// - `non_empty`: (Optional) Whether the field’s string value must not be empty (`""`).
-///   - If `true`, empty strings are rejected.
+///   - If `true`, empty and whitespace-only strings are rejected.
+///   - Works on `String` and `Option<String>`; unlike the other string rules it rejects `None`.
 ///   - Default: `false` (empty strings allowed).
 ///
 /// - `positive`: (Optional) Whether the field’s numeric value must be strictly > 0.
 ///   - If `true`, zero and negative values are rejected.
+///   - Works on integer and `f32`/`f64` fields alike.
 ///   - Default: `false` (no positivity constraint).
 ///
 /// - `negative`: (Optional) Whether the field’s numeric value must be strictly < 0.
@@ -48,12 +89,112 @@ use syn::{ Attribute, Lit };
 ///
 /// - `min`: (Optional) Minimum allowed value for numeric fields (inclusive).
 ///   - If provided, the field’s numeric value must be >= this value.
+///   - Accepts integer or float literals (`min = 0.5`); floats compare as `f64`.
 ///   - Default: no minimum‐value constraint.
 ///
 /// - `max`: (Optional) Maximum allowed value for numeric fields (inclusive).
 ///   - If provided, the field’s numeric value must be <= this value.
+///   - Accepts integer or float literals (`max = 4.99`); floats compare as `f64`.
 ///   - Default: no maximum‐value constraint.
 ///
+/// - `exclusive_min`: (Optional) Exclusive lower bound for numeric fields.
+///   - If provided, the field’s numeric value must be > this value; the bound itself fails.
+///   - Accepts integer or float literals and combines with `min`/`max`.
+///   - Default: no exclusive lower bound.
+///
+/// - `exclusive_max`: (Optional) Exclusive upper bound for numeric fields.
+///   - If provided, the field’s numeric value must be < this value; the bound itself fails.
+///   - Accepts integer or float literals and combines with `min`/`max`.
+///   - Default: no exclusive upper bound.
+///
+/// Any numeric rule on an `f32`/`f64` field also rejects `NaN`.
+///
+/// - `multiple_of`: (Optional) A non-zero divisor the field’s numeric value must be a multiple of.
+///   - If provided, `value % multiple_of` must be `0`; float fields are compared as `f64`, so `10.5`
+///     is not a multiple of `5`.
+///   - Default: no divisibility constraint.
+///
+/// - `starts_with`: (Optional) A prefix the field’s string value must start with.
+///   - Default: no prefix constraint.
+///
+/// - `ends_with`: (Optional) A suffix the field’s string value must end with.
+///   - Default: no suffix constraint.
+///
+/// - `includes`: (Optional) A substring the field’s string value must contain.
+///   - Default: no substring constraint.
+///
+/// - `url`: (Optional) Whether the field must be an absolute URL with a host.
+///   - Parsed with the `url` crate; relative URLs and empty strings are rejected.
+///   - Only `http` and `https` are accepted unless restricted via `url(schemes = "https,ftp")`.
+///   - Default: no URL check.
+///
+/// - `uuid`: (Optional) Whether the field must be a canonical hyphenated UUID (8-4-4-4-12 hex digits).
+///   - Restrict to a single version with `uuid(version = 4)`.
+///   - Default: no UUID check.
+///
+/// - `ip`, `ipv4`, `ipv6`: (Optional) Whether the field must parse as an IP address of that family.
+///   - Uses `std::net::{IpAddr, Ipv4Addr, Ipv6Addr}` parsing; `ip` accepts either family.
+///   - Default: no IP address check.
+///
+/// - `alphanumeric`: (Optional) Whether every character must satisfy `char::is_alphanumeric`.
+///   - Empty strings pass; combine with `non_empty` to reject them.
+///   - Default: `false` (no character-class constraint).
+///
+/// - `alpha`: (Optional) Whether every character must satisfy `char::is_alphabetic`.
+///   - Empty strings pass; combine with `non_empty` to reject them.
+///   - Default: `false` (no character-class constraint).
+///
+/// - `numeric`: (Optional) Whether every character must satisfy `char::is_numeric`.
+///   - Empty strings pass; combine with `non_empty` to reject them.
+///   - Default: `false` (no character-class constraint).
+///
+/// - `trimmed`: (Optional) Whether the value must have no leading or trailing whitespace.
+///   - Whitespace is as defined by `str::trim`; use `#[normalize(trim)]` to strip it instead.
+///   - Default: `false` (surrounding whitespace is allowed).
+///
+/// - `after`: (Optional) An RFC 3339 date, e.g. `after = "2020-01-01T00:00:00Z"`, that a
+///   `DateTime` or `Option<DateTime>` field must be strictly later than.
+///   - Parsed when the macro expands, so a malformed date is a compile error.
+///   - Default: no lower date bound.
+///
+/// - `before`: (Optional) An RFC 3339 date the field must be strictly earlier than.
+///   - Default: no upper date bound.
+///
+/// - `after_now`, `before_now`: (Optional) Whether the date must be in the future or the past,
+///   compared with the current time when `validate()` runs.
+///   - Default: `false`
+///
+/// Date rules skip `None`; use `required` to reject it. Using them on other types is a compile error.
+///
+/// - `equals`: (Optional) The name of another field this field must be equal to.
+///   - Compares with `!=`, so both fields need the same type; for `Option` fields
+///     `None == None` passes while `Some(_)` vs `None` fails.
+///   - The referenced field must exist on the struct, or expansion fails.
+///   - Default: no cross-field constraint.
+///
+/// - `with`: (Optional) Path to a custom validation function, e.g. `with = "rules::check"`.
+///   - The function must have the signature `fn(&Self) -> Result<(), String>`; an `Err`
+///     becomes an `OximodError::ValidationError` carrying the returned message.
+///   - May be repeated to chain several functions, and may also be placed on the struct.
+///   - Default: no custom function.
+///
+/// - `nested`: (Optional) Whether to run the field's own rules via the `Validate` trait.
+///   - For embedded types such as `address: Address`; `Option<T>` and `Vec<T>` fields are
+///     validated when present and element by element.
+///   - Messages name the full path, e.g. `address.zip` or `items.0.sku`.
+///   - Default: `false` (embedded values are not validated).
+///
+/// - `unique`: (Optional) Whether no other stored document may have the same value.
+///   - Checked by the async `Model::validate_unique()`, not by `validate()`, since it
+///     queries the collection. The document's own `_id` is excluded, so re-saving passes.
+///   - Only available on `#[derive(Model)]`; pair it with `#[index(unique)]` to also close
+///     the race between the check and the write.
+///   - Default: `false`
+///
+/// - `message`: (Optional) Custom error message for every rule in this attribute.
+///   - If provided, it replaces the default English message in the `ValidationError`.
+///   - Default: a generated message naming the field and the violated rule.
+///
 /// # Example
 ///
 /// ```rust
@@ -77,26 +218,99 @@ use syn::{ Attribute, Lit };
 pub struct ValidateArgs {
     pub min_length: Option<u32>,
     pub max_length: Option<u32>,
+    pub len: Option<u32>,
+    pub min_items: Option<u32>,
+    pub max_items: Option<u32>,
     pub required: Option<bool>,
-    // pub enum_values: Option<Vec<String>>, // use rust's enum instead
+    pub one_of: Option<Vec<String>>,
+    pub enum_path: Option<TokenStream>,
     pub email: Option<bool>,
     pub pattern: Option<String>,
+    pub regex_path: Option<TokenStream>,
     pub non_empty: Option<bool>,
     pub positive: Option<bool>,
     pub negative: Option<bool>,
     pub non_negative: Option<bool>,
-    pub min: Option<i64>,
-    pub max: Option<i64>,
+    pub min: Option<NumericBound>,
+    pub max: Option<NumericBound>,
+    pub exclusive_min: Option<NumericBound>,
+    pub exclusive_max: Option<NumericBound>,
+    pub multiple_of: Option<i64>,
+    pub starts_with: Option<String>,
+    pub ends_with: Option<String>,
+    pub includes: Option<String>,
+    pub url: Option<Vec<String>>,
+    /// `Some(None)` accepts any UUID version, `Some(Some(v))` only version `v`.
+    pub uuid: Option<Option<u8>>,
+    pub ip: Option<bool>,
+    pub ipv4: Option<bool>,
+    pub ipv6: Option<bool>,
+    pub alphanumeric: Option<bool>,
+    pub alpha: Option<bool>,
+    pub numeric: Option<bool>,
+    pub trimmed: Option<bool>,
+    /// The bound in milliseconds since the epoch, and the date as written for messages.
+    pub after: Option<(i64, String)>,
+    pub before: Option<(i64, String)>,
+    pub after_now: Option<bool>,
+    pub before_now: Option<bool>,
+    pub equals: Option<(String, proc_macro2::Span)>,
+    pub with: Vec<TokenStream>,
+    pub nested: Option<bool>,
+    pub unique: Option<bool>,
+    pub message: Option<String>,
+}
+
+/// A numeric bound given as an integer or float literal.
+#[derive(Debug, Clone, Copy)]
+pub enum NumericBound {
+    Int(i64),
+    Float(f64),
+}
+
+impl NumericBound {
+    fn parse(lit: Lit, key: &str) -> syn::Result<Self> {
+        match lit {
+            Lit::Int(lit_int) => Ok(NumericBound::Int(lit_int.base10_parse::<i64>()?)),
+            Lit::Float(lit_float) => Ok(NumericBound::Float(lit_float.base10_parse::<f64>()?)),
+            other =>
+                Err(
+                    syn::Error::new(
+                        other.span(),
+                        format!("expected integer or float literal for `{}`", key)
+                    )
+                ),
+        }
+    }
+}
+
+impl ToTokens for NumericBound {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            NumericBound::Int(val) => Literal::i64_unsuffixed(*val).to_tokens(tokens),
+            NumericBound::Float(val) => Literal::f64_unsuffixed(*val).to_tokens(tokens),
+        }
+    }
 }
 
 pub struct ValidateDefinition {
     pub field_name: String,
+    /// The key the field is stored under after `#[serde(rename)]`/`rename_all`, used in messages.
+    pub key: String,
+    /// Whether the field is an `Option<T>`, so string rules know how to reach the value.
+    pub is_option: bool,
+    /// Whether the field is `f32`/`f64`, so numeric rules compare as floats.
+    pub is_float: bool,
+    /// Whether the field is a `Vec<T>`, so `nested` validates every element.
+    pub is_vec: bool,
     pub args: ValidateArgs,
 }
 
 pub fn parse_validate_args(
     attr: &Attribute,
-    field_name: String
+    field_name: String,
+    key: String,
+    field_ty: &Type
 ) -> syn::Result<ValidateDefinition> {
     let mut args = ValidateArgs::default();
 
@@ -120,24 +334,47 @@ pub fn parse_validate_args(
                         syn::Error::new(lit.span(), "expected integer literal for `max_length`")
                     );
                 }
+            } else if meta.path.is_ident("len") {
+                let lit: Lit = meta.value()?.parse()?;
+                if let Lit::Int(lit_int) = lit {
+                    args.len = Some(lit_int.base10_parse::<u32>()?);
+                } else {
+                    return Err(syn::Error::new(lit.span(), "expected integer literal for `len`"));
+                }
+            } else if meta.path.is_ident("min_items") {
+                let lit: Lit = meta.value()?.parse()?;
+                if let Lit::Int(lit_int) = lit {
+                    args.min_items = Some(lit_int.base10_parse::<u32>()?);
+                } else {
+                    return Err(
+                        syn::Error::new(lit.span(), "expected integer literal for `min_items`")
+                    );
+                }
+            } else if meta.path.is_ident("max_items") {
+                let lit: Lit = meta.value()?.parse()?;
+                if let Lit::Int(lit_int) = lit {
+                    args.max_items = Some(lit_int.base10_parse::<u32>()?);
+                } else {
+                    return Err(
+                        syn::Error::new(lit.span(), "expected integer literal for `max_items`")
+                    );
+                }
             } else if meta.path.is_ident("required") {
                 args.required = Some(true);
-                // } else if meta.path.is_ident("enum_values") {
-                //     // 1. Grab the parenthesized group
-                //     let content;
-                //     syn::parenthesized!(content in meta.input);
-
-                //     // 2. Parse a comma-separated list of string literals
-                //     let values = content
-                //         .parse_terminated(
-                //             |buf: &syn::parse::ParseBuffer| buf.parse::<syn::LitStr>(), // note the closure
-                //             syn::Token![,]
-                //         )?
-                //         .into_iter()
-                //         .map(|lit_str| lit_str.value())
-                //         .collect::<Vec<_>>();
-
-                //     args.enum_values = Some(values);
+            } else if meta.path.is_ident("one_of") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let values = content
+                    .parse_terminated(|buf: &syn::parse::ParseBuffer| buf.parse::<LitStr>(), Token![,])?
+                    .into_iter()
+                    .map(|lit_str| lit_str.value())
+                    .collect::<Vec<_>>();
+                if values.is_empty() {
+                    return Err(meta.error("`one_of` needs at least one value"));
+                }
+                args.one_of = Some(values);
+            } else if meta.path.is_ident("enum") {
+                args.enum_path = Some(parse_path_arg(&meta, "enum")?);
             } else if meta.path.is_ident("email") {
                 args.email = Some(true);
             } else if meta.path.is_ident("pattern") {
@@ -149,6 +386,8 @@ pub fn parse_validate_args(
                         syn::Error::new(lit.span(), "expected integer literal for `min_length`")
                     );
                 }
+            } else if meta.path.is_ident("regex_path") {
+                args.regex_path = Some(parse_path_arg(&meta, "regex_path")?);
             } else if meta.path.is_ident("non_empty") {
                 args.non_empty = Some(true);
             } else if meta.path.is_ident("positive") {
@@ -158,18 +397,128 @@ pub fn parse_validate_args(
             } else if meta.path.is_ident("non_negative") {
                 args.non_negative = Some(true);
             } else if meta.path.is_ident("min") {
+                let lit: Lit = meta.value()?.parse()?;
+                args.min = Some(NumericBound::parse(lit, "min")?);
+            } else if meta.path.is_ident("max") {
+                let lit: Lit = meta.value()?.parse()?;
+                args.max = Some(NumericBound::parse(lit, "max")?);
+            } else if meta.path.is_ident("exclusive_min") {
+                let lit: Lit = meta.value()?.parse()?;
+                args.exclusive_min = Some(NumericBound::parse(lit, "exclusive_min")?);
+            } else if meta.path.is_ident("exclusive_max") {
+                let lit: Lit = meta.value()?.parse()?;
+                args.exclusive_max = Some(NumericBound::parse(lit, "exclusive_max")?);
+            } else if meta.path.is_ident("multiple_of") {
                 let lit: Lit = meta.value()?.parse()?;
                 if let Lit::Int(lit_int) = lit {
-                    args.min = Some(lit_int.base10_parse::<i64>()?);
+                    let divisor = lit_int.base10_parse::<i64>()?;
+                    if divisor == 0 {
+                        return Err(
+                            syn::Error::new(lit_int.span(), "`multiple_of` must not be zero")
+                        );
+                    }
+                    args.multiple_of = Some(divisor);
                 } else {
-                    return Err(syn::Error::new(lit.span(), "expected integer literal for `min`"));
+                    return Err(
+                        syn::Error::new(lit.span(), "expected integer literal for `multiple_of`")
+                    );
                 }
-            } else if meta.path.is_ident("max") {
+            } else if meta.path.is_ident("starts_with") {
+                args.starts_with = Some(parse_str_arg(&meta, "starts_with")?);
+            } else if meta.path.is_ident("ends_with") {
+                args.ends_with = Some(parse_str_arg(&meta, "ends_with")?);
+            } else if meta.path.is_ident("includes") {
+                args.includes = Some(parse_str_arg(&meta, "includes")?);
+            } else if meta.path.is_ident("url") {
+                let mut schemes = vec!["http".to_string(), "https".to_string()];
+                if meta.input.peek(syn::token::Paren) {
+                    meta.parse_nested_meta(|inner| {
+                        if inner.path.is_ident("schemes") {
+                            schemes = parse_str_arg(&inner, "schemes")?
+                                .split(',')
+                                .map(|scheme| scheme.trim().to_lowercase())
+                                .filter(|scheme| !scheme.is_empty())
+                                .collect();
+                            Ok(())
+                        } else {
+                            Err(inner.error("unknown `url` option, expected `schemes`"))
+                        }
+                    })?;
+                }
+                args.url = Some(schemes);
+            } else if meta.path.is_ident("uuid") {
+                let mut version = None;
+                if meta.input.peek(syn::token::Paren) {
+                    meta.parse_nested_meta(|inner| {
+                        if inner.path.is_ident("version") {
+                            let lit: Lit = inner.value()?.parse()?;
+                            if let Lit::Int(lit_int) = lit {
+                                let parsed = lit_int.base10_parse::<u8>()?;
+                                if !(1..=8).contains(&parsed) {
+                                    return Err(
+                                        syn::Error::new(
+                                            lit_int.span(),
+                                            "UUID `version` must be between 1 and 8"
+                                        )
+                                    );
+                                }
+                                version = Some(parsed);
+                                Ok(())
+                            } else {
+                                Err(
+                                    syn::Error::new(
+                                        lit.span(),
+                                        "expected integer literal for `version`"
+                                    )
+                                )
+                            }
+                        } else {
+                            Err(inner.error("unknown `uuid` option, expected `version`"))
+                        }
+                    })?;
+                }
+                args.uuid = Some(version);
+            } else if meta.path.is_ident("ip") {
+                args.ip = Some(true);
+            } else if meta.path.is_ident("ipv4") {
+                args.ipv4 = Some(true);
+            } else if meta.path.is_ident("ipv6") {
+                args.ipv6 = Some(true);
+            } else if meta.path.is_ident("alphanumeric") {
+                args.alphanumeric = Some(true);
+            } else if meta.path.is_ident("alpha") {
+                args.alpha = Some(true);
+            } else if meta.path.is_ident("numeric") {
+                args.numeric = Some(true);
+            } else if meta.path.is_ident("trimmed") {
+                args.trimmed = Some(true);
+            } else if meta.path.is_ident("after") {
+                args.after = Some(parse_date_arg(&meta, "after")?);
+            } else if meta.path.is_ident("before") {
+                args.before = Some(parse_date_arg(&meta, "before")?);
+            } else if meta.path.is_ident("after_now") {
+                args.after_now = Some(true);
+            } else if meta.path.is_ident("before_now") {
+                args.before_now = Some(true);
+            } else if meta.path.is_ident("equals") {
                 let lit: Lit = meta.value()?.parse()?;
-                if let Lit::Int(lit_int) = lit {
-                    args.max = Some(lit_int.base10_parse::<i64>()?);
+                if let Lit::Str(lit_str) = lit {
+                    args.equals = Some((lit_str.value(), lit_str.span()));
+                } else {
+                    return Err(syn::Error::new(lit.span(), "expected string literal for `equals`"));
+                }
+            } else if meta.path.is_ident("with") {
+                args.with.push(parse_path_arg(&meta, "with")?);
+            } else if meta.path.is_ident("nested") {
+                args.nested = Some(true);
+            } else if meta.path.is_ident("unique") {
+                args.unique = Some(true);
+            } else if meta.path.is_ident("message") {
+                let lit: Lit = meta.value()?.parse()?;
+                if let Lit::Str(lit_str) = lit {
+                    args.message = Some(lit_str.value());
                 } else {
-                    return Err(syn::Error::new(lit.span(), "expected integer literal for `max`"));
+                    return Err(syn::Error::new(lit.span(), "expected string literal for `message`"));
                 }
             } else {
                 return Err(meta.error("unknown attribute key"));
@@ -179,52 +528,362 @@ pub fn parse_validate_args(
         })?;
     }
 
-    Ok(ValidateDefinition { field_name, args })
+    let has_date_rule =
+        args.after.is_some() ||
+        args.before.is_some() ||
+        args.after_now.is_some() ||
+        args.before_now.is_some();
+    if has_date_rule && !is_date_time(option_inner_type(field_ty).unwrap_or(field_ty)) {
+        return Err(
+            syn::Error::new_spanned(
+                field_ty,
+                "`after`, `before`, `after_now`, and `before_now` can only be used on `DateTime` or `Option<DateTime>` fields"
+            )
+        );
+    }
+    if let (Some((after, after_str)), Some((before, before_str))) = (&args.after, &args.before) {
+        if after >= before {
+            return Err(
+                syn::Error::new_spanned(
+                    attr,
+                    format!("`after = \"{}\"` leaves no valid dates before `before = \"{}\"`", after_str, before_str)
+                )
+            );
+        }
+    }
+
+    let is_option = option_inner_type(field_ty).is_some();
+    let is_float = is_float_type(field_ty);
+    let is_vec = vec_inner_type(field_ty).is_some();
+
+    Ok(ValidateDefinition { field_name, key, is_option, is_float, is_vec, args })
+}
+
+fn is_float_type(ty: &Type) -> bool {
+    if let Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            return segment.ident == "f32" || segment.ident == "f64";
+        }
+    }
+    false
+}
+
+/// Returns the field expression and the bound to compare it against, both as `f64`
+/// when either side is a float and as `i64` otherwise.
+fn numeric_operands(
+    field: &TokenStream,
+    is_float: bool,
+    bound: &NumericBound
+) -> (TokenStream, TokenStream) {
+    match bound {
+        NumericBound::Int(val) if !is_float => (quote! { (#field as i64) }, quote! { #val }),
+        NumericBound::Int(val) => {
+            let val = Literal::f64_unsuffixed(*val as f64);
+            (quote! { (#field as f64) }, quote! { #val })
+        }
+        NumericBound::Float(val) => {
+            let val = Literal::f64_unsuffixed(*val);
+            (quote! { (#field as f64) }, quote! { #val })
+        }
+    }
+}
+
+/// Parses the struct-level `#[validate(with = "...")]` attribute, which only accepts `with`.
+pub fn parse_struct_validate_args(attr: &Attribute) -> syn::Result<Vec<TokenStream>> {
+    let mut with = Vec::new();
+
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("with") {
+            with.push(parse_path_arg(&meta, "with")?);
+            Ok(())
+        } else {
+            Err(meta.error("only `with = \"path::to::fn\"` is supported on the struct"))
+        }
+    })?;
+
+    Ok(with)
+}
+
+/// Parses a path given as a string literal, e.g. `with = "rules::check"`.
+fn parse_path_arg(meta: &syn::meta::ParseNestedMeta, key: &str) -> syn::Result<TokenStream> {
+    let lit: Lit = meta.value()?.parse()?;
+    if let Lit::Str(lit_str) = lit {
+        let path: syn::Path = lit_str.parse()?;
+        Ok(path.to_token_stream())
+    } else {
+        Err(syn::Error::new(lit.span(), format!("expected string literal for `{}`", key)))
+    }
+}
+
+/// Parses an RFC 3339 date such as `"2020-01-01T00:00:00Z"` into milliseconds since the epoch.
+fn parse_date_arg(meta: &syn::meta::ParseNestedMeta, key: &str) -> syn::Result<(i64, String)> {
+    let lit: LitStr = meta.value()?.parse()?;
+    let date = lit.value();
+    match mongodb::bson::DateTime::parse_rfc3339_str(&date) {
+        Ok(parsed) => Ok((parsed.timestamp_millis(), date)),
+        Err(e) =>
+            Err(
+                syn::Error::new(
+                    lit.span(),
+                    format!("expected an RFC 3339 date such as \"2020-01-01T00:00:00Z\" for `{}`: {}", key, e)
+                )
+            ),
+    }
+}
+
+fn parse_str_arg(meta: &syn::meta::ParseNestedMeta, key: &str) -> syn::Result<String> {
+    let lit: Lit = meta.value()?.parse()?;
+    if let Lit::Str(lit_str) = lit {
+        Ok(lit_str.value())
+    } else {
+        Err(syn::Error::new(lit.span(), format!("expected string literal for `{}`", key)))
+    }
+}
+
+/// The expression a check reads the field through: `self.field`, or for a `#[normalize]`d field
+/// the local holding the normalized copy, so rules see the value that will be stored.
+fn field_access(field_ident: &syn::Ident, normalized: &[syn::Ident]) -> TokenStream {
+    if normalized.contains(field_ident) {
+        normalized_local(field_ident).to_token_stream()
+    } else {
+        quote! { self.#field_ident }
+    }
+}
+
+/// The name of the local holding the normalized copy of `field_ident`.
+fn normalized_local(field_ident: &syn::Ident) -> syn::Ident {
+    quote::format_ident!("__normalized_{}", field_ident)
+}
+
+/// Wraps a check on `value: &String` so it works for both `String` and `Option<String>`
+/// fields. `None` values are skipped; use `required` to reject them. Date rules use it the
+/// same way with `value: &DateTime`.
+fn string_check(field: &TokenStream, is_option: bool, check: TokenStream) -> TokenStream {
+    if is_option {
+        quote! {
+            if let Some(value) = &#field {
+                #check
+            }
+        }
+    } else {
+        quote! {
+            {
+                let value = &#field;
+                #check
+            }
+        }
+    }
+}
+
+/// The bound tokens of `after_now`/`before_now`: a statement reading the current time once,
+/// that time in milliseconds, and the same instant as an RFC 3339 string for the message.
+fn now_bound() -> (TokenStream, TokenStream, TokenStream) {
+    (
+        quote! { let __now = ::oximod::_mongodb::bson::DateTime::now(); },
+        quote! { __now.timestamp_millis() },
+        quote! { __now.try_to_rfc3339_string().unwrap_or_else(|_| "the current time".to_string()) },
+    )
 }
 
-pub fn generate_validate_model_tokens(validate_def: &ValidateDefinition) -> Vec<TokenStream> {
+/// Returns an expression for the compiled `pattern`, as a `&'static Result<Regex, regex::Error>`.
+///
+/// Each call site gets its own static, so the regex is compiled on first use and then reused by
+/// every later `validate()`. A compile error is kept too, and reported as a `ValidationError`
+/// each time rather than panicking, so clippy's `invalid_regex` lint is silenced here.
+fn cached_regex(pattern: &str) -> TokenStream {
+    quote! {
+        {
+            static REGEX: ::std::sync::OnceLock<
+                Result<::oximod::_regex::Regex, ::oximod::_regex::Error>
+            > = ::std::sync::OnceLock::new();
+            #[allow(clippy::invalid_regex)]
+            fn compile() -> Result<::oximod::_regex::Regex, ::oximod::_regex::Error> {
+                ::oximod::_regex::Regex::new(#pattern)
+            }
+            REGEX.get_or_init(compile)
+        }
+    }
+}
+
+/// Returns the tokens building a validation error message, preferring the
+/// user-supplied `message = "..."` over the default one.
+fn error_message(custom_message: &Option<String>, default: TokenStream) -> TokenStream {
+    match custom_message {
+        Some(message) => quote! { #message.to_string() },
+        None => default,
+    }
+}
+
+/// Generates the checks for one field. `field_keys` maps Rust field names to stored keys so
+/// messages about other fields (e.g. `equals`) use their stored keys too. Fields listed in
+/// `normalized` are read from their `#[normalize]`d copy instead of `self`.
+pub fn generate_validate_model_tokens(
+    validate_def: &ValidateDefinition,
+    field_keys: &[(syn::Ident, String)],
+    normalized: &[syn::Ident]
+) -> Vec<TokenStream> {
     let field_ident = syn::Ident::new(&validate_def.field_name, proc_macro2::Span::call_site());
+    let field = field_access(&field_ident, normalized);
+    let field_key = &validate_def.key;
+    // `__path` is the prefix of an embedded model (e.g. `"address."`), empty at the top level.
+    let field_path = quote! { ::std::format_args!("{}{}", __path, #field_key) };
     let ValidateArgs {
         min_length,
         max_length,
+        len,
+        min_items,
+        max_items,
         required,
-        // enum_values,
+        one_of,
+        enum_path,
         email,
         pattern,
+        regex_path,
         non_empty,
         positive,
         negative,
         non_negative,
         min,
         max,
+        exclusive_min,
+        exclusive_max,
+        multiple_of,
+        starts_with,
+        ends_with,
+        includes,
+        url,
+        uuid,
+        ip,
+        ipv4,
+        ipv6,
+        alphanumeric,
+        alpha,
+        numeric,
+        trimmed,
+        after,
+        before,
+        after_now,
+        before_now,
+        equals,
+        with,
+        nested,
+        // Checked by the async `Model::validate_unique()` instead.
+        unique: _,
+        message: custom_message,
     } = &validate_def.args;
+    let is_option = validate_def.is_option;
+    let is_float = validate_def.is_float;
+    let zero = if is_float { quote! { 0.0 } } else { quote! { 0 } };
 
     let mut checks = vec![];
 
     if let Some(min) = min_length {
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must be at least {} characters long", #field_path, #min) }
+        );
+        checks.push(
+            string_check(
+                &field,
+                is_option,
+                quote! {
+                if value.chars().count() < #min as usize {
+                    return Err(::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::ValidationError(
+                            #message
+                        ),
+                        concat!("Ensure '", #field_key, "' has at least ", #min, " characters.")
+                    ));
+                }
+            }
+            )
+        );
+    }
+
+    if let Some(max) = max_length {
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must be at most {} characters long", #field_path, #max) }
+        );
+        checks.push(
+            string_check(
+                &field,
+                is_option,
+                quote! {
+                if value.chars().count() > #max as usize {
+                    return Err(::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::ValidationError(
+                            #message
+                        ),
+                        concat!("Ensure '", #field_key, "' has at most ", #max, " characters.")
+                    ));
+                }
+            }
+            )
+        );
+    }
+
+    if let Some(len) = len {
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must be exactly {} characters", #field_path, #len) }
+        );
+        checks.push(
+            string_check(
+                &field,
+                is_option,
+                quote! {
+                if value.chars().count() != #len as usize {
+                    return Err(::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::ValidationError(
+                            #message
+                        ),
+                        concat!("Ensure '", #field_key, "' has exactly ", #len, " characters.")
+                    ));
+                }
+            }
+            )
+        );
+    }
+
+    let item_count = if is_option {
+        quote! { #field.as_ref().map_or(0, |items| items.len()) }
+    } else {
+        quote! { #field.len() }
+    };
+
+    if let Some(min) = min_items {
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must contain at least {} items", #field_path, #min) }
+        );
         checks.push(
             quote! {
-            if self.#field_ident.len() < #min as usize {
+            if #item_count < #min as usize {
                 return Err(::oximod::_attach_printables!(
                     ::oximod::_error::oximod_error::OximodError::ValidationError(
-                        format!("Field '{}' must be at least {} characters long", stringify!(#field_ident), #min)
+                        #message
                     ),
-                    concat!("Ensure '", stringify!(#field_ident), "' has at least ", #min, " characters.")
+                    concat!("Ensure '", #field_key, "' has at least ", #min, " items.")
                 ));
             }
         }
         );
     }
 
-    if let Some(max) = max_length {
+    if let Some(max) = max_items {
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must contain at most {} items", #field_path, #max) }
+        );
         checks.push(
             quote! {
-            if self.#field_ident.len() > #max as usize {
+            if #item_count > #max as usize {
                 return Err(::oximod::_attach_printables!(
                     ::oximod::_error::oximod_error::OximodError::ValidationError(
-                        format!("Field '{}' must be at most {} characters long", stringify!(#field_ident), #max)
+                        #message
                     ),
-                    concat!("Ensure '", stringify!(#field_ident), "' has at most ", #max, " characters.")
+                    concat!("Ensure '", #field_key, "' has at most ", #max, " items.")
                 ));
             }
         }
@@ -233,16 +892,20 @@ pub fn generate_validate_model_tokens(validate_def: &ValidateDefinition) -> Vec<
 
     if let Some(req) = required {
         if *req {
+            let message = error_message(
+                custom_message,
+                quote! { format!("Field '{}' is required", #field_path) }
+            );
             checks.push(
                 quote! {
-                match self.#field_ident {
+                match #field {
                     Some(_) => {},
                     None => {
                         return Err(::oximod::_attach_printables!(
                             ::oximod::_error::oximod_error::OximodError::ValidationError(
-                                format!("Field '{}' is required", stringify!(#field_ident))
+                                #message
                             ),
-                            concat!("Provide a value for '", stringify!(#field_ident), "'.")
+                            concat!("Provide a value for '", #field_key, "'.")
                         ));
                     },
                     _ => {}
@@ -252,45 +915,74 @@ pub fn generate_validate_model_tokens(validate_def: &ValidateDefinition) -> Vec<
         }
     }
 
-    // if let Some(values) = enum_values {
-    //     let allowed: Vec<proc_macro2::TokenStream> = values
-    //         .iter()
-    //         .map(|v| quote! { #v })
-    //         .collect();
-    //
-    //     checks.push(quote! {
-    //         if let Some(ref value) = self.#field_ident {
-    //             if ! [#( #allowed ),*].contains(&value.as_str()) {
-    //                 return Err(::oximod::_attach_printables!(
-    //                     ::oximod::_error::oximod_error::OximodError::ValidationError(
-    //                         format!(
-    //                             "Field '{}' must be one of: {}",
-    //                             stringify!(#field_ident),
-    //                             vec![#( #allowed.to_string() ),*].join(", ")
-    //                         )
-    //                     ),
-    //                     concat!(
-    //                         "Use one of: [",
-    //                         #( values.join(", ") ),*,
-    //                         "] for field '", stringify!(#field_ident), "'."
-    //                     )
-    //                 ));
-    //             }
-    //         }
-    //     });
-    // }
+    if let Some(values) = one_of {
+        let listed = values.join(", ");
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must be one of: {}", #field_path, #listed) }
+        );
+        checks.push(
+            string_check(
+                &field,
+                is_option,
+                quote! {
+                if ![#(#values),*].contains(&value.as_str()) {
+                    return Err(::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::ValidationError(
+                            #message
+                        ),
+                        concat!("Use one of: ", #listed, " for '", #field_key, "'.")
+                    ));
+                }
+            }
+            )
+        );
+    }
+
+    if let Some(enum_path) = enum_path {
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must be a variant of {}", #field_path, stringify!(#enum_path)) }
+        );
+        checks.push(
+            string_check(
+                &field,
+                is_option,
+                quote! {
+                let is_variant = ::oximod::_mongodb::bson::to_bson(value).is_ok_and(|stored| {
+                    ::oximod::_mongodb::bson::from_bson::<#enum_path>(stored.clone())
+                        .ok()
+                        .and_then(|variant| ::oximod::_mongodb::bson::to_bson(&variant).ok())
+                        == Some(stored)
+                });
+                if !is_variant {
+                    return Err(::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::ValidationError(
+                            #message
+                        ),
+                        concat!("Use a value that `", stringify!(#enum_path), "` serializes to for '", #field_key, "'.")
+                    ));
+                }
+            }
+            )
+        );
+    }
 
     if let Some(is_email) = email {
         if *is_email {
+            let message = error_message(
+                custom_message,
+                quote! { format!("Field '{}' must be a valid email address", #field_path) }
+            );
             checks.push(
                 quote! {
-                if let Some(email) = &self.#field_ident {
+                if let Some(email) = &#field {
                     if !email.contains('@') || !email.contains('.') {
                         return Err(::oximod::_attach_printables!(
                             ::oximod::_error::oximod_error::OximodError::ValidationError(
-                                format!("Field '{}' must be a valid email address", stringify!(#field_ident))
+                                #message
                             ),
-                            concat!("Provide a valid email for '", stringify!(#field_ident), "'.")
+                            concat!("Provide a valid email for '", #field_key, "'.")
                         ));
                     }
 
@@ -298,9 +990,9 @@ pub fn generate_validate_model_tokens(validate_def: &ValidateDefinition) -> Vec<
                     if parts.len() != 2 || parts[0].is_empty() || parts[1].is_empty() || !parts[1].contains('.') {
                         return Err(::oximod::_attach_printables!(
                             ::oximod::_error::oximod_error::OximodError::ValidationError(
-                                format!("Field '{}' must be a valid email address", stringify!(#field_ident))
+                                #message
                             ),
-                            concat!("Ensure '", stringify!(#field_ident), "' is in the format local@domain.")
+                            concat!("Ensure '", #field_key, "' is in the format local@domain.")
                         ));
                     }
                 }
@@ -310,26 +1002,28 @@ pub fn generate_validate_model_tokens(validate_def: &ValidateDefinition) -> Vec<
     }
 
     if let Some(pattern) = pattern {
+        let regex = cached_regex(pattern);
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' does not match the required pattern", #field_path) }
+        );
         checks.push(
             quote! {
-            if let Some(ref value) = self.#field_ident {
-                let regex = ::oximod::_regex::Regex::new(#pattern).map_err(|e| {
+            if let Some(ref value) = #field {
+                let regex = #regex.as_ref().map_err(|e| {
                     ::oximod::_attach_printables!(
                         ::oximod::_error::oximod_error::OximodError::ValidationError(
-                            format!("Invalid regex pattern in validation for '{}': {}", stringify!(#field_ident), e)
+                            format!("Invalid regex pattern in validation for '{}': {}", #field_path, e)
                         ),
-                        concat!("Check the regex pattern for '", stringify!(#field_ident), "'.")
+                        concat!("Check the regex pattern for '", #field_key, "'.")
                     )
                 })?;
                 if !regex.is_match(value) {
                     return Err(::oximod::_attach_printables!(
                         ::oximod::_error::oximod_error::OximodError::ValidationError(
-                            format!(
-                                "Field '{}' does not match the required pattern",
-                                stringify!(#field_ident)
-                            )
+                            #message
                         ),
-                        concat!("Ensure '", stringify!(#field_ident), "' matches regex: ", #pattern, ".")
+                        concat!("Ensure '", #field_key, "' matches regex: ", #pattern, ".")
                     ));
                 }
             }
@@ -337,25 +1031,93 @@ pub fn generate_validate_model_tokens(validate_def: &ValidateDefinition) -> Vec<
         );
     }
 
-    if let Some(true) = non_empty {
+    if let Some(regex) = regex_path {
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' does not match the required pattern", #field_path) }
+        );
         checks.push(
+            string_check(
+                &field,
+                is_option,
+                quote! {
+                if !#regex.is_match(value) {
+                    return Err(::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::ValidationError(
+                            #message
+                        ),
+                        concat!("Ensure '", #field_key, "' matches the regex in `", stringify!(#regex), "`.")
+                    ));
+                }
+            }
+            )
+        );
+    }
+
+    if let Some(true) = non_empty {
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must be non-empty", #field_path) }
+        );
+        let missing_message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' is missing but marked as non-empty", #field_path) }
+        );
+        let empty_check =
             quote! {
-            let value = &self.#field_ident;
-            if let Some(ref val) = value {
-                if val.trim().is_empty() {
+            if value.trim().is_empty() {
+                return Err(::oximod::_attach_printables!(
+                    ::oximod::_error::oximod_error::OximodError::ValidationError(
+                        #message
+                    ),
+                    concat!("Provide a non-empty string for '", #field_key, "'.")
+                ));
+            }
+        };
+        if is_option {
+            // Unlike the other string rules, `non_empty` also rejects `None`.
+            checks.push(
+                quote! {
+                if let Some(value) = &#field {
+                    #empty_check
+                } else {
                     return Err(::oximod::_attach_printables!(
                         ::oximod::_error::oximod_error::OximodError::ValidationError(
-                            format!("Field '{}' must be non-empty", stringify!(#field_ident))
+                            #missing_message
                         ),
-                        concat!("Provide a non-empty string for '", stringify!(#field_ident), "'.")
+                        concat!("Ensure '", #field_key, "' is present and not empty.")
                     ));
                 }
-            } else {
+            }
+            );
+        } else {
+            checks.push(string_check(&field, is_option, empty_check));
+        }
+    }
+
+    let has_numeric_rule =
+        positive.is_some() ||
+        negative.is_some() ||
+        non_negative.is_some() ||
+        min.is_some() ||
+        max.is_some() ||
+        exclusive_min.is_some() ||
+        exclusive_max.is_some() ||
+        multiple_of.is_some();
+
+    if is_float && has_numeric_rule {
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must be a number, not NaN", #field_path) }
+        );
+        checks.push(
+            quote! {
+            if #field.is_nan() {
                 return Err(::oximod::_attach_printables!(
                     ::oximod::_error::oximod_error::OximodError::ValidationError(
-                        format!("Field '{}' is missing but marked as non-empty", stringify!(#field_ident))
+                        #message
                     ),
-                    concat!("Ensure '", stringify!(#field_ident), "' is present and not empty.")
+                    concat!("Ensure '", #field_key, "' is not NaN.")
                 ));
             }
         }
@@ -364,14 +1126,18 @@ pub fn generate_validate_model_tokens(validate_def: &ValidateDefinition) -> Vec<
 
     if let Some(positive) = positive {
         if *positive {
+            let message = error_message(
+                custom_message,
+                quote! { format!("Field '{}' must be positive", #field_path) }
+            );
             checks.push(
                 quote! {
-                if self.#field_ident <= 0 {
+                if #field <= #zero {
                     return Err(::oximod::_attach_printables!(
                         ::oximod::_error::oximod_error::OximodError::ValidationError(
-                            format!("Field '{}' must be positive", stringify!(#field_ident))
+                            #message
                         ),
-                        concat!("Use a positive value for '", stringify!(#field_ident), "'.")
+                        concat!("Use a positive value for '", #field_key, "'.")
                     ));
                 }
             }
@@ -381,14 +1147,18 @@ pub fn generate_validate_model_tokens(validate_def: &ValidateDefinition) -> Vec<
 
     if let Some(negative) = negative {
         if *negative {
+            let message = error_message(
+                custom_message,
+                quote! { format!("Field '{}' must be negative", #field_path) }
+            );
             checks.push(
                 quote! {
-                if self.#field_ident >= 0 {
+                if #field >= #zero {
                     return Err(::oximod::_attach_printables!(
                         ::oximod::_error::oximod_error::OximodError::ValidationError(
-                            format!("Field '{}' must be negative", stringify!(#field_ident))
+                            #message
                         ),
-                        concat!("Use a negative value for '", stringify!(#field_ident), "'.")
+                        concat!("Use a negative value for '", #field_key, "'.")
                     ));
                 }
             }
@@ -398,14 +1168,18 @@ pub fn generate_validate_model_tokens(validate_def: &ValidateDefinition) -> Vec<
 
     if let Some(non_negative) = non_negative {
         if *non_negative {
+            let message = error_message(
+                custom_message,
+                quote! { format!("Field '{}' must be non-negative", #field_path) }
+            );
             checks.push(
                 quote! {
-                if self.#field_ident < 0 {
+                if #field < #zero {
                     return Err(::oximod::_attach_printables!(
                         ::oximod::_error::oximod_error::OximodError::ValidationError(
-                            format!("Field '{}' must be non-negative", stringify!(#field_ident))
+                            #message
                         ),
-                        concat!("Use zero or a positive value for '", stringify!(#field_ident), "'.")
+                        concat!("Use zero or a positive value for '", #field_key, "'.")
                     ));
                 }
             }
@@ -414,14 +1188,19 @@ pub fn generate_validate_model_tokens(validate_def: &ValidateDefinition) -> Vec<
     }
 
     if let Some(min) = min {
+        let (operand, bound) = numeric_operands(&field, is_float, min);
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must be at least {}", #field_path, #min) }
+        );
         checks.push(
             quote! {
-            if (self.#field_ident as i64) < #min {
+            if #operand < #bound {
                 return Err(::oximod::_attach_printables!(
                     ::oximod::_error::oximod_error::OximodError::ValidationError(
-                        format!("Field '{}' must be at least {}", stringify!(#field_ident), #min)
+                        #message
                     ),
-                    concat!("Ensure '", stringify!(#field_ident), "' is at least ", #min, ".")
+                    concat!("Ensure '", #field_key, "' is at least ", #min, ".")
                 ));
             }
         }
@@ -429,19 +1208,616 @@ pub fn generate_validate_model_tokens(validate_def: &ValidateDefinition) -> Vec<
     }
 
     if let Some(max) = max {
+        let (operand, bound) = numeric_operands(&field, is_float, max);
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must be at most {}", #field_path, #max) }
+        );
         checks.push(
             quote! {
-            if (self.#field_ident as i64) > #max {
+            if #operand > #bound {
                 return Err(::oximod::_attach_printables!(
                     ::oximod::_error::oximod_error::OximodError::ValidationError(
-                        format!("Field '{}' must be at most {}", stringify!(#field_ident), #max)
+                        #message
                     ),
-                    concat!("Ensure '", stringify!(#field_ident), "' is at most ", #max, ".")
+                    concat!("Ensure '", #field_key, "' is at most ", #max, ".")
                 ));
             }
         }
         );
     }
 
+    if let Some(exclusive_min) = exclusive_min {
+        let (operand, bound) = numeric_operands(&field, is_float, exclusive_min);
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must be greater than {}", #field_path, #exclusive_min) }
+        );
+        checks.push(
+            quote! {
+            if #operand <= #bound {
+                return Err(::oximod::_attach_printables!(
+                    ::oximod::_error::oximod_error::OximodError::ValidationError(
+                        #message
+                    ),
+                    concat!("Ensure '", #field_key, "' is greater than ", #exclusive_min, ".")
+                ));
+            }
+        }
+        );
+    }
+
+    if let Some(exclusive_max) = exclusive_max {
+        let (operand, bound) = numeric_operands(&field, is_float, exclusive_max);
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must be less than {}", #field_path, #exclusive_max) }
+        );
+        checks.push(
+            quote! {
+            if #operand >= #bound {
+                return Err(::oximod::_attach_printables!(
+                    ::oximod::_error::oximod_error::OximodError::ValidationError(
+                        #message
+                    ),
+                    concat!("Ensure '", #field_key, "' is less than ", #exclusive_max, ".")
+                ));
+            }
+        }
+        );
+    }
+
+    if let Some(divisor) = multiple_of {
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must be a multiple of {}", #field_path, #divisor) }
+        );
+        let (value, divisor_value) = numeric_operands(&field, is_float, &NumericBound::Int(*divisor));
+        checks.push(
+            quote! {
+            if #value % #divisor_value != #zero {
+                return Err(::oximod::_attach_printables!(
+                    ::oximod::_error::oximod_error::OximodError::ValidationError(
+                        #message
+                    ),
+                    concat!("Ensure '", #field_key, "' is a multiple of ", #divisor, ".")
+                ));
+            }
+        }
+        );
+    }
+
+    let date_rules = [
+        (after.as_ref().map(|(millis, date)| (quote! {}, quote! { #millis }, quote! { #date })), "after", quote! { <= }),
+        (before.as_ref().map(|(millis, date)| (quote! {}, quote! { #millis }, quote! { #date })), "before", quote! { >= }),
+        (after_now.map(|_| now_bound()), "after", quote! { <= }),
+        (before_now.map(|_| now_bound()), "before", quote! { >= }),
+    ];
+    for (bound, relation, fails) in date_rules {
+        let Some((setup, millis, date)) = bound else {
+            continue;
+        };
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must be {} {}", #field_path, #relation, #date) }
+        );
+        checks.push(
+            string_check(
+                &field,
+                is_option,
+                quote! {
+                    #setup
+                    if value.timestamp_millis() #fails #millis {
+                        return Err(::oximod::_attach_printables!(
+                            ::oximod::_error::oximod_error::OximodError::ValidationError(
+                                #message
+                            ),
+                            concat!("Use a date ", #relation, " the boundary for '", #field_key, "'.")
+                        ));
+                    }
+                }
+            )
+        );
+    }
+
+    if let Some(prefix) = starts_with {
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must start with '{}'", #field_path, #prefix) }
+        );
+        checks.push(
+            string_check(
+                &field,
+                is_option,
+                quote! {
+                if !value.starts_with(#prefix) {
+                    return Err(::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::ValidationError(
+                            #message
+                        ),
+                        concat!("Ensure '", #field_key, "' starts with '", #prefix, "'.")
+                    ));
+                }
+            }
+            )
+        );
+    }
+
+    if let Some(suffix) = ends_with {
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must end with '{}'", #field_path, #suffix) }
+        );
+        checks.push(
+            string_check(
+                &field,
+                is_option,
+                quote! {
+                if !value.ends_with(#suffix) {
+                    return Err(::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::ValidationError(
+                            #message
+                        ),
+                        concat!("Ensure '", #field_key, "' ends with '", #suffix, "'.")
+                    ));
+                }
+            }
+            )
+        );
+    }
+
+    if let Some(substring) = includes {
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must include '{}'", #field_path, #substring) }
+        );
+        checks.push(
+            string_check(
+                &field,
+                is_option,
+                quote! {
+                if !value.contains(#substring) {
+                    return Err(::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::ValidationError(
+                            #message
+                        ),
+                        concat!("Ensure '", #field_key, "' includes '", #substring, "'.")
+                    ));
+                }
+            }
+            )
+        );
+    }
+
+    if let Some(schemes) = url {
+        let allowed = schemes.join(", ");
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must be a valid absolute URL ({})", #field_path, #allowed) }
+        );
+        checks.push(
+            string_check(
+                &field,
+                is_option,
+                quote! {
+                let is_valid_url = match ::oximod::_url::Url::parse(value) {
+                    Ok(parsed) => parsed.has_host() && [#(#schemes),*].contains(&parsed.scheme()),
+                    Err(_) => false,
+                };
+                if !is_valid_url {
+                    return Err(::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::ValidationError(
+                            #message
+                        ),
+                        concat!("Ensure '", #field_key, "' is an absolute URL using one of: ", #allowed, ".")
+                    ));
+                }
+            }
+            )
+        );
+    }
+
+    if let Some(version) = uuid {
+        let (uuid_regex, message) = match version {
+            Some(v) =>
+                (
+                    format!(
+                        "^[0-9a-fA-F]{{8}}-[0-9a-fA-F]{{4}}-{}[0-9a-fA-F]{{3}}-[0-9a-fA-F]{{4}}-[0-9a-fA-F]{{12}}$",
+                        v
+                    ),
+                    error_message(
+                        custom_message,
+                        quote! { format!("Field '{}' must be a valid version {} UUID", #field_path, #v) }
+                    ),
+                ),
+            None =>
+                (
+                    "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$".to_string(),
+                    error_message(
+                        custom_message,
+                        quote! { format!("Field '{}' must be a valid UUID", #field_path) }
+                    ),
+                ),
+        };
+        let uuid_regex = cached_regex(&uuid_regex);
+        checks.push(
+            string_check(
+                &field,
+                is_option,
+                quote! {
+                let is_valid_uuid = #uuid_regex
+                    .as_ref()
+                    .map(|regex| regex.is_match(value))
+                    .unwrap_or(false);
+                if !is_valid_uuid {
+                    return Err(::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::ValidationError(
+                            #message
+                        ),
+                        concat!("Ensure '", #field_key, "' is a UUID like 123e4567-e89b-12d3-a456-426614174000.")
+                    ));
+                }
+            }
+            )
+        );
+    }
+
+    let ip_families = [
+        (ip, quote! { IpAddr }, "IP (v4 or v6)"),
+        (ipv4, quote! { Ipv4Addr }, "IPv4"),
+        (ipv6, quote! { Ipv6Addr }, "IPv6"),
+    ];
+
+    for (enabled, addr_type, family) in ip_families {
+        if let Some(true) = enabled {
+            let message = error_message(
+                custom_message,
+                quote! { format!("Field '{}' must be a valid {} address", #field_path, #family) }
+            );
+            checks.push(
+                string_check(
+                    &field,
+                    is_option,
+                    quote! {
+                    if value.parse::<::std::net::#addr_type>().is_err() {
+                        return Err(::oximod::_attach_printables!(
+                            ::oximod::_error::oximod_error::OximodError::ValidationError(
+                                #message
+                            ),
+                            concat!("Ensure '", #field_key, "' is a valid ", #family, " address.")
+                        ));
+                    }
+                }
+                )
+            );
+        }
+    }
+
+    let char_classes = [
+        (alphanumeric, quote! { is_alphanumeric }, "alphanumeric"),
+        (alpha, quote! { is_alphabetic }, "alphabetic"),
+        (numeric, quote! { is_numeric }, "numeric"),
+    ];
+
+    for (enabled, predicate, class) in char_classes {
+        if let Some(true) = enabled {
+            let message = error_message(
+                custom_message,
+                quote! { format!("Field '{}' must contain only {} characters", #field_path, #class) }
+            );
+            checks.push(
+                string_check(
+                    &field,
+                    is_option,
+                    quote! {
+                    if !value.chars().all(char::#predicate) {
+                        return Err(::oximod::_attach_printables!(
+                            ::oximod::_error::oximod_error::OximodError::ValidationError(
+                                #message
+                            ),
+                            concat!("Ensure '", #field_key, "' contains only ", #class, " characters.")
+                        ));
+                    }
+                }
+                )
+            );
+        }
+    }
+
+    if let Some(true) = trimmed {
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must not have leading or trailing whitespace", #field_path) }
+        );
+        checks.push(
+            string_check(
+                &field,
+                is_option,
+                quote! {
+                if value.trim().len() != value.len() {
+                    return Err(::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::ValidationError(
+                            #message
+                        ),
+                        concat!("Trim surrounding whitespace from '", #field_key, "'.")
+                    ));
+                }
+            }
+            )
+        );
+    }
+
+    if let Some((other_field, _)) = equals {
+        let other = field_access(&syn::Ident::new(other_field, proc_macro2::Span::call_site()), normalized);
+        let other_field = field_keys
+            .iter()
+            .find(|(ident, _)| ident == other_field)
+            .map_or(other_field, |(_, key)| key);
+        let message = error_message(
+            custom_message,
+            quote! { format!("Field '{}' must be equal to '{}{}'", #field_path, __path, #other_field) }
+        );
+        checks.push(
+            quote! {
+            if #field != #other {
+                return Err(::oximod::_attach_printables!(
+                    ::oximod::_error::oximod_error::OximodError::ValidationError(
+                        #message
+                    ),
+                    concat!("Ensure '", #field_key, "' matches '", #other_field, "'.")
+                ));
+            }
+        }
+        );
+    }
+
+    if nested.is_some() {
+        let validate_at = quote! { ::oximod::_feature::validate::Validate::validate_at };
+        checks.push(
+            if is_option {
+                quote! {
+                if let Some(value) = &#field {
+                    #validate_at(value, &format!("{}.", #field_path))?;
+                }
+            }
+            } else if validate_def.is_vec {
+                quote! {
+                for (index, value) in #field.iter().enumerate() {
+                    #validate_at(value, &format!("{}.{}.", #field_path, index))?;
+                }
+            }
+            } else {
+                quote! {
+                #validate_at(&#field, &format!("{}.", #field_path))?;
+            }
+            }
+        );
+    }
+
+    checks.extend(generate_with_tokens(with, custom_message));
+
     checks
 }
+
+/// Emits a call to each user-supplied `fn(&Self) -> Result<(), String>`.
+pub fn generate_with_tokens(
+    with: &[TokenStream],
+    custom_message: &Option<String>
+) -> Vec<TokenStream> {
+    with.iter()
+        .map(|path| {
+            let message = error_message(custom_message, quote! { message });
+            quote! {
+                if let Err(message) = #path(self) {
+                    return Err(::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::ValidationError(
+                            #message
+                        ),
+                        concat!("Check the rule implemented by `", stringify!(#path), "`.")
+                    ));
+                }
+            }
+        })
+        .collect()
+}
+
+/// Collects every struct- and field-level `#[validate(...)]` rule on `input` and emits the
+/// inherent `validate()` together with the `Validate` impl. Shared by `#[derive(Model)]` and
+/// `#[derive(Validate)]`.
+pub fn generate_validate_impl_tokens(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let rename_all = parse_rename_all(&input.attrs);
+    let mut struct_validators = Vec::new();
+    let mut validate_definitions = Vec::new();
+    let mut field_keys: Vec<(syn::Ident, String)> = Vec::new();
+    let mut normalizers = Vec::new();
+
+    for attr in &input.attrs {
+        if attr.path().is_ident("validate") {
+            struct_validators.extend(parse_struct_validate_args(attr)?);
+        }
+    }
+
+    if let syn::Data::Struct(data_struct) = &input.data {
+        for field in data_struct.fields.iter() {
+            if let Some(ident) = &field.ident {
+                let key = serialized_name(field, rename_all.as_deref());
+                field_keys.push((ident.clone(), key.clone()));
+                let skipped = is_skipped(&field.attrs);
+                for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("normalize")) {
+                    let definition = parse_normalize_args(attr, &field.ty, key.clone())?;
+                    let local = normalized_local(ident);
+                    let value = generate_normalized_value_tokens(
+                        &definition,
+                        &quote! { self.#ident },
+                        option_inner_type(&field.ty).is_some()
+                    );
+                    normalizers.push((ident.clone(), quote! { let #local = #value; }));
+                }
+                for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("validate")) {
+                    let def = parse_validate_args(attr, ident.to_string(), key.clone(), &field.ty)?;
+                    if skipped {
+                        let message = if def.args.required == Some(true) {
+                            "#[validate(required)] contradicts #[serde(skip)]: the field is never stored or deserialized"
+                        } else {
+                            "#[validate] has no effect on a #[serde(skip)] field, which is never stored or deserialized; validate the fields it is computed from"
+                        };
+                        return Err(syn::Error::new_spanned(attr, message));
+                    }
+                    validate_definitions.push(def);
+                }
+            }
+        }
+    }
+
+    for validate_def in &validate_definitions {
+        if let Some((other_field, span)) = &validate_def.args.equals {
+            if !field_keys.iter().any(|(ident, _)| ident == other_field) {
+                return Err(
+                    syn::Error::new(
+                        *span,
+                        format!("`equals` references unknown field `{}`", other_field)
+                    )
+                );
+            }
+        }
+    }
+
+    // Only locals that a rule reads are emitted, so unused copies don't trigger warnings.
+    let normalized: Vec<syn::Ident> = normalizers
+        .iter()
+        .map(|(ident, _)| ident.clone())
+        .collect();
+    let normalized_locals = normalizers
+        .iter()
+        .filter(|(ident, _)| {
+            validate_definitions.iter().any(|def| {
+                *ident == def.field_name ||
+                    def.args.equals.as_ref().is_some_and(|(other, _)| ident == other)
+            })
+        })
+        .map(|(_, tokens)| tokens);
+
+    let validations = validate_definitions
+        .iter()
+        .flat_map(|def| generate_validate_model_tokens(def, &field_keys, &normalized))
+        .chain(generate_with_tokens(&struct_validators, &None));
+
+    Ok(
+        quote! {
+        impl #name {
+            /// Runs every `#[validate(...)]` rule declared on the struct.
+            pub fn validate(&self) -> Result<(), ::oximod::_error::oximod_error::OximodError> {
+                self._validate_at("")
+            }
+
+            fn _validate_at(&self, __path: &str) -> Result<(), ::oximod::_error::oximod_error::OximodError> {
+                use ::oximod::_error::printable::Printable;
+                #(#normalized_locals)*
+                #(#validations)*
+                Ok(())
+            }
+        }
+
+        impl ::oximod::_feature::validate::Validate for #name {
+            fn validate(&self) -> Result<(), ::oximod::_error::oximod_error::OximodError> {
+                #name::validate(self)
+            }
+
+            fn validate_at(&self, path: &str) -> Result<(), ::oximod::_error::oximod_error::OximodError> {
+                #name::_validate_at(self, path)
+            }
+        }
+    }
+    )
+}
+
+/// A field marked `#[validate(unique)]`, checked by the generated `Model::validate_unique()`.
+pub struct UniqueField {
+    /// The key the field is stored under, used in the query and the message.
+    pub key: String,
+    pub message: Option<String>,
+    pub span: proc_macro2::Span,
+}
+
+/// Collects the fields marked `#[validate(unique)]`.
+pub fn parse_unique_fields(input: &DeriveInput) -> syn::Result<Vec<UniqueField>> {
+    let rename_all = parse_rename_all(&input.attrs);
+    let mut unique_fields = Vec::new();
+
+    if let syn::Data::Struct(data_struct) = &input.data {
+        for field in data_struct.fields.iter() {
+            let Some(ident) = &field.ident else {
+                continue;
+            };
+            let key = serialized_name(field, rename_all.as_deref());
+            for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("validate")) {
+                let def = parse_validate_args(attr, ident.to_string(), key.clone(), &field.ty)?;
+                if def.args.unique == Some(true) {
+                    unique_fields.push(UniqueField {
+                        key: key.clone(),
+                        message: def.args.message,
+                        span: attr.span(),
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(unique_fields)
+}
+
+/// Generates the body of `Model::validate_unique()`.
+///
+/// Each unique field is looked up in the document `save()` would write, so serde renames and
+/// `#[normalize]` apply, and checked with a `count_documents` limited to one match that
+/// excludes the document's own `_id`. Missing and `null` values are not checked.
+pub fn generate_validate_unique_tokens(unique_fields: &[UniqueField]) -> TokenStream {
+    if unique_fields.is_empty() {
+        return quote! { Ok(()) };
+    }
+
+    let checks = unique_fields.iter().map(|UniqueField { key, message, .. }| {
+        let message = match message {
+            Some(message) => quote! { #message.to_string() },
+            None => quote! { format!("Field '{}' must be unique", #key) },
+        };
+        quote! {
+            if let Some(value) = document.get(#key).filter(|value| **value != ::oximod::_mongodb::bson::Bson::Null) {
+                let mut filter = ::oximod::_mongodb::bson::doc! { #key: value.clone() };
+                if let Some(id) = document.get("_id") {
+                    filter.insert("_id", ::oximod::_mongodb::bson::doc! { "$ne": id.clone() });
+                }
+
+                let count = collection
+                    .count_documents(filter)
+                    .limit(1)
+                    .await
+                    .map_err(|e| {
+                        ::oximod::_attach_printables!(
+                            ::oximod::_error::oximod_error::OximodError::ConnectionError(e.to_string()),
+                            "Failed to check uniqueness. Make sure your connection is healthy."
+                        )
+                    })?;
+
+                if count > 0 {
+                    return Err(::oximod::_attach_printables!(
+                        ::oximod::_error::oximod_error::OximodError::ValidationError(#message),
+                        concat!("Choose a different value for '", #key, "'; another document already uses it.")
+                    ));
+                }
+            }
+        }
+    });
+
+    quote! {
+        use ::oximod::_error::printable::Printable;
+
+        let document = self._to_document()?;
+        let collection = Self::get_collection()?;
+
+        #(#checks)*
+
+        Ok(())
+    }
+}